@@ -0,0 +1,75 @@
+// sequence alignment: substitution scores, scalar dynamic programming and its vectorised variants
+//
+// all the algorithms in here work on slices of symbols (nucleobases, amino acids...),
+// every symbol being converted to a small integer index so that scores can be looked up in a flat matrix
+
+pub mod multiple;
+pub mod scoring;
+pub mod smith_waterman;
+pub mod striped;
+
+use amino_acids::AminoAcid;
+use dna::Nucleobase as DnaNucleobase;
+
+/// A symbol that can be aligned: it belongs to a finite alphabet and maps to an index within it.
+pub trait Symbol {
+    /// Number of distinct symbols in the alphabet.
+    const ALPHABET_SIZE: usize;
+
+    /// Returns the index of the symbol in its alphabet, always < `ALPHABET_SIZE`.
+    fn index(&self) -> usize;
+}
+
+impl Symbol for DnaNucleobase {
+    const ALPHABET_SIZE: usize = 4;
+
+    fn index(&self) -> usize {
+        use dna::Nucleobase::*;
+
+        match self {
+            Adenine => 0,
+            Cytosine => 1,
+            Guanine => 2,
+            Thymine => 3,
+        }
+    }
+}
+
+impl Symbol for AminoAcid {
+    const ALPHABET_SIZE: usize = 21;
+
+    // the order is the one used by BLOSUM and PAM matrices: ARNDCQEGHILKMFPSTWYV, then the stop (*),
+    // which NCBI's matrices score too
+    fn index(&self) -> usize {
+        use amino_acids::AminoAcid::*;
+
+        match self {
+            Alanine => 0,
+            Arginine => 1,
+            Asparagine => 2,
+            AsparticAcid => 3,
+            Cysteine => 4,
+            Glutamine => 5,
+            GlutamicAcid => 6,
+            Glycine => 7,
+            Histidine => 8,
+            Isoleucine => 9,
+            Leucine => 10,
+            Lysine => 11,
+            Methionine => 12,
+            Phenyalalanine => 13,
+            Proline => 14,
+            Serine => 15,
+            Threonine => 16,
+            Tryptophan => 17,
+            Tyrosine => 18,
+            Valine => 19,
+            Stop => 20,
+        }
+    }
+}
+
+// converts a sequence of symbols to the indexes used to look up scores
+fn encode<S: Symbol>(sequence: &[S]) -> Vec<usize> {
+    sequence.iter().map(|symbol| symbol.index()).collect()
+}
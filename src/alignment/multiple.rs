@@ -0,0 +1,575 @@
+// progressive multiple sequence alignment, in the spirit of ClustalW:
+//
+// 1. every pair of sequences is aligned globally and their distance is 1 - (identity of the alignment)
+// 2. a guide tree is built from the distances with UPGMA, so that the closest sequences are joined first
+// 3. the tree is walked from the leaves to the root, aligning profiles (groups of already aligned sequences)
+//    two by two; once two sequences are aligned, the gaps between them are never removed ("once a gap, always a gap")
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::{Nucleobase, Sequence as DnaSequence};
+    use alignment::scoring::Scoring;
+    use super::GuideTree;
+
+    fn dna_sequences(strings: &[&str]) -> Vec<DnaSequence> {
+        strings
+            .iter()
+            .map(|string| DnaSequence::try_from(*string).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn align_sequence_with_deletion() {
+        let sequences = dna_sequences(&["ACGTACGT", "ACGTCGT"]);
+        let scoring = Scoring::<Nucleobase>::match_mismatch(1, -1, 2, 1);
+
+        let alignment = super::align(&sequences, &scoring);
+
+        assert_eq!(alignment.to_string(), "ACGTACGT\nACGT-CGT");
+    }
+
+    #[test]
+    fn align_identical_sequences() {
+        let sequences = dna_sequences(&["GATTACA", "GATTACA", "GATTACA"]);
+        let scoring = Scoring::<Nucleobase>::match_mismatch(1, -1, 2, 1);
+
+        let alignment = super::align(&sequences, &scoring);
+
+        assert_eq!(alignment.len(), 7);
+        assert_eq!(alignment.to_string(), "GATTACA\nGATTACA\nGATTACA");
+    }
+
+    #[test]
+    fn align_keeps_input_order_and_symbols() {
+        let sequences = dna_sequences(&[
+            "ATCCAGCTAAGT",
+            "GGGCAACTGT",
+            "ATGGATCTAAGT",
+            "AAGCAACCGT",
+            "TTGGAACTAGT",
+            "ATGCCATTGT",
+        ]);
+        let scoring = Scoring::<Nucleobase>::match_mismatch(2, -1, 3, 1);
+
+        let alignment = super::align(&sequences, &scoring);
+
+        assert_eq!(alignment.number_of_sequences(), sequences.len());
+
+        for (index, sequence) in sequences.iter().enumerate() {
+            assert_eq!(alignment.rows()[index].len(), alignment.len());
+            assert_eq!(alignment.ungapped_row(index), sequence.as_ref());
+        }
+    }
+
+    #[test]
+    fn align_no_sequences() {
+        let scoring = Scoring::<Nucleobase>::match_mismatch(1, -1, 2, 1);
+
+        let alignment = super::align::<Nucleobase, DnaSequence>(&[], &scoring);
+
+        assert_eq!(alignment.number_of_sequences(), 0);
+        assert_eq!(alignment.len(), 0);
+    }
+
+    #[test]
+    fn upgma_joins_closest_sequences_first() {
+        let distances = vec![
+            vec![0.0, 0.1, 0.8, 0.9],
+            vec![0.1, 0.0, 0.7, 0.8],
+            vec![0.8, 0.7, 0.0, 0.2],
+            vec![0.9, 0.8, 0.2, 0.0],
+        ];
+
+        let tree = GuideTree::upgma(&distances).unwrap();
+
+        assert_eq!(
+            tree,
+            GuideTree::Node(
+                Box::new(GuideTree::Node(
+                    Box::new(GuideTree::Leaf(0)),
+                    Box::new(GuideTree::Leaf(1)),
+                )),
+                Box::new(GuideTree::Node(
+                    Box::new(GuideTree::Leaf(2)),
+                    Box::new(GuideTree::Leaf(3)),
+                )),
+            )
+        );
+
+        assert_eq!(tree.leaves(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn distance_matrix() {
+        let sequences = dna_sequences(&["ACGTACGT", "ACGTACGA", "ACGTACGT"]);
+        let scoring = Scoring::<Nucleobase>::match_mismatch(1, -1, 2, 1);
+
+        let distances = super::distance_matrix(&sequences, &scoring);
+
+        assert_eq!(distances[0][2], 0.0);
+        assert_eq!(distances[0][1], 1.0 / 8.0);
+        assert_eq!(distances[1][0], distances[0][1]);
+    }
+}
+
+use std::{f64, fmt};
+use super::{encode, Symbol};
+use super::scoring::Scoring;
+
+/// A set of sequences aligned with each other, gaps being represented by None.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alignment<S> {
+    rows: Vec<Vec<Option<S>>>,
+}
+
+impl<S: Clone> Alignment<S> {
+    /// Creates an alignment from rows of equal length, returns None if the rows have different lengths.
+    pub fn new(rows: Vec<Vec<Option<S>>>) -> Option<Self> {
+        let all_same_length = match rows.first() {
+            Some(first_row) => rows.iter().all(|row| row.len() == first_row.len()),
+            None => true,
+        };
+
+        if all_same_length {
+            Some(Alignment { rows: rows })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of columns of the alignment.
+    pub fn len(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn number_of_sequences(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the aligned sequences, in the same order as the sequences passed to `align`.
+    pub fn rows(&self) -> &[Vec<Option<S>>] {
+        &self.rows
+    }
+
+    /// Returns the column at `index`, one symbol (or gap) per sequence.
+    pub fn column(&self, index: usize) -> Vec<Option<S>> {
+        self.rows.iter().map(|row| row[index].clone()).collect()
+    }
+
+    /// Returns the sequence of row `index` without its gaps.
+    pub fn ungapped_row(&self, index: usize) -> Vec<S> {
+        self.rows[index].iter().filter_map(|symbol| symbol.clone()).collect()
+    }
+}
+
+/// Displays every aligned sequence on its own line, with '-' for gaps.
+impl<S: fmt::Display> fmt::Display for Alignment<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, row) in self.rows.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            for symbol in row {
+                match symbol {
+                    Some(symbol) => write!(f, "{}", symbol)?,
+                    None => write!(f, "-")?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Binary tree giving the order in which sequences are aligned, leaves being indexes of sequences.
+#[derive(Debug, PartialEq)]
+pub enum GuideTree {
+    Leaf(usize),
+    Node(Box<GuideTree>, Box<GuideTree>),
+}
+
+impl GuideTree {
+    /// Builds the guide tree from a symmetric matrix of distances with UPGMA
+    /// (the two closest clusters are joined first, ties going to the lowest indexes).
+    ///
+    /// Returns None if the matrix is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosalind::alignment::multiple::GuideTree;
+    ///
+    /// let distances = vec![
+    ///     vec![0.0, 0.5, 0.1],
+    ///     vec![0.5, 0.0, 0.6],
+    ///     vec![0.1, 0.6, 0.0],
+    /// ];
+    ///
+    /// let tree = GuideTree::upgma(&distances).unwrap();
+    ///
+    /// assert_eq!(tree.leaves(), vec![0, 2, 1]);
+    /// ```
+    pub fn upgma(distances: &[Vec<f64>]) -> Option<Self> {
+        // (tree, number of sequences in the tree), None once the cluster has been merged into another one
+        let mut clusters: Vec<Option<(GuideTree, usize)>> = (0..distances.len())
+            .map(|index| Some((GuideTree::Leaf(index), 1)))
+            .collect();
+
+        let mut distances = distances.to_vec();
+
+        for _ in 1..clusters.len() {
+            let mut closest: Option<(usize, usize)> = None;
+
+            for a in 0..clusters.len() {
+                for b in a + 1..clusters.len() {
+                    if clusters[a].is_none() || clusters[b].is_none() {
+                        continue;
+                    }
+
+                    closest = match closest {
+                        Some((best_a, best_b)) if distances[best_a][best_b] <= distances[a][b] => {
+                            Some((best_a, best_b))
+                        }
+                        _ => Some((a, b)),
+                    };
+                }
+            }
+
+            let (a, b) = closest.expect("There are at least two clusters left");
+
+            let (tree_a, size_a) = clusters[a].take().unwrap();
+            let (tree_b, size_b) = clusters[b].take().unwrap();
+
+            // the merged cluster takes the place of a, its distance to other clusters is the average distance
+            for other in 0..clusters.len() {
+                let distance = (distances[a][other] * size_a as f64
+                    + distances[b][other] * size_b as f64)
+                    / (size_a + size_b) as f64;
+
+                distances[a][other] = distance;
+                distances[other][a] = distance;
+            }
+
+            clusters[a] = Some((
+                GuideTree::Node(Box::new(tree_a), Box::new(tree_b)),
+                size_a + size_b,
+            ));
+        }
+
+        clusters
+            .into_iter()
+            .filter_map(|cluster| cluster)
+            .next()
+            .map(|(tree, _)| tree)
+    }
+
+    /// Returns the indexes of the sequences, from the leftmost leaf to the rightmost.
+    pub fn leaves(&self) -> Vec<usize> {
+        match self {
+            GuideTree::Leaf(index) => vec![*index],
+            GuideTree::Node(left, right) => {
+                let mut leaves = left.leaves();
+                leaves.extend(right.leaves());
+                leaves
+            }
+        }
+    }
+}
+
+/// Returns the matrix of distances between every pair of sequences: 1 - the identity of their global alignment,
+/// the identity being the number of identical columns divided by the number of columns without gaps.
+pub fn distance_matrix<S, Q>(sequences: &[Q], scoring: &Scoring<S>) -> Vec<Vec<f64>>
+where
+    S: Symbol,
+    Q: AsRef<[S]>,
+{
+    let profiles: Vec<Profile> = sequences
+        .iter()
+        .enumerate()
+        .map(|(index, sequence)| Profile::from_sequence(index, &encode(sequence.as_ref())))
+        .collect();
+
+    let mut distances = vec![vec![0.0; sequences.len()]; sequences.len()];
+
+    for a in 0..profiles.len() {
+        for b in a + 1..profiles.len() {
+            let aligned = profiles[a].align(&profiles[b], scoring);
+
+            let (identical, ungapped) = aligned.rows[0].iter().zip(&aligned.rows[1]).fold(
+                (0, 0),
+                |(identical, ungapped), pair| match pair {
+                    (Some(x), Some(y)) if x == y => (identical + 1, ungapped + 1),
+                    (Some(_), Some(_)) => (identical, ungapped + 1),
+                    _ => (identical, ungapped),
+                },
+            );
+
+            let distance = match ungapped {
+                0 => 1.0,
+                _ => 1.0 - identical as f64 / ungapped as f64,
+            };
+
+            distances[a][b] = distance;
+            distances[b][a] = distance;
+        }
+    }
+
+    distances
+}
+
+/// Aligns all the `sequences` progressively, following an UPGMA guide tree.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::alignment::{multiple, scoring::Scoring};
+/// use rosalind::dna::{Nucleobase, Sequence};
+///
+/// let sequences: Vec<Sequence> = ["GATTACA", "GATACA", "GCATTACA"]
+///     .iter()
+///     .map(|string| Sequence::try_from(*string).unwrap())
+///     .collect();
+///
+/// let alignment = multiple::align(&sequences, &Scoring::<Nucleobase>::match_mismatch(1, -1, 2, 1));
+///
+/// println!("{}", alignment);
+/// ```
+pub fn align<S, Q>(sequences: &[Q], scoring: &Scoring<S>) -> Alignment<S>
+where
+    S: Symbol + Clone,
+    Q: AsRef<[S]>,
+{
+    let tree = match GuideTree::upgma(&distance_matrix(sequences, scoring)) {
+        Some(tree) => tree,
+        None => return Alignment { rows: Vec::new() },
+    };
+
+    let profile = align_along_tree(&tree, sequences, scoring);
+
+    // the profile rows follow the leaves of the tree, put them back in the order of the input
+    let mut rows = vec![Vec::new(); sequences.len()];
+
+    for (member, encoded_row) in profile.members.iter().zip(profile.rows) {
+        let mut symbols = sequences[*member].as_ref().iter();
+
+        rows[*member] = encoded_row
+            .into_iter()
+            .map(|symbol| symbol.and_then(|_| symbols.next().cloned()))
+            .collect();
+    }
+
+    Alignment { rows: rows }
+}
+
+fn align_along_tree<S, Q>(tree: &GuideTree, sequences: &[Q], scoring: &Scoring<S>) -> Profile
+where
+    S: Symbol,
+    Q: AsRef<[S]>,
+{
+    match tree {
+        GuideTree::Leaf(index) => {
+            Profile::from_sequence(*index, &encode(sequences[*index].as_ref()))
+        }
+        GuideTree::Node(left, right) => {
+            let left = align_along_tree(left, sequences, scoring);
+            let right = align_along_tree(right, sequences, scoring);
+
+            left.align(&right, scoring)
+        }
+    }
+}
+
+// a group of aligned sequences, with symbols encoded as indexes
+struct Profile {
+    // indexes of the sequences in the input of `align`
+    members: Vec<usize>,
+    rows: Vec<Vec<Option<usize>>>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Match,
+    GapInRight,
+    GapInLeft,
+}
+
+impl Profile {
+    fn from_sequence(index: usize, sequence: &[usize]) -> Self {
+        Profile {
+            members: vec![index],
+            rows: vec![sequence.iter().map(|&symbol| Some(symbol)).collect()],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    // frequency of every symbol in every column (gaps don't count, so a column may sum to less than 1)
+    fn frequencies(&self, alphabet_size: usize) -> Vec<Vec<f64>> {
+        let weight = 1.0 / self.rows.len() as f64;
+
+        (0..self.len())
+            .map(|column| {
+                let mut frequencies = vec![0.0; alphabet_size];
+
+                for row in &self.rows {
+                    if let Some(symbol) = row[column] {
+                        frequencies[symbol] += weight;
+                    }
+                }
+
+                frequencies
+            })
+            .collect()
+    }
+
+    // global alignment of two profiles with affine gaps (Gotoh), columns being scored
+    // by the average substitution score between their symbols (sum-of-pairs)
+    fn align<S: Symbol>(&self, other: &Profile, scoring: &Scoring<S>) -> Profile {
+        let size = S::ALPHABET_SIZE;
+        let (n, m) = (self.len(), other.len());
+
+        let left_frequencies = self.frequencies(size);
+
+        // expected score of aligning each symbol against each column of the right profile
+        let right_weights: Vec<Vec<f64>> = other
+            .frequencies(size)
+            .iter()
+            .map(|frequencies| {
+                (0..size)
+                    .map(|a| {
+                        (0..size)
+                            .map(|b| frequencies[b] * scoring.score(a, b) as f64)
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let column_score = |i: usize, j: usize| -> f64 {
+            left_frequencies[i]
+                .iter()
+                .zip(&right_weights[j])
+                .map(|(frequency, weight)| frequency * weight)
+                .sum()
+        };
+
+        let gap_open = scoring.gap_open() as f64;
+        let gap_extend = scoring.gap_extend() as f64;
+
+        let mut matches = vec![vec![f64::NEG_INFINITY; m + 1]; n + 1];
+        let mut gaps_in_right = vec![vec![f64::NEG_INFINITY; m + 1]; n + 1];
+        let mut gaps_in_left = vec![vec![f64::NEG_INFINITY; m + 1]; n + 1];
+
+        matches[0][0] = 0.0;
+
+        for i in 1..n + 1 {
+            gaps_in_right[i][0] = -gap_open - (i - 1) as f64 * gap_extend;
+        }
+
+        for j in 1..m + 1 {
+            gaps_in_left[0][j] = -gap_open - (j - 1) as f64 * gap_extend;
+        }
+
+        for i in 1..n + 1 {
+            for j in 1..m + 1 {
+                matches[i][j] = matches[i - 1][j - 1]
+                    .max(gaps_in_right[i - 1][j - 1])
+                    .max(gaps_in_left[i - 1][j - 1])
+                    + column_score(i - 1, j - 1);
+
+                gaps_in_right[i][j] = (matches[i - 1][j] - gap_open)
+                    .max(gaps_in_right[i - 1][j] - gap_extend)
+                    .max(gaps_in_left[i - 1][j] - gap_open);
+
+                gaps_in_left[i][j] = (matches[i][j - 1] - gap_open)
+                    .max(gaps_in_left[i][j - 1] - gap_extend)
+                    .max(gaps_in_right[i][j - 1] - gap_open);
+            }
+        }
+
+        // state with the best score in a cell, ties going to Match, then GapInRight
+        let best_state = |i: usize, j: usize| -> State {
+            if matches[i][j] >= gaps_in_right[i][j] && matches[i][j] >= gaps_in_left[i][j] {
+                State::Match
+            } else if gaps_in_right[i][j] >= gaps_in_left[i][j] {
+                State::GapInRight
+            } else {
+                State::GapInLeft
+            }
+        };
+
+        // columns of the alignment, from the end: (column of the left profile, column of the right profile)
+        let mut columns: Vec<(Option<usize>, Option<usize>)> = Vec::with_capacity(n + m);
+
+        let (mut i, mut j) = (n, m);
+        let mut state = best_state(i, j);
+
+        while i > 0 || j > 0 {
+            state = match state {
+                State::Match => {
+                    columns.push((Some(i - 1), Some(j - 1)));
+                    i -= 1;
+                    j -= 1;
+                    best_state(i, j)
+                }
+                State::GapInRight => {
+                    columns.push((Some(i - 1), None));
+                    i -= 1;
+
+                    let score = gaps_in_right[i + 1][j];
+
+                    if score == matches[i][j] - gap_open {
+                        State::Match
+                    } else if score == gaps_in_right[i][j] - gap_extend {
+                        State::GapInRight
+                    } else {
+                        State::GapInLeft
+                    }
+                }
+                State::GapInLeft => {
+                    columns.push((None, Some(j - 1)));
+                    j -= 1;
+
+                    let score = gaps_in_left[i][j + 1];
+
+                    if score == matches[i][j] - gap_open {
+                        State::Match
+                    } else if score == gaps_in_left[i][j] - gap_extend {
+                        State::GapInLeft
+                    } else {
+                        State::GapInRight
+                    }
+                }
+            };
+        }
+
+        columns.reverse();
+
+        let mut rows = Vec::with_capacity(self.rows.len() + other.rows.len());
+
+        for row in &self.rows {
+            rows.push(columns.iter().map(|&(i, _)| i.and_then(|i| row[i])).collect());
+        }
+
+        for row in &other.rows {
+            rows.push(columns.iter().map(|&(_, j)| j.and_then(|j| row[j])).collect());
+        }
+
+        let mut members = self.members.clone();
+        members.extend(other.members.iter().cloned());
+
+        Profile {
+            members: members,
+            rows: rows,
+        }
+    }
+}
@@ -0,0 +1,260 @@
+#[cfg(test)]
+mod tests {
+    use amino_acids::AminoAcid;
+    use dna::Nucleobase;
+
+    #[test]
+    fn blosum62_is_symmetric() {
+        let scoring = super::Scoring::<AminoAcid>::blosum62(11, 1);
+
+        for a in 0..21 {
+            for b in 0..21 {
+                assert_eq!(scoring.score(a, b), scoring.score(b, a));
+            }
+        }
+    }
+
+    #[test]
+    fn blosum62_scores() {
+        use amino_acids::AminoAcid::*;
+
+        let scoring = super::Scoring::<AminoAcid>::blosum62(11, 1);
+
+        assert_eq!(scoring.symbol_score(&Tryptophan, &Tryptophan), 11);
+        assert_eq!(scoring.symbol_score(&Glutamine, &GlutamicAcid), 2);
+        assert_eq!(scoring.symbol_score(&Proline, &Phenyalalanine), -4);
+        assert_eq!(scoring.symbol_score(&Stop, &Stop), 1);
+        assert_eq!(scoring.symbol_score(&Stop, &Tryptophan), -4);
+        assert_eq!(scoring.max_score(), 11);
+    }
+
+    #[test]
+    fn match_mismatch() {
+        use dna::Nucleobase::*;
+
+        let scoring = super::Scoring::<Nucleobase>::match_mismatch(2, -3, 5, 2);
+
+        assert_eq!(scoring.symbol_score(&Adenine, &Adenine), 2);
+        assert_eq!(scoring.symbol_score(&Adenine, &Guanine), -3);
+        assert_eq!(scoring.gap_cost(1), 5);
+        assert_eq!(scoring.gap_cost(4), 11);
+    }
+
+    #[test]
+    fn new_with_wrong_matrix_size() {
+        assert_eq!(
+            super::Scoring::<Nucleobase>::new(vec![1, 2, 3], 5, 2).unwrap_err(),
+            super::ScoringError::MatrixSize {
+                expected: 16,
+                actual: 3
+            },
+        );
+    }
+}
+
+use std::{error, fmt, marker};
+use amino_acids::AminoAcid;
+use super::Symbol;
+
+// BLOSUM62, rows and columns in the ARNDCQEGHILKMFPSTWYV* order (see Symbol for AminoAcid)
+#[rustfmt::skip]
+const BLOSUM62: [i32; 441] = [
+     4, -1, -2, -2,  0, -1, -1,  0, -2, -1, -1, -1, -1, -2, -1,  1,  0, -3, -2,  0, -4,
+    -1,  5,  0, -2, -3,  1,  0, -2,  0, -3, -2,  2, -1, -3, -2, -1, -1, -3, -2, -3, -4,
+    -2,  0,  6,  1, -3,  0,  0,  0,  1, -3, -3,  0, -2, -3, -2,  1,  0, -4, -2, -3, -4,
+    -2, -2,  1,  6, -3,  0,  2, -1, -1, -3, -4, -1, -3, -3, -1,  0, -1, -4, -3, -3, -4,
+     0, -3, -3, -3,  9, -3, -4, -3, -3, -1, -1, -3, -1, -2, -3, -1, -1, -2, -2, -1, -4,
+    -1,  1,  0,  0, -3,  5,  2, -2,  0, -3, -2,  1,  0, -3, -1,  0, -1, -2, -1, -2, -4,
+    -1,  0,  0,  2, -4,  2,  5, -2,  0, -3, -3,  1, -2, -3, -1,  0, -1, -3, -2, -2, -4,
+     0, -2,  0, -1, -3, -2, -2,  6, -2, -4, -4, -2, -3, -3, -2,  0, -2, -2, -3, -3, -4,
+    -2,  0,  1, -1, -3,  0,  0, -2,  8, -3, -3, -1, -2, -1, -2, -1, -2, -2,  2, -3, -4,
+    -1, -3, -3, -3, -1, -3, -3, -4, -3,  4,  2, -3,  1,  0, -3, -2, -1, -3, -1,  3, -4,
+    -1, -2, -3, -4, -1, -2, -3, -4, -3,  2,  4, -2,  2,  0, -3, -2, -1, -2, -1,  1, -4,
+    -1,  2,  0, -1, -3,  1,  1, -2, -1, -3, -2,  5, -1, -3, -1,  0, -1, -3, -2, -2, -4,
+    -1, -1, -2, -3, -1,  0, -2, -3, -2,  1,  2, -1,  5,  0, -2, -1, -1, -1, -1,  1, -4,
+    -2, -3, -3, -3, -2, -3, -3, -3, -1,  0,  0, -3,  0,  6, -4, -2, -2,  1,  3, -1, -4,
+    -1, -2, -2, -1, -3, -1, -1, -2, -2, -3, -3, -1, -2, -4,  7, -1, -1, -4, -3, -2, -4,
+     1, -1,  1,  0, -1,  0,  0,  0, -1, -2, -2,  0, -1, -2, -1,  4,  1, -3, -2, -2, -4,
+     0, -1,  0, -1, -1, -1, -1, -2, -2, -1, -1, -1, -1, -2, -1,  1,  5, -2, -2,  0, -4,
+    -3, -3, -4, -4, -2, -2, -3, -2, -2, -3, -2, -3, -1,  1, -4, -3, -2, 11,  2, -3, -4,
+    -2, -2, -2, -3, -2, -1, -2, -3,  2, -1, -1, -2, -1,  3, -3, -2, -2,  2,  7, -1, -4,
+     0, -3, -3, -3, -1, -2, -2, -3, -3,  3,  1, -2,  1, -1, -2, -2,  0, -3, -1,  4, -4,
+    -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,  1,
+];
+
+/// Substitution scores and affine gap penalties used to align sequences of symbols `S`.
+///
+/// A gap of length `k` costs `gap_open + (k - 1) * gap_extend`, both penalties being given as positive numbers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scoring<S> {
+    matrix: Vec<i32>,
+    gap_open: i32,
+    gap_extend: i32,
+    symbol: marker::PhantomData<S>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ScoringError {
+    MatrixSize { expected: usize, actual: usize },
+    GapPenalties { gap_open: i32, gap_extend: i32 },
+}
+
+impl error::Error for ScoringError {
+    fn description(&self) -> &str {
+        match self {
+            ScoringError::MatrixSize { .. } => {
+                "the substitution matrix doesn't match the size of the alphabet"
+            }
+            ScoringError::GapPenalties { .. } => {
+                "gap penalties must be positive and opening a gap cannot be cheaper than extending it"
+            }
+        }
+    }
+}
+
+impl fmt::Display for ScoringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScoringError::MatrixSize { expected, actual } => write!(
+                f,
+                "the substitution matrix should contain {} scores but contains {}",
+                expected, actual
+            ),
+            ScoringError::GapPenalties {
+                gap_open,
+                gap_extend,
+            } => write!(
+                f,
+                "invalid gap penalties: open {}, extend {} (expected 0 <= extend <= open)",
+                gap_open, gap_extend
+            ),
+        }
+    }
+}
+
+impl<S: Symbol> Scoring<S> {
+    /// Creates a scoring scheme from a row-major substitution matrix of `S::ALPHABET_SIZE` x `S::ALPHABET_SIZE` scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosalind::alignment::scoring::Scoring;
+    /// use rosalind::dna::Nucleobase;
+    ///
+    /// let transition_friendly = vec![
+    ///      2, -3, -1, -3,
+    ///     -3,  2, -3, -1,
+    ///     -1, -3,  2, -3,
+    ///     -3, -1, -3,  2,
+    /// ];
+    ///
+    /// let scoring = Scoring::<Nucleobase>::new(transition_friendly, 5, 2).unwrap();
+    /// ```
+    pub fn new(matrix: Vec<i32>, gap_open: i32, gap_extend: i32) -> Result<Self, ScoringError> {
+        let expected = S::ALPHABET_SIZE * S::ALPHABET_SIZE;
+
+        if matrix.len() != expected {
+            return Err(ScoringError::MatrixSize {
+                expected: expected,
+                actual: matrix.len(),
+            });
+        }
+
+        if gap_extend < 0 || gap_open < gap_extend {
+            return Err(ScoringError::GapPenalties {
+                gap_open: gap_open,
+                gap_extend: gap_extend,
+            });
+        }
+
+        Ok(Scoring {
+            matrix: matrix,
+            gap_open: gap_open,
+            gap_extend: gap_extend,
+            symbol: marker::PhantomData,
+        })
+    }
+
+    /// Creates a scoring scheme where identical symbols score `match_score` and different ones `mismatch_score`.
+    ///
+    /// Panics if the gap penalties are negative or if `gap_open` < `gap_extend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosalind::alignment::scoring::Scoring;
+    /// use rosalind::dna::Nucleobase;
+    ///
+    /// let scoring = Scoring::<Nucleobase>::match_mismatch(2, -3, 5, 2);
+    /// ```
+    pub fn match_mismatch(match_score: i32, mismatch_score: i32, gap_open: i32, gap_extend: i32) -> Self {
+        let size = S::ALPHABET_SIZE;
+
+        let matrix = (0..size * size)
+            .map(|index| {
+                if index / size == index % size {
+                    match_score
+                } else {
+                    mismatch_score
+                }
+            })
+            .collect();
+
+        Scoring::new(matrix, gap_open, gap_extend).expect("Invalid gap penalties")
+    }
+
+    /// Returns the score of substituting the symbol of index `a` with the symbol of index `b`.
+    #[inline]
+    pub fn score(&self, a: usize, b: usize) -> i32 {
+        self.matrix[a * S::ALPHABET_SIZE + b]
+    }
+
+    /// Returns the score of substituting symbol `a` with symbol `b`.
+    pub fn symbol_score(&self, a: &S, b: &S) -> i32 {
+        self.score(a.index(), b.index())
+    }
+
+    /// Returns the highest score of the substitution matrix.
+    pub fn max_score(&self) -> i32 {
+        self.matrix.iter().cloned().max().unwrap_or(0)
+    }
+
+    /// Returns the lowest score of the substitution matrix.
+    pub fn min_score(&self) -> i32 {
+        self.matrix.iter().cloned().min().unwrap_or(0)
+    }
+
+    pub fn gap_open(&self) -> i32 {
+        self.gap_open
+    }
+
+    pub fn gap_extend(&self) -> i32 {
+        self.gap_extend
+    }
+
+    /// Returns the penalty of a gap of `length` symbols.
+    pub fn gap_cost(&self, length: usize) -> i32 {
+        match length {
+            0 => 0,
+            _ => self.gap_open + (length as i32 - 1) * self.gap_extend,
+        }
+    }
+}
+
+impl Scoring<AminoAcid> {
+    /// Creates a scoring scheme based on the BLOSUM62 substitution matrix.
+    ///
+    /// Panics if the gap penalties are negative or if `gap_open` < `gap_extend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosalind::alignment::scoring::Scoring;
+    /// use rosalind::amino_acids::AminoAcid;
+    ///
+    /// let scoring = Scoring::<AminoAcid>::blosum62(11, 1);
+    /// ```
+    pub fn blosum62(gap_open: i32, gap_extend: i32) -> Self {
+        Scoring::new(BLOSUM62.to_vec(), gap_open, gap_extend).expect("Invalid gap penalties")
+    }
+}
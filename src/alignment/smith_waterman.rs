@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::{AminoAcid, Sequence as AaSequence};
+    use dna::{Nucleobase, Sequence as DnaSequence};
+    use alignment::scoring::Scoring;
+
+    #[test]
+    fn score_identical_dna_sequences() {
+        let sequence = DnaSequence::try_from("ACGTACGT").unwrap();
+        let scoring = Scoring::<Nucleobase>::match_mismatch(2, -3, 5, 2);
+
+        assert_eq!(super::score(&sequence, &sequence, &scoring), 16);
+    }
+
+    #[test]
+    fn score_dna_with_gap() {
+        let query = DnaSequence::try_from("ACGTACGT").unwrap();
+        let target = DnaSequence::try_from("ACGTGGACGT").unwrap();
+        // ACGT--ACGT: 4 matches, a gap of length 2, 4 matches => 8 - (5 + 2) + 8 = 9
+        let scoring = Scoring::<Nucleobase>::match_mismatch(2, -3, 5, 2);
+
+        assert_eq!(super::score(&query, &target, &scoring), 9);
+    }
+
+    #[test]
+    fn score_unrelated_sequences() {
+        let query = DnaSequence::try_from("AAAA").unwrap();
+        let target = DnaSequence::try_from("CCCC").unwrap();
+        let scoring = Scoring::<Nucleobase>::match_mismatch(2, -3, 5, 2);
+
+        assert_eq!(super::score(&query, &target, &scoring), 0);
+    }
+
+    #[test]
+    fn score_proteins_with_blosum62() {
+        let query = AaSequence::try_from("MEANLY").unwrap();
+        let target = AaSequence::try_from("PLEASANTLY").unwrap();
+        let scoring = Scoring::<AminoAcid>::blosum62(11, 1);
+
+        assert_eq!(super::score(&query, &target, &scoring), 12);
+    }
+
+    #[test]
+    fn score_proteins_with_stops() {
+        let query = AaSequence::try_from("MW*").unwrap();
+        let target = AaSequence::try_from("AMW*A").unwrap();
+        let scoring = Scoring::<AminoAcid>::blosum62(11, 1);
+
+        // M and W score 5 and 11, and the stops align too, scoring 1
+        assert_eq!(super::score(&query, &target, &scoring), 17);
+    }
+}
+
+use super::{encode, Symbol};
+use super::scoring::Scoring;
+
+/// Returns the score of the best local alignment between `query` and `target` (Smith-Waterman with affine gaps).
+///
+/// This is the reference implementation, see `alignment::striped` for a faster one when scanning many targets.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::alignment::{scoring::Scoring, smith_waterman};
+/// use rosalind::amino_acids::{AminoAcid, Sequence};
+///
+/// let query = Sequence::try_from("MEANLY").unwrap();
+/// let target = Sequence::try_from("PLEASANTLY").unwrap();
+///
+/// let score = smith_waterman::score(&query, &target, &Scoring::<AminoAcid>::blosum62(11, 1));
+/// ```
+pub fn score<S, Q>(query: &Q, target: &Q, scoring: &Scoring<S>) -> i32
+where
+    S: Symbol,
+    Q: AsRef<[S]> + ?Sized,
+{
+    score_encoded(
+        &encode(query.as_ref()),
+        &encode(target.as_ref()),
+        scoring,
+    )
+}
+
+// Gotoh's algorithm, keeping only one column of the matrices in memory:
+//
+// H(i, j) = max(0, E(i, j), F(i, j), H(i - 1, j - 1) + score(query[i], target[j]))
+// E(i, j) = max(E(i, j - 1) - extend, H(i, j - 1) - open)   (gap in the query)
+// F(i, j) = max(F(i - 1, j) - extend, H(i - 1, j) - open)   (gap in the target)
+//
+// E and F are floored at 0: a negative value can never beat the 0 of H anyway
+pub(crate) fn score_encoded<S: Symbol>(query: &[usize], target: &[usize], scoring: &Scoring<S>) -> i32 {
+    let gap_open = scoring.gap_open();
+    let gap_extend = scoring.gap_extend();
+
+    let mut h_column = vec![0; query.len() + 1];
+    let mut e_column = vec![0; query.len() + 1];
+
+    let mut best = 0;
+
+    for &target_symbol in target {
+        let mut h_diagonal = 0;
+        let mut f = 0;
+
+        for (i, &query_symbol) in query.iter().enumerate() {
+            let e = (e_column[i + 1] - gap_extend)
+                .max(h_column[i + 1] - gap_open)
+                .max(0);
+
+            f = (f - gap_extend).max(h_column[i] - gap_open).max(0);
+
+            let h = (h_diagonal + scoring.score(query_symbol, target_symbol))
+                .max(e)
+                .max(f)
+                .max(0);
+
+            h_diagonal = h_column[i + 1];
+            h_column[i + 1] = h;
+            e_column[i + 1] = e;
+
+            best = best.max(h);
+        }
+    }
+
+    best
+}
@@ -0,0 +1,587 @@
+// striped Smith-Waterman (Farrar, 2007), score only
+//
+// the query is split into `lanes` segments of `segment_length` symbols, the symbol at position
+// `segment + lane * segment_length` being processed in lane `lane` of vector number `segment`:
+//
+//   query: ABCDEFGHIJKL, 4 lanes => segment_length = 3
+//
+//   vector 0: A D G J
+//   vector 1: B E H K
+//   vector 2: C F I L
+//
+// this way, the dependencies between consecutive query positions (diagonal and vertical gaps)
+// are between consecutive vectors instead of between lanes of the same vector,
+// except for the vertical gaps crossing segments, which are fixed afterwards by the "lazy F" loop
+//
+// scores are computed on saturating 16-bit integers; when a score gets close enough to saturation
+// to be wrong, the alignment is computed again with the scalar implementation, so scores are always exact
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::{AminoAcid, Sequence as AaSequence};
+    use dna::{Nucleobase, Sequence as DnaSequence};
+    use alignment::scoring::Scoring;
+    use alignment::smith_waterman;
+    use super::{Backend, QueryProfile};
+
+    // a linear congruential generator is good enough to generate test sequences
+    fn pseudo_random_indexes(seed: u64, length: usize, modulo: usize) -> Vec<usize> {
+        let mut state = seed;
+
+        (0..length)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                ((state >> 33) as usize) % modulo
+            })
+            .collect()
+    }
+
+    fn random_protein(seed: u64, length: usize) -> AaSequence {
+        const ALPHABET: &str = "ARNDCQEGHILKMFPSTWYV";
+
+        let string: String = pseudo_random_indexes(seed, length, 20)
+            .into_iter()
+            .map(|index| ALPHABET.as_bytes()[index] as char)
+            .collect();
+
+        AaSequence::try_from(string.as_str()).unwrap()
+    }
+
+    fn random_dna(seed: u64, length: usize) -> DnaSequence {
+        let string: String = pseudo_random_indexes(seed, length, 4)
+            .into_iter()
+            .map(|index| "ACGT".as_bytes()[index] as char)
+            .collect();
+
+        DnaSequence::try_from(string.as_str()).unwrap()
+    }
+
+    fn available_backends() -> Vec<Backend> {
+        vec![Backend::Scalar, Backend::Sse2, Backend::Avx2]
+            .into_iter()
+            .filter(|backend| backend.is_available())
+            .collect()
+    }
+
+    #[test]
+    fn striped_scores_match_scalar_scores_for_proteins() {
+        let scoring = Scoring::<AminoAcid>::blosum62(11, 1);
+
+        for (seed, &query_length) in [1, 5, 7, 8, 9, 16, 17, 33, 100, 257].iter().enumerate() {
+            let query = random_protein(seed as u64, query_length);
+
+            for backend in available_backends() {
+                let profile = QueryProfile::with_backend(&query, &scoring, backend).unwrap();
+
+                for target_seed in 0..10 {
+                    let target = random_protein(1000 + target_seed, 10 + target_seed as usize * 37);
+
+                    assert_eq!(
+                        profile.score(&target),
+                        smith_waterman::score(&query, &target, &scoring),
+                        "backend {:?}, query {}, target {}",
+                        backend,
+                        query,
+                        target
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn striped_scores_match_scalar_scores_for_similar_proteins() {
+        // mutated copies of the query, to get long alignments with gaps crossing segments
+        let scoring = Scoring::<AminoAcid>::blosum62(5, 1);
+        let query = random_protein(42, 150);
+
+        for backend in available_backends() {
+            let profile = QueryProfile::with_backend(&query, &scoring, backend).unwrap();
+
+            for seed in 0..10 {
+                let mut target = query.0.clone();
+                let positions = pseudo_random_indexes(seed, 10, target.len() - 40);
+
+                for (count, &position) in positions.iter().enumerate() {
+                    if count % 2 == 0 {
+                        target.drain(position..position + 1 + count % 5);
+                    } else {
+                        target.insert(position, AminoAcid::Tryptophan);
+                    }
+                }
+
+                let target = AaSequence(target);
+
+                assert_eq!(
+                    profile.score(&target),
+                    smith_waterman::score(&query, &target, &scoring),
+                    "backend {:?}, target {}",
+                    backend,
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn striped_scores_match_scalar_scores_for_dna() {
+        let scoring = Scoring::<Nucleobase>::match_mismatch(2, -3, 5, 2);
+
+        for (seed, &query_length) in [3, 15, 16, 31, 64, 200].iter().enumerate() {
+            let query = random_dna(seed as u64, query_length);
+
+            for backend in available_backends() {
+                let profile = QueryProfile::with_backend(&query, &scoring, backend).unwrap();
+
+                for target_seed in 0..10 {
+                    let target = random_dna(500 + target_seed, 5 + target_seed as usize * 53);
+
+                    assert_eq!(
+                        profile.score(&target),
+                        smith_waterman::score(&query, &target, &scoring),
+                        "backend {:?}, query {}, target {}",
+                        backend,
+                        query,
+                        target
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn striped_score_falls_back_to_scalar_when_saturating() {
+        let scoring = Scoring::<Nucleobase>::match_mismatch(100, -100, 200, 100);
+        let sequence = random_dna(7, 500);
+
+        for backend in available_backends() {
+            let profile = QueryProfile::with_backend(&sequence, &scoring, backend).unwrap();
+
+            assert_eq!(profile.score(&sequence), 50_000);
+        }
+    }
+
+    #[test]
+    fn striped_score_of_empty_sequences() {
+        let scoring = Scoring::<Nucleobase>::match_mismatch(2, -3, 5, 2);
+        let empty = DnaSequence::try_from("").unwrap();
+        let sequence = random_dna(3, 20);
+
+        for backend in available_backends() {
+            let profile = QueryProfile::with_backend(&empty, &scoring, backend).unwrap();
+            assert_eq!(profile.score(&sequence), 0);
+
+            let profile = QueryProfile::with_backend(&sequence, &scoring, backend).unwrap();
+            assert_eq!(profile.score(&empty), 0);
+        }
+    }
+}
+
+use std::i16;
+use super::{encode, Symbol};
+use super::scoring::Scoring;
+use super::smith_waterman;
+
+/// Implementation used to compute the alignment scores.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Backend {
+    /// Plain (non-vectorised) implementation, available everywhere.
+    Scalar,
+    /// 8 lanes of 16-bit integers, x86_64 only.
+    Sse2,
+    /// 16 lanes of 16-bit integers, x86_64 only.
+    Avx2,
+}
+
+impl Backend {
+    /// Returns the fastest backend supported by the CPU the program is running on.
+    pub fn detect() -> Self {
+        if Backend::Avx2.is_available() {
+            Backend::Avx2
+        } else if Backend::Sse2.is_available() {
+            Backend::Sse2
+        } else {
+            Backend::Scalar
+        }
+    }
+
+    /// Returns true if the backend can run on the CPU the program is running on.
+    #[cfg(target_arch = "x86_64")]
+    pub fn is_available(&self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            Backend::Sse2 => is_x86_feature_detected!("sse2"),
+            Backend::Avx2 => is_x86_feature_detected!("avx2"),
+        }
+    }
+
+    /// Returns true if the backend can run on the CPU the program is running on.
+    #[cfg(not(target_arch = "x86_64"))]
+    pub fn is_available(&self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            _ => false,
+        }
+    }
+
+    fn lanes(&self) -> usize {
+        match self {
+            Backend::Scalar => 1,
+            Backend::Sse2 => 8,
+            Backend::Avx2 => 16,
+        }
+    }
+}
+
+/// A query prepared to be aligned against many targets.
+///
+/// Building the profile costs `O(alphabet size * query length)`, which is then amortized over all the targets.
+pub struct QueryProfile<S> {
+    backend: Backend,
+    query: Vec<usize>,
+    scoring: Scoring<S>,
+    segment_length: usize,
+    // scores laid out as [symbol][segment][lane], see the comment at the top of the module
+    profile: Vec<i16>,
+    gap_open: i16,
+    gap_extend: i16,
+    // if a striped score reaches this threshold, it may have been saturated
+    saturation_threshold: i16,
+}
+
+impl<S: Symbol + Clone> QueryProfile<S> {
+    /// Builds the profile of `query` for the fastest backend available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::alignment::{scoring::Scoring, striped::QueryProfile};
+    /// use rosalind::amino_acids::{AminoAcid, Sequence};
+    ///
+    /// let scoring = Scoring::<AminoAcid>::blosum62(11, 1);
+    /// let query = Sequence::try_from("MEANLY").unwrap();
+    /// let profile = QueryProfile::new(&query, &scoring);
+    ///
+    /// for target in &["PLEASANTLY", "MEANLYPLEASANT", "WWW"] {
+    ///     let target = Sequence::try_from(*target).unwrap();
+    ///     println!("{}: {}", target, profile.score(&target));
+    /// }
+    /// ```
+    pub fn new<Q: AsRef<[S]> + ?Sized>(query: &Q, scoring: &Scoring<S>) -> Self {
+        QueryProfile::with_backend(query, scoring, Backend::detect())
+            .expect("The detected backend should always be available")
+    }
+
+    /// Builds the profile of `query` for a given backend, returns None if the backend is not supported by the CPU.
+    ///
+    /// If the scores or gap penalties don't fit in 16-bit integers, the profile silently uses the scalar backend.
+    pub fn with_backend<Q: AsRef<[S]> + ?Sized>(
+        query: &Q,
+        scoring: &Scoring<S>,
+        backend: Backend,
+    ) -> Option<Self> {
+        if !backend.is_available() {
+            return None;
+        }
+
+        let fits_in_i16 = |value: i32| value.abs() < i16::MAX as i32 / 2;
+
+        let backend = if fits_in_i16(scoring.max_score()) && fits_in_i16(scoring.min_score())
+            && fits_in_i16(scoring.gap_open())
+        {
+            backend
+        } else {
+            Backend::Scalar
+        };
+
+        let query = encode(query.as_ref());
+        let lanes = backend.lanes();
+        let segment_length = (query.len() + lanes - 1) / lanes;
+
+        let mut profile = Vec::with_capacity(S::ALPHABET_SIZE * segment_length * lanes);
+
+        if backend != Backend::Scalar {
+            for symbol in 0..S::ALPHABET_SIZE {
+                for segment in 0..segment_length {
+                    for lane in 0..lanes {
+                        let position = segment + lane * segment_length;
+
+                        // positions past the end of the query score 0: they come after the last symbol,
+                        // so no real cell depends on them and they can never exceed the best real score
+                        let score = match query.get(position) {
+                            Some(&query_symbol) => scoring.score(query_symbol, symbol) as i16,
+                            None => 0,
+                        };
+
+                        profile.push(score);
+                    }
+                }
+            }
+        }
+
+        Some(QueryProfile {
+            backend: backend,
+            query: query,
+            scoring: scoring.clone(),
+            segment_length: segment_length,
+            profile: profile,
+            gap_open: scoring.gap_open() as i16,
+            gap_extend: scoring.gap_extend() as i16,
+            saturation_threshold: i16::MAX - scoring.max_score().max(0) as i16,
+        })
+    }
+
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /// Returns the score of the best local alignment between the query and `target`,
+    /// always equal to what `smith_waterman::score` returns.
+    pub fn score<Q: AsRef<[S]> + ?Sized>(&self, target: &Q) -> i32 {
+        let target = encode(target.as_ref());
+
+        if self.query.is_empty() || target.is_empty() {
+            return 0;
+        }
+
+        let striped_score = match self.backend {
+            Backend::Scalar => None,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => Some(unsafe { x86_64::score_sse2(self, &target) }),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => Some(unsafe { x86_64::score_avx2(self, &target) }),
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => None,
+        };
+
+        match striped_score {
+            Some(score) if score < self.saturation_threshold => score as i32,
+            _ => smith_waterman::score_encoded(&self.query, &target, &self.scoring),
+        }
+    }
+}
+
+// operations needed by the striped algorithm on a vector of signed 16-bit integers
+// all methods are unsafe as the caller must make sure the CPU supports the instructions
+trait Vector: Copy {
+    const LANES: usize;
+
+    unsafe fn zero() -> Self;
+    unsafe fn splat(value: i16) -> Self;
+    unsafe fn load(pointer: *const i16) -> Self;
+    unsafe fn adds(self, other: Self) -> Self;
+    unsafe fn subs(self, other: Self) -> Self;
+    unsafe fn max(self, other: Self) -> Self;
+    // moves every value to the next lane, the first lane becoming 0
+    unsafe fn shift_lanes(self) -> Self;
+    unsafe fn any_greater_than(self, other: Self) -> bool;
+    unsafe fn horizontal_max(self) -> i16;
+}
+
+// H, E and F are floored at 0, which doesn't change H (see smith_waterman::score_encoded)
+// and keeps the lazy F loop from spinning on negative values
+#[inline(always)]
+unsafe fn striped_score<V: Vector>(
+    profile: &[i16],
+    segment_length: usize,
+    target: &[usize],
+    gap_open: i16,
+    gap_extend: i16,
+) -> i16 {
+    let zero = V::zero();
+    let v_gap_open = V::splat(gap_open);
+    let v_gap_extend = V::splat(gap_extend);
+
+    let mut h_load = vec![zero; segment_length];
+    let mut h_store = vec![zero; segment_length];
+    let mut e = vec![zero; segment_length];
+    let mut v_max = zero;
+
+    for &symbol in target {
+        let symbol_profile = profile[symbol * segment_length * V::LANES..].as_ptr();
+
+        let mut v_f = zero;
+        // the diagonal of the first segment comes from the last segment of the previous column, one lane lower
+        let mut v_h = h_store[segment_length - 1].shift_lanes();
+
+        ::std::mem::swap(&mut h_load, &mut h_store);
+
+        for segment in 0..segment_length {
+            v_h = v_h.adds(V::load(symbol_profile.add(segment * V::LANES)));
+            v_h = v_h.max(e[segment]).max(v_f).max(zero);
+            v_max = v_max.max(v_h);
+            h_store[segment] = v_h;
+
+            let v_h_open = v_h.subs(v_gap_open);
+            e[segment] = e[segment].subs(v_gap_extend).max(v_h_open).max(zero);
+            v_f = v_f.subs(v_gap_extend).max(v_h_open).max(zero);
+
+            v_h = h_load[segment];
+        }
+
+        // lazy F loop: propagate the vertical gaps from one lane to the next, as long as they can change something
+        // (a gap coming into a cell that is not better than opening a gap from this cell won't change anything)
+        v_f = v_f.shift_lanes();
+        let mut segment = 0;
+
+        while v_f.any_greater_than(h_store[segment].subs(v_gap_open).max(zero)) {
+            let v_h = h_store[segment].max(v_f);
+            h_store[segment] = v_h;
+            v_max = v_max.max(v_h);
+            e[segment] = e[segment].max(v_h.subs(v_gap_open));
+
+            v_f = v_f.subs(v_gap_extend).max(zero);
+
+            segment += 1;
+            if segment == segment_length {
+                segment = 0;
+                v_f = v_f.shift_lanes();
+            }
+        }
+    }
+
+    v_max.horizontal_max()
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use std::arch::x86_64::*;
+    use super::{striped_score, QueryProfile, Vector};
+
+    #[derive(Clone, Copy)]
+    struct Sse2(__m128i);
+
+    impl Vector for Sse2 {
+        const LANES: usize = 8;
+
+        #[inline(always)]
+        unsafe fn zero() -> Self {
+            Sse2(_mm_setzero_si128())
+        }
+
+        #[inline(always)]
+        unsafe fn splat(value: i16) -> Self {
+            Sse2(_mm_set1_epi16(value))
+        }
+
+        #[inline(always)]
+        unsafe fn load(pointer: *const i16) -> Self {
+            Sse2(_mm_loadu_si128(pointer as *const __m128i))
+        }
+
+        #[inline(always)]
+        unsafe fn adds(self, other: Self) -> Self {
+            Sse2(_mm_adds_epi16(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn subs(self, other: Self) -> Self {
+            Sse2(_mm_subs_epi16(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn max(self, other: Self) -> Self {
+            Sse2(_mm_max_epi16(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn shift_lanes(self) -> Self {
+            Sse2(_mm_slli_si128(self.0, 2))
+        }
+
+        #[inline(always)]
+        unsafe fn any_greater_than(self, other: Self) -> bool {
+            _mm_movemask_epi8(_mm_cmpgt_epi16(self.0, other.0)) != 0
+        }
+
+        #[inline(always)]
+        unsafe fn horizontal_max(self) -> i16 {
+            let mut values = [0i16; 8];
+            _mm_storeu_si128(values.as_mut_ptr() as *mut __m128i, self.0);
+            values.iter().cloned().max().unwrap()
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct Avx2(__m256i);
+
+    impl Vector for Avx2 {
+        const LANES: usize = 16;
+
+        #[inline(always)]
+        unsafe fn zero() -> Self {
+            Avx2(_mm256_setzero_si256())
+        }
+
+        #[inline(always)]
+        unsafe fn splat(value: i16) -> Self {
+            Avx2(_mm256_set1_epi16(value))
+        }
+
+        #[inline(always)]
+        unsafe fn load(pointer: *const i16) -> Self {
+            Avx2(_mm256_loadu_si256(pointer as *const __m256i))
+        }
+
+        #[inline(always)]
+        unsafe fn adds(self, other: Self) -> Self {
+            Avx2(_mm256_adds_epi16(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn subs(self, other: Self) -> Self {
+            Avx2(_mm256_subs_epi16(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn max(self, other: Self) -> Self {
+            Avx2(_mm256_max_epi16(self.0, other.0))
+        }
+
+        // AVX2 byte shifts work within each 128-bit half, so the last value of the low half
+        // has to be carried over to the high half: [0, low] is built and used as the shifted-in bytes
+        #[inline(always)]
+        unsafe fn shift_lanes(self) -> Self {
+            let carried = _mm256_permute2x128_si256(self.0, self.0, 0x08);
+            Avx2(_mm256_alignr_epi8(self.0, carried, 14))
+        }
+
+        #[inline(always)]
+        unsafe fn any_greater_than(self, other: Self) -> bool {
+            _mm256_movemask_epi8(_mm256_cmpgt_epi16(self.0, other.0)) != 0
+        }
+
+        #[inline(always)]
+        unsafe fn horizontal_max(self) -> i16 {
+            let mut values = [0i16; 16];
+            _mm256_storeu_si256(values.as_mut_ptr() as *mut __m256i, self.0);
+            values.iter().cloned().max().unwrap()
+        }
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn score_sse2<S>(profile: &QueryProfile<S>, target: &[usize]) -> i16 {
+        striped_score::<Sse2>(
+            &profile.profile,
+            profile.segment_length,
+            target,
+            profile.gap_open,
+            profile.gap_extend,
+        )
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn score_avx2<S>(profile: &QueryProfile<S>, target: &[usize]) -> i16 {
+        striped_score::<Avx2>(
+            &profile.profile,
+            profile.segment_length,
+            target,
+            profile.gap_open,
+            profile.gap_extend,
+        )
+    }
+}
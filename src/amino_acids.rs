@@ -26,6 +26,8 @@ pub enum AminoAcid {
     Tryptophan,
     Tyrosine,
     Valine,
+    /// Not an amino acid: the end of translation, where a stop codon was read.
+    Stop,
 }
 
 impl AminoAcid {
@@ -53,6 +55,8 @@ impl AminoAcid {
             Tryptophan => 186.07931,
             Tyrosine => 163.06333,
             Valine => 99.06841,
+            // translation ends there, it adds nothing to the protein
+            Stop => 0.0,
         }
     }
 }
@@ -123,6 +127,7 @@ impl convert::TryFrom<char> for AminoAcid {
             'W' => Ok(Tryptophan),
             'R' => Ok(Arginine),
             'G' => Ok(Glycine),
+            '*' => Ok(Stop),
             _ => Err(ParseError::IllegalChar { ch }),
         }
     }
@@ -153,6 +158,7 @@ impl fmt::Display for AminoAcid {
             Tryptophan => "W",
             Tyrosine => "Y",
             Valine => "V",
+            Stop => "*",
         };
 
         write!(f, "{}", repr)
@@ -176,11 +182,32 @@ pub mod sequence {
 
             assert_eq!(aas.to_string(), "MAMAPRTEINSTRING");
         }
+
+        #[test]
+        fn amino_acids_from_rna_sequence_stop_at_first_stop_codon() {
+            use std::convert::TryFrom;
+
+            let rna = ::rna::Sequence::try_from("AUGUAAAUG").unwrap();
+
+            assert_eq!(super::Sequence::from(&rna).to_string(), "M");
+        }
+
+        #[test]
+        fn try_from_str_with_stop() {
+            use std::convert::TryFrom;
+            use super::super::AminoAcid::*;
+
+            assert_eq!(
+                super::Sequence::try_from("MW*").unwrap(),
+                super::Sequence(vec![Methionine, Tryptophan, Stop])
+            );
+        }
     }
 
     use std::{convert, fmt, ops};
     use super::AminoAcid;
     use super::RnaSequence;
+    use genetic_code::{GeneticCode, TranslationMode};
 
     #[derive(Debug, Eq, PartialOrd, Ord, PartialEq)]
     pub struct Sequence(pub Vec<AminoAcid>);
@@ -202,6 +229,12 @@ pub mod sequence {
         }
     }
 
+    impl AsRef<[AminoAcid]> for Sequence {
+        fn as_ref(&self) -> &[AminoAcid] {
+            &self.0
+        }
+    }
+
     impl ops::Index<usize> for Sequence {
         type Output = AminoAcid;
 
@@ -252,7 +285,8 @@ pub mod sequence {
     }
 
     impl<'a> convert::From<&'a RnaSequence> for Sequence {
-        /// Converts a RNA sequence to an amino acid sequence.
+        /// Converts a RNA sequence to an amino acid sequence with the standard genetic code, up to the first stop codon.
+        /// See `GeneticCode::translate` for the other genetic codes and ways to handle stop codons.
         ///
         /// # Examples
         ///
@@ -265,49 +299,10 @@ pub mod sequence {
         /// println!("{}", amino_acids);
         /// ```
         fn from(rna: &RnaSequence) -> Self {
-            use self::AminoAcid::*;
-            use rna::codons::*;
-
-            let mut amino_acids = Vec::new();
-
-            for codon in ::rna::sequence::StrictCodonIterator::new(rna) {
-                let amino_acid = match codon {
-                    UUU | UUC => Phenyalalanine,
-                    UUA | UUG | CUU | CUC | CUA | CUG => Leucine,
-                    AUU | AUC | AUA => Isoleucine,
-                    AUG => Methionine,
-                    GUU | GUC | GUA | GUG => Valine,
-                    UCU | UCC | UCA | UCG => Serine,
-                    CCU | CCC | CCA | CCG => Proline,
-                    ACU | ACC | ACA | ACG => Threonine,
-                    GCU | GCC | GCA | GCG => Alanine,
-                    UAU | UAC => Tyrosine,
-                    CAU | CAC => Histidine,
-                    CAA | CAG => Glutamine,
-                    AAU | AAC => Asparagine,
-                    AAA | AAG => Lysine,
-                    GAU | GAC => AsparticAcid,
-                    GAA | GAG => GlutamicAcid,
-                    UGU | UGC => Cysteine,
-                    UGG => Tryptophan,
-                    CGU | CGC | CGA | CGG => Arginine,
-                    AGU | AGC => Serine,
-                    AGA | AGG => Arginine,
-                    GGU | GGC | GGA | GGG => Glycine,
-                    UAA | UAG | UGA => {
-                        // STOP codons, they don't code for any amino acid
-                        continue;
-                    }
-                    _ => {
-                        // this can never happen as we cover every possible case
-                        panic!("No matching codon! {:?}", codon);
-                    }
-                };
-
-                amino_acids.push(amino_acid);
-            }
-
-            Sequence(amino_acids)
+            GeneticCode::standard()
+                .translate(rna, TranslationMode::StopAtFirstStop)
+                .expect("Translating up to the first stop codon can't fail")
+                .protein
         }
     }
 
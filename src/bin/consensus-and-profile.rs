@@ -1,8 +1,10 @@
 extern crate rosalind;
 
 use std::convert::TryFrom;
+use rosalind::alignment::{multiple, scoring::Scoring};
 use rosalind::fasta::Label;
 use rosalind::dna::{Nucleobase as DnaNucleobase, Nucleobase::*, Sequence as DnaSequence};
+use rosalind::motif::Profile;
 
 // solution to http://rosalind.info/problems/cons/
 
@@ -10,9 +12,23 @@ use rosalind::dna::{Nucleobase as DnaNucleobase, Nucleobase::*, Sequence as DnaS
 mod tests {
     use std::convert::TryFrom;
     use rosalind::dna::Sequence as DnaSequence;
+    use rosalind::motif::Profile;
+
+    fn profile_rows(profile: &Profile) -> Vec<Vec<u32>> {
+        use rosalind::dna::Nucleobase::*;
+
+        [Adenine, Cytosine, Guanine, Thymine]
+            .iter()
+            .map(|nucleobase| {
+                (0..profile.len())
+                    .map(|position| profile.count(position, nucleobase))
+                    .collect()
+            })
+            .collect()
+    }
 
     #[test]
-    fn build_profile_matrix() {
+    fn build_profile() {
         let fasta_content = r"
 >Rosalind_1
 ATCCAGCT
@@ -33,7 +49,7 @@ ATGGCACT
         let sequences = ::rosalind::fasta::parse_fasta_format_to_map(&fasta_content)
             .expect("Couldn't parse FASTA data");
 
-        let profile_matrix = ::build_profile_matrix(
+        let profile = ::build_profile(
             sequences
                 .into_iter()
                 .map(|(label, sequence_str)| {
@@ -43,146 +59,80 @@ ATGGCACT
                     (label, DnaSequence::try_from(sequence_str.as_str()).unwrap())
                 })
                 .collect(),
-        ).expect("Error building profile matrix");
+        ).expect("Error building profile");
 
-        let expected_profile_matrix = [
+        let expected_profile_rows = vec![
             vec![5, 1, 0, 0, 5, 5, 0, 0],
             vec![0, 0, 1, 4, 2, 0, 6, 1],
             vec![1, 1, 6, 3, 0, 1, 0, 0],
             vec![1, 5, 0, 0, 0, 1, 1, 6],
         ];
 
-        assert_eq!(profile_matrix, expected_profile_matrix);
+        assert_eq!(profile_rows(&profile), expected_profile_rows);
+        assert_eq!(profile.consensus().to_string(), "ATGCAACT");
     }
 
     #[test]
-    fn compute_consensus_string() {
-        let profile_matrix = [
-            vec![5, 1, 0, 0, 5, 5, 0, 0],
-            vec![0, 0, 1, 4, 2, 0, 6, 1],
-            vec![1, 1, 6, 3, 0, 1, 0, 0],
-            vec![1, 5, 0, 0, 0, 1, 1, 6],
+    fn build_profile_of_unaligned_sequences() {
+        let sequences = vec![
+            ("Rosalind_1".to_string(), DnaSequence::try_from("ACGTACGT").unwrap()),
+            ("Rosalind_2".to_string(), DnaSequence::try_from("ACGTCGT").unwrap()),
         ];
 
-        let consensus_string = ::compute_consensus_string(&profile_matrix);
-
-        assert_eq!(consensus_string, "ATGCAACT");
-    }
-}
+        let profile = ::build_profile(sequences).expect("Error building profile");
 
-// in ProfileMatrix, each row contains the number of occurrences of a nucleobase in all sequences, letter by letter
-//
-// example of profile matrix:
-//
-// A   5 1 0 0 5 5 0 0
-// C   0 0 1 4 2 0 6 1
-// G   1 1 6 3 0 1 0 0
-// T   1 5 0 0 0 1 1 6
-type ProfileMatrix = [Vec<u32>; 4];
-
-fn row_to_string(row: &Vec<u32>) -> String {
-    row.iter()
-        .map(|x| x.to_string())
-        .collect::<Vec<String>>()
-        .join(" ")
-}
-
-fn display_profile_matrix(profile_matrix: &ProfileMatrix) {
-    println!(
-        "A: {}",
-        row_to_string(&profile_matrix[nucleobase_index(Adenine)])
-    );
-    println!(
-        "C: {}",
-        row_to_string(&profile_matrix[nucleobase_index(Cytosine)])
-    );
-    println!(
-        "G: {}",
-        row_to_string(&profile_matrix[nucleobase_index(Guanine)])
-    );
-    println!(
-        "T: {}",
-        row_to_string(&profile_matrix[nucleobase_index(Thymine)])
-    );
-}
+        // ACGTACGT
+        // ACGT-CGT
+        let expected_profile_rows = vec![
+            vec![2, 0, 0, 0, 1, 0, 0, 0],
+            vec![0, 2, 0, 0, 0, 2, 0, 0],
+            vec![0, 0, 2, 0, 0, 0, 2, 0],
+            vec![0, 0, 0, 2, 0, 0, 0, 2],
+        ];
 
-fn nucleobase_index(nucleobase: DnaNucleobase) -> usize {
-    match nucleobase {
-        Adenine => 0,
-        Cytosine => 1,
-        Guanine => 2,
-        Thymine => 3,
+        assert_eq!(profile_rows(&profile), expected_profile_rows);
     }
 }
 
-fn nucleobase_from_index(index: usize) -> Result<DnaNucleobase, String> {
-    match index {
-        0 => Ok(Adenine),
-        1 => Ok(Cytosine),
-        2 => Ok(Guanine),
-        3 => Ok(Thymine),
-        _ => Err(format!("Unallowed nucleobase index: {}", index)),
+fn display_profile(profile: &Profile) {
+    for nucleobase in &[Adenine, Cytosine, Guanine, Thymine] {
+        let row = (0..profile.len())
+            .map(|position| profile.count(position, nucleobase).to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        println!("{}: {}", nucleobase, row);
     }
 }
 
-fn build_profile_matrix(sequences: Vec<(Label, DnaSequence)>) -> Result<ProfileMatrix, String> {
+fn build_profile(mut sequences: Vec<(Label, DnaSequence)>) -> Result<Profile, String> {
     if sequences.len() == 0 {
-        return Err("Cannot create profile matrix for 0 sequences!".to_string());
+        return Err("Cannot create profile for 0 sequences!".to_string());
     }
 
-    let &(_, ref first_sequence) = &sequences[0];
-    let sequences_length = first_sequence.len();
-
-    let mut profile_matrix = [
-        vec![0; sequences_length], // Adenine
-        vec![0; sequences_length], // Cytosine
-        vec![0; sequences_length], // Guanine
-        vec![0; sequences_length], // Thymine
-    ];
-
-    for &(ref label, ref sequence) in &sequences {
-        if sequence.len() != sequences_length {
-            return Err(format!(
-                "Sequences should have a length of {} but sequence {} has length of {}",
-                sequences_length,
-                label,
-                sequence.len()
-            ));
-        }
-
-        for (index_in_sequence, nucleobase) in sequence.into_iter().enumerate() {
-            profile_matrix[nucleobase_index(nucleobase)][index_in_sequence] += 1;
-        }
-    }
+    let all_same_length = sequences
+        .iter()
+        .all(|&(_, ref sequence)| sequence.len() == sequences[0].1.len());
 
-    Ok(profile_matrix)
-}
+    if all_same_length {
+        let sequences: Vec<DnaSequence> = sequences.into_iter().map(|(_, sequence)| sequence).collect();
 
-fn compute_consensus_string(profile_matrix: &ProfileMatrix) -> String {
-    let mut consensus_string_buffer: Vec<String> = Vec::with_capacity(profile_matrix[0].len());
-
-    for (index, _) in profile_matrix[0].iter().enumerate() {
-        let nucleobases = [
-            profile_matrix[nucleobase_index(Adenine)][index],
-            profile_matrix[nucleobase_index(Cytosine)][index],
-            profile_matrix[nucleobase_index(Guanine)][index],
-            profile_matrix[nucleobase_index(Thymine)][index],
-        ];
+        return Profile::from_sequences(&sequences, 0.0).map_err(|error| error.to_string());
+    }
 
-        let (_, dominant_nucleobase_index) = nucleobases
-            .iter()
-            .enumerate()
-            .map(|(index, value)| (value, index))
-            .max()
-            .unwrap();
+    // sequences of different lengths can't have been aligned beforehand, so let's align them
+    // the alignment depends on the order of the sequences, and the FASTA HashMap has none
+    sequences.sort_by(|&(ref label_a, _), &(ref label_b, _)| label_a.cmp(label_b));
 
-        let dominant_nucleobase = nucleobase_from_index(dominant_nucleobase_index).unwrap();
+    let sequences: Vec<DnaSequence> = sequences.into_iter().map(|(_, sequence)| sequence).collect();
 
-        consensus_string_buffer.push(dominant_nucleobase.to_string());
-    }
+    let alignment = multiple::align(
+        &sequences,
+        &Scoring::<DnaNucleobase>::match_mismatch(2, -1, 3, 1),
+    );
 
-    // find max of each letter
-    consensus_string_buffer.join("")
+    // gaps are simply not counted
+    Ok(Profile::from_alignment(&alignment, 0.0))
 }
 
 fn main() {
@@ -191,7 +141,7 @@ fn main() {
     let sequences = rosalind::fasta::parse_fasta_format_to_map(&fasta_content)
         .expect("Couldn't parse FASTA data");
 
-    let profile_matrix = build_profile_matrix(
+    let profile = build_profile(
         // this very eloquent piece of gargling code's life purpose is to convert sequence
         // from:
         // HashMap<::fasta::Label, ::fasta::Sequence> (where Label and Sequence are just String)
@@ -206,11 +156,9 @@ fn main() {
                 (label, DnaSequence::try_from(sequence_str.as_str()).unwrap())
             })
             .collect(),
-    ).expect("Error building profile matrix");
-
-    let consensus_string = compute_consensus_string(&profile_matrix);
+    ).expect("Error building profile");
 
-    println!("{}", consensus_string);
+    println!("{}", profile.consensus());
 
-    display_profile_matrix(&profile_matrix);
+    display_profile(&profile);
 }
@@ -1,5 +1,9 @@
 extern crate rosalind;
 
+use std::convert::TryFrom;
+use rosalind::dna::Sequence as DnaSequence;
+use rosalind::substitution;
+
 // solution to http://rosalind.info/problems/hamm/
 
 #[cfg(test)]
@@ -26,23 +30,10 @@ mod tests {
 }
 
 fn count_point_mutations(dna_strand_a: &str, dna_strand_b: &str) -> u32 {
-    let mut strand_a_bases = dna_strand_a.chars();
-
-    let mut point_mutations = 0;
-
-    for base_strand_b in dna_strand_b.chars() {
-        match strand_a_bases.next() {
-            Some(base_strand_a) if base_strand_a != base_strand_b => {
-                point_mutations += 1;
-            }
-            Some(_) => {}
-            None => {
-                break;
-            }
-        }
-    }
+    let strand_a = DnaSequence::try_from(dna_strand_a).expect("Couldn't parse strand A");
+    let strand_b = DnaSequence::try_from(dna_strand_b).expect("Couldn't parse strand B");
 
-    point_mutations
+    substitution::count_differences(&strand_a, &strand_b) as u32
 }
 
 fn main() {
@@ -0,0 +1,20 @@
+extern crate rosalind;
+
+// solution to http://rosalind.info/problems/suff/
+
+use std::convert::TryFrom;
+use rosalind::dna::Sequence as DnaSequence;
+use rosalind::suffix::SuffixTree;
+
+fn main() {
+    let content = rosalind::io::load_data(file!()).expect("Couldn't open the file");
+
+    // the terminal symbol is added by the suffix tree itself
+    let dna_string = content.trim().trim_end_matches('$');
+
+    let dna = DnaSequence::try_from(dna_string).expect("Couldn't parse the sequence");
+
+    for label in SuffixTree::new(&dna).expect("A linear sequence is always indexed").edge_labels() {
+        println!("{}", label);
+    }
+}
@@ -2,6 +2,8 @@ extern crate rosalind;
 
 // solution to http://rosalind.info/problems/subs/
 
+use rosalind::search::kmp;
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -26,17 +28,8 @@ mod tests {
 }
 
 fn find_all_substring_locations(string: &str, substring: &str) -> Vec<usize> {
-    let mut locations = Vec::new();
-
-    let mut search_index = 0;
-
-    while let Some(index) = string[search_index..].find(substring) {
-        locations.push(index + search_index);
-
-        search_index += index + 1;
-    }
-
-    locations
+    // both strings are plain ASCII, so comparing them byte by byte is enough
+    kmp::find_all(string, substring)
 }
 
 fn main() {
@@ -2,7 +2,8 @@ extern crate reqwest;
 extern crate rosalind;
 
 use std::convert::TryFrom;
-use rosalind::amino_acids::{sequence, AminoAcid, Sequence};
+use rosalind::amino_acids::{sequence, Sequence};
+use rosalind::motif::prosite::Pattern;
 
 // solution to http://rosalind.info/problems/mprt/
 
@@ -23,6 +24,15 @@ mod tests {
 
         assert_eq!(positions, vec![66, 67, 120]);
     }
+
+    #[test]
+    fn find_n_glycosylation_motif_at_the_end_of_protein() {
+        let sequence = Sequence::try_from("AANGSA").unwrap();
+
+        let positions = ::find_all_n_glycosylation_motif_positions_in_protein(&sequence);
+
+        assert_eq!(positions, vec![2]);
+    }
 }
 
 // useless over-complication just for the sake of learning how to compose error types
@@ -62,36 +72,23 @@ fn download_protein_from_uniprot(protein_id: &str) -> Result<Sequence, UniprotEr
     Ok(Sequence::try_from(protein_str.as_str())?)
 }
 
-fn find_all_n_glycosylation_motif_positions_in_protein(protein: &Sequence) -> Vec<usize> {
-    use AminoAcid::{Asparagine, Proline, Serine, Threonine};
-
-    let mut positions = Vec::new();
+// the shorthand protein motif notation for N-glycosylation: N{P}[ST]{P}
+// it means:
+// 1 Asparagine amino acid (N),             followed by
+// 1 "any amino acid except Proline (P)",   followed by
+// 1 "either Serine (S) or Threonine (T)",  followed by
+// 1 "any amino acid except Proline (P)"
+// the complete motif is 4-amino-acid long
+const N_GLYCOSYLATION_MOTIF: &str = "N{P}[ST]{P}";
 
-    // the shorthand protein motif notation for N-glycosylation: N{P}[ST]{P}
-    // it means:
-    // 1 Asparagine amino acid (N),             followed by
-    // 1 "any amino acid except Proline (P)",   followed by
-    // 1 "either Serine (S) or Threonine (T)",  followed by
-    // 1 "any amino acid except Proline (P)"
-    // the complete motif is 4-amino-acid long
-
-    for (index, aa_1) in protein.into_iter().enumerate() {
-        if index + 4 >= protein.len() {
-            break;
-        }
-
-        let aa_2 = &protein[index + 1];
-        let aa_3 = &protein[index + 2];
-        let aa_4 = &protein[index + 3];
-
-        if aa_1 == Asparagine && *aa_2 != Proline && (*aa_3 == Serine || *aa_3 == Threonine)
-            && *aa_4 != Proline
-        {
-            positions.push(index);
-        }
-    }
+fn find_all_n_glycosylation_motif_positions_in_protein(protein: &Sequence) -> Vec<usize> {
+    let motif = Pattern::try_from(N_GLYCOSYLATION_MOTIF).expect("Invalid PROSITE pattern");
 
-    positions
+    motif
+        .find_all(protein)
+        .iter()
+        .map(|found| found.position)
+        .collect()
 }
 
 fn main() {
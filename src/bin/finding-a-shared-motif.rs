@@ -1,7 +1,10 @@
 extern crate rosalind;
 
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use rosalind::dna;
 use rosalind::fasta::{Label, Sequence};
+use rosalind::suffix::SuffixArray;
 
 // solution to http://rosalind.info/problems/lcsm/
 
@@ -22,46 +25,31 @@ mod tests {
 
         let longest_common_substring: &str = ::find_longest_common_substring(&sequences);
 
-        // there are 2 possible solutions here and we have no guarantee on which one will come out,
-        // due to the fact that HashMap is unordered and the outcome depends on which sequence is compared first
-        let expected_possible_longest_common_substrings: Vec<&str> = vec!["AC", "TA"];
-
-        assert!(expected_possible_longest_common_substrings.contains(&longest_common_substring));
+        // AC, CA and TA are all possible solutions, the smallest one always comes out
+        assert_eq!(longest_common_substring, "AC");
     }
 }
 
-// in order to find the longest common substring in all sequences,
-// we start by searching a common substring of length 1
-// if we find it, then we'll only look for a common substring of length 2,
-// if we find it, then we'll only look for a common substring of length (last common substring's length) + 1
-// and so on...
-// this will allow us to skip useless comparisons: as long as we already have a common substring,
-// we don't need to search for other common substrings that are not longer than the previously found one
+// all the sequences are indexed in a generalized suffix array, the longest common substring is then the longest
+// common prefix of a run of consecutive suffixes containing at least one suffix of each sequence
+// when there are several longest common substrings, the smallest one in lexicographic order is returned
 fn find_longest_common_substring<'a>(sequences_map: &'a HashMap<Label, Sequence>) -> &'a str {
-    let mut longest_common_substring = "";
-
-    if sequences_map.len() == 0 {
-        return longest_common_substring;
-    }
+    let strings = sequences_map
+        .iter()
+        .map(|(_, sequence)| &sequence[..])
+        .collect::<Vec<&str>>();
 
-    let mut sequences_iter = sequences_map.iter().map(|(_, sequence)| &sequence[..]);
+    let sequences = strings
+        .iter()
+        .map(|string| dna::Sequence::try_from(*string).expect("Invalid DNA sequence"))
+        .collect::<Vec<dna::Sequence>>();
 
-    let first_sequence = sequences_iter.next().unwrap();
-    let sequences = sequences_iter.collect::<Vec<_>>();
+    let suffix_array = SuffixArray::generalized(&sequences).expect("Linear sequences are always indexed");
 
-    let mut ch_index = 0;
-
-    while ch_index + longest_common_substring.len() < first_sequence.len() {
-        let needle = &first_sequence[ch_index..ch_index + longest_common_substring.len() + 1];
-
-        if sequences.iter().all(|sequence| sequence.contains(needle)) {
-            longest_common_substring = needle;
-        } else {
-            ch_index += 1;
-        }
+    match suffix_array.longest_common_substring() {
+        Some(found) => &strings[found.sequence][found.offset..found.offset + found.length],
+        None => "",
     }
-
-    longest_common_substring
 }
 
 fn main() {
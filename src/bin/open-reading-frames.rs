@@ -3,12 +3,10 @@ extern crate rosalind;
 // solution to http://rosalind.info/problems/orf/
 
 use std::convert::TryFrom;
-use std::iter::FromIterator;
 
 use rosalind::dna::Sequence as DnaSequence;
-use rosalind::rna::{codons, Nucleobase as RnaNucleobase, Sequence as RnaSequence,
-                    StrictCodonIterator};
 use rosalind::amino_acids::Sequence as AaSequence;
+use rosalind::orf::OrfFinder;
 
 #[cfg(test)]
 mod tests {
@@ -36,77 +34,31 @@ mod tests {
 
         assert_eq!(candidates, expected_candidates);
     }
-}
-
-fn is_stop_codon(codon: &[RnaNucleobase]) -> bool {
-    use codons::*;
-
-    match codon {
-        UAA | UAG | UGA => true,
-        _ => false,
-    }
-}
-
-const RNA_START_CODON: &[RnaNucleobase] = codons::AUG;
-
-fn find_candidate_protein_strings_in_codons_sequence(
-    codons: Vec<&[RnaNucleobase]>,
-) -> Result<Vec<AaSequence>, String> {
-    let mut candidates = Vec::new();
 
-    let mut index = 0;
-
-    while index < codons.len() {
-        if codons[index] == RNA_START_CODON {
-            let mut candidate = Vec::from_iter(RNA_START_CODON.iter().cloned());
+    #[test]
+    fn find_candidate_protein_strings_spanning_origin() {
+        use rosalind::dna::Topology;
 
-            let mut index_end = index + 1;
-            while index_end < codons.len() {
-                if is_stop_codon(codons[index_end]) {
-                    let sequence = RnaSequence::new(candidate);
+        let sequence = DnaSequence::try_from("GCCTTAACCAT").unwrap();
 
-                    candidates.push(AaSequence::from(&sequence));
+        assert!(::find_all_candidate_protein_strings(&sequence).unwrap().is_empty());
 
-                    break;
-                } else {
-                    candidate.extend(codons[index_end].iter().cloned());
-                }
+        let plasmid = sequence.with_topology(Topology::Circular);
 
-                index_end += 1;
-            }
-        }
+        let candidates = ::find_all_candidate_protein_strings(&plasmid).unwrap();
 
-        index += 1;
+        assert!(candidates.contains(&AaSequence::try_from("MP").unwrap()));
     }
-
-    Ok(candidates)
 }
 
 fn find_all_candidate_protein_strings(
     dna_sequence: &DnaSequence,
 ) -> Result<Vec<AaSequence>, String> {
-    let rna_reverse_complement = RnaSequence::from(&dna_sequence.reverse_complement());
-
-    let rna = RnaSequence::from(dna_sequence);
-
-    let codons_sequences = vec![
-        StrictCodonIterator::new_starting_from(&rna, 0).collect(),
-        StrictCodonIterator::new_starting_from(&rna, 1).collect(),
-        StrictCodonIterator::new_starting_from(&rna, 2).collect(),
-        StrictCodonIterator::new_starting_from(&rna_reverse_complement, 0).collect(),
-        StrictCodonIterator::new_starting_from(&rna_reverse_complement, 1).collect(),
-        StrictCodonIterator::new_starting_from(&rna_reverse_complement, 2).collect(),
-    ];
-
     let mut candidates = Vec::new();
 
-    for sequence in codons_sequences {
-        let mut sequence_candidates = find_candidate_protein_strings_in_codons_sequence(sequence)?;
-
-        for candidate in sequence_candidates {
-            if !candidates.contains(&candidate) {
-                candidates.push(candidate);
-            }
+    for orf in OrfFinder::new().find_all(dna_sequence) {
+        if !candidates.contains(&orf.protein) {
+            candidates.push(orf.protein);
         }
     }
 
@@ -0,0 +1,25 @@
+extern crate rosalind;
+
+// solution to http://rosalind.info/problems/kmp/
+
+use rosalind::dna;
+use rosalind::search::kmp;
+use std::convert::TryFrom;
+
+fn main() {
+    let fasta_content = rosalind::io::load_data(file!()).expect("Couldn't open the file");
+
+    let sequences = rosalind::fasta::parse_fasta_format_to_map(&fasta_content)
+        .expect("Couldn't parse FASTA data");
+
+    for (_, sequence) in sequences {
+        let sequence = dna::Sequence::try_from(sequence.as_str()).expect("Invalid DNA sequence");
+
+        let failure_array = kmp::failure_array(&sequence)
+            .iter()
+            .map(|length| length.to_string())
+            .collect::<Vec<String>>();
+
+        println!("{}", failure_array.join(" "));
+    }
+}
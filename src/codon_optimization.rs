@@ -0,0 +1,355 @@
+// codon optimization: back-translating a protein to a synthetic gene with the codons a host uses
+//
+// codons are picked one amino acid after the other, either the most used one or at random in proportion to their use,
+// falling back to the other synonymous codons (and backtracking to the previous amino acids if needed)
+// when a codon would create an avoided restriction site or a too long homopolymer run
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::Sequence as AminoAcidSequence;
+    use codon_usage::CodonUsage;
+    use dna::Sequence as DnaSequence;
+    use genetic_code::{GeneticCode, TranslationMode};
+    use restriction::Enzyme;
+    use rna::Sequence as RnaSequence;
+    use super::{CodonOptimizer, CodonSelection, OptimizationError};
+
+    fn usage() -> CodonUsage {
+        CodonUsage::try_from(
+            "AUG 1.0(10)  AAA 1.0(8)  AAG 1.0(2)  CUG 1.0(9)  UUA 1.0(1)\n\
+             GAA 1.0(10)  GAG 1.0(1)  UUC 1.0(10)  UUU 1.0(1)  UAA 1.0(5)  UGA 1.0(1)",
+        ).unwrap()
+    }
+
+    fn protein(string: &str) -> AminoAcidSequence {
+        AminoAcidSequence::try_from(string).unwrap()
+    }
+
+    fn dna(string: &str) -> DnaSequence {
+        DnaSequence::try_from(string).unwrap()
+    }
+
+    #[test]
+    fn most_used() {
+        let gene = CodonOptimizer::new(usage()).optimize(&protein("MKL*")).unwrap();
+
+        assert_eq!(gene.sequence, dna("ATGAAACTGTAA"));
+        assert_eq!(gene.gc_content, 3.0 / 12.0);
+        assert_eq!(gene.cai, Some(1.0));
+    }
+
+    #[test]
+    fn avoid_restriction_sites() {
+        let optimizer = CodonOptimizer::new(usage());
+
+        assert_eq!(optimizer.optimize(&protein("EF")).unwrap().sequence, dna("GAATTC"));
+
+        let optimizer = optimizer.avoid(Enzyme::by_name("EcoRI").unwrap());
+        let gene = optimizer.optimize(&protein("EF")).unwrap();
+
+        assert_eq!(gene.sequence, dna("GAATTT"));
+        assert!(gene.cai.unwrap() < 1.0);
+    }
+
+    #[test]
+    fn avoid_homopolymer_runs() {
+        let optimizer = CodonOptimizer::new(usage()).max_homopolymer(4);
+
+        // AAAAAA and AAAAAG are both too long, so the first lysine has to change
+        assert_eq!(optimizer.optimize(&protein("KK")).unwrap().sequence, dna("AAGAAA"));
+
+        match CodonOptimizer::new(usage()).max_homopolymer(1).optimize(&protein("K")) {
+            Err(OptimizationError::Unsatisfiable { position: 0 }) => {}
+            _ => panic!("Both lysine codons repeat A"),
+        }
+    }
+
+    #[test]
+    fn proportional() {
+        let optimizer = CodonOptimizer::new(usage()).selection(CodonSelection::Proportional { seed: 7 });
+        let protein = protein(&"KLEF".repeat(50));
+
+        let gene = optimizer.optimize(&protein).unwrap();
+
+        // the same seed gives the same gene
+        assert_eq!(optimizer.optimize(&protein).unwrap(), gene);
+
+        let translation = GeneticCode::standard()
+            .translate(&RnaSequence::from(&gene.sequence), TranslationMode::ReadThrough)
+            .unwrap();
+
+        assert_eq!(translation.protein, protein);
+
+        // rarely used codons are picked, but rarely
+        let lysines = gene.sequence.to_string().matches("AAG").count();
+
+        assert!(lysines > 0 && lysines < 25);
+    }
+}
+
+use std::{cmp, error, fmt};
+use amino_acids::{AminoAcid, Sequence as AminoAcidSequence};
+use codon_usage::CodonUsage;
+use dna::{Nucleobase, Sequence as DnaSequence};
+use genetic_code::GeneticCode;
+use random::SplitMix64;
+use restriction::Enzyme;
+use rna::Codon;
+
+// how many times the optimizer may go back to a previous amino acid per amino acid of the protein before giving up,
+// as constraints which can't be satisfied would otherwise make it try every combination of codons
+const BACKTRACKS_PER_AMINO_ACID: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub enum OptimizationError {
+    /// The genetic code has no codon for the amino acid at `position`.
+    NoCodon { position: usize },
+    /// No codon of the amino acid at `position` avoids the restriction sites and homopolymer runs.
+    Unsatisfiable { position: usize },
+}
+
+impl error::Error for OptimizationError {
+    fn description(&self) -> &str {
+        match self {
+            OptimizationError::NoCodon { .. } => "the genetic code has no codon for an amino acid",
+            OptimizationError::Unsatisfiable { .. } => {
+                "no codon avoids the restriction sites and homopolymer runs"
+            }
+        }
+    }
+}
+
+impl fmt::Display for OptimizationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OptimizationError::NoCodon { position } => write!(
+                f,
+                "the genetic code has no codon for the amino acid at position {}",
+                position
+            ),
+            OptimizationError::Unsatisfiable { position } => write!(
+                f,
+                "no codon of the amino acid at position {} avoids the restriction sites and homopolymer runs",
+                position
+            ),
+        }
+    }
+}
+
+/// How the codon of each amino acid is picked among its synonymous codons.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CodonSelection {
+    /// The codon the host uses most, which maximizes the CAI.
+    MostUsed,
+    /// A codon drawn at random, with a probability proportional to its use by the host.
+    /// The same seed always gives the same gene.
+    Proportional { seed: u64 },
+}
+
+/// Optimized gene.
+#[derive(Debug, PartialEq)]
+pub struct OptimizedGene {
+    pub sequence: DnaSequence,
+    pub gc_content: f64,
+    /// CAI of the gene against the host usage, see `CodonUsage::cai`.
+    pub cai: Option<f64>,
+}
+
+/// Back-translates proteins with the codons used by a host, by default the most used ones of the standard genetic code.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::amino_acids::Sequence;
+/// use rosalind::codon_optimization::CodonOptimizer;
+/// use rosalind::codon_usage::CodonUsage;
+/// use rosalind::restriction::Enzyme;
+///
+/// let host = CodonUsage::try_from("AUG 20.0(20)  AAA 30.0(30)  AAG 10.0(10)  UAA 5.0(5)").unwrap();
+///
+/// let optimizer = CodonOptimizer::new(host)
+///     .avoid(Enzyme::by_name("HindIII").unwrap())
+///     .max_homopolymer(5);
+///
+/// let gene = optimizer.optimize(&Sequence::try_from("MKK*").unwrap()).unwrap();
+///
+/// println!("{} (GC: {:.2}, CAI: {:?})", gene.sequence, gene.gc_content, gene.cai);
+/// ```
+pub struct CodonOptimizer {
+    usage: CodonUsage,
+    genetic_code: GeneticCode,
+    selection: CodonSelection,
+    avoided: Vec<Enzyme>,
+    max_homopolymer: Option<usize>,
+}
+
+impl CodonOptimizer {
+    /// Creates an optimizer for a host which uses codons as told by `usage`.
+    pub fn new(usage: CodonUsage) -> Self {
+        CodonOptimizer {
+            usage: usage,
+            genetic_code: GeneticCode::standard(),
+            selection: CodonSelection::MostUsed,
+            avoided: Vec::new(),
+            max_homopolymer: None,
+        }
+    }
+
+    /// Uses `genetic_code` to find the codons of each amino acid.
+    pub fn genetic_code(self, genetic_code: GeneticCode) -> Self {
+        CodonOptimizer {
+            genetic_code: genetic_code,
+            ..self
+        }
+    }
+
+    pub fn selection(self, selection: CodonSelection) -> Self {
+        CodonOptimizer {
+            selection: selection,
+            ..self
+        }
+    }
+
+    /// Doesn't let the recognition site of `enzyme` appear on either strand of the gene.
+    pub fn avoid(mut self, enzyme: Enzyme) -> Self {
+        self.avoided.push(enzyme);
+
+        self
+    }
+
+    /// Doesn't let the same nucleobase be repeated more than `max_homopolymer` times in a row.
+    pub fn max_homopolymer(self, max_homopolymer: usize) -> Self {
+        CodonOptimizer {
+            max_homopolymer: Some(max_homopolymer),
+            ..self
+        }
+    }
+
+    /// Returns a gene coding for `protein`, codon by codon (a stop codon is only added if `protein` ends with a stop).
+    pub fn optimize(&self, protein: &AminoAcidSequence) -> Result<OptimizedGene, OptimizationError> {
+        let mut rng = match self.selection {
+            CodonSelection::MostUsed => None,
+            CodonSelection::Proportional { seed } => Some(SplitMix64::new(seed)),
+        };
+
+        // codons of the amino acids up to the current one, in the order they are tried, and how many were tried
+        let mut choices: Vec<(Vec<Codon>, usize)> = Vec::with_capacity(protein.len());
+        let mut nucleobases = Vec::with_capacity(3 * protein.len());
+        let mut backtracks = 0;
+        let mut deepest = 0;
+
+        while nucleobases.len() < 3 * protein.len() {
+            let position = nucleobases.len() / 3;
+
+            if choices.len() == position {
+                let candidates = self.candidates(&protein[position], rng.as_mut());
+
+                if candidates.is_empty() {
+                    return Err(OptimizationError::NoCodon { position: position });
+                }
+
+                choices.push((candidates, 0));
+            }
+
+            let accepted = {
+                let (ref candidates, ref mut tried) = choices[position];
+                let mut accepted = false;
+
+                while *tried < candidates.len() && !accepted {
+                    nucleobases.extend(candidates[*tried].0.iter().map(|&nucleobase| Nucleobase::from(nucleobase)));
+                    *tried += 1;
+
+                    accepted = self.is_acceptable(&nucleobases);
+
+                    if !accepted {
+                        nucleobases.truncate(3 * position);
+                    }
+                }
+
+                accepted
+            };
+
+            if accepted {
+                continue;
+            }
+
+            // every codon was tried: back to the previous amino acid, whose next codon may help
+            deepest = deepest.max(position);
+            backtracks += 1;
+            choices.pop();
+
+            if position == 0 || backtracks > BACKTRACKS_PER_AMINO_ACID * protein.len() {
+                return Err(OptimizationError::Unsatisfiable { position: deepest });
+            }
+
+            nucleobases.truncate(3 * (position - 1));
+        }
+
+        let sequence = DnaSequence::new(nucleobases);
+
+        Ok(OptimizedGene {
+            gc_content: sequence.gc_content(),
+            cai: self.usage.cai(&sequence, &self.genetic_code),
+            sequence: sequence,
+        })
+    }
+
+    // the codons of amino_acid in the order they should be tried
+    fn candidates(&self, amino_acid: &AminoAcid, rng: Option<&mut SplitMix64>) -> Vec<Codon> {
+        let mut codons = self.genetic_code.codons(amino_acid);
+
+        match rng {
+            // stable, so equally used codons are tried in the TCAG order
+            None => codons.sort_by_key(|&codon| cmp::Reverse(self.usage.count(codon))),
+            Some(rng) => {
+                let mut drawn = Vec::with_capacity(codons.len());
+
+                // drawing without replacement, the unused codons only come last
+                while let Some(index) = {
+                    let weights: Vec<f64> = codons.iter().map(|&codon| self.usage.count(codon) as f64).collect();
+
+                    rng.weighted_index(&weights)
+                } {
+                    drawn.push(codons.remove(index));
+                }
+
+                drawn.extend(codons.drain(..));
+                codons = drawn;
+            }
+        }
+
+        codons
+    }
+
+    // whether the last codon of the gene doesn't make an avoided site or a too long homopolymer run
+    // (the previous codons have already been checked)
+    fn is_acceptable(&self, nucleobases: &[Nucleobase]) -> bool {
+        let longest_site = self.avoided.iter().map(|enzyme| enzyme.site().len()).max().unwrap_or(0);
+        let longest_run = self.max_homopolymer.map_or(0, |max| max + 1);
+        // anything ending in the last codon is in there
+        let context = nucleobases.len().min(longest_site.max(longest_run) + 2);
+        let tail = &nucleobases[nucleobases.len() - context..];
+
+        if let Some(max) = self.max_homopolymer {
+            let mut run = 0;
+
+            for (index, nucleobase) in tail.iter().enumerate() {
+                run = if index > 0 && *nucleobase == tail[index - 1] { run + 1 } else { 1 };
+
+                if run > max {
+                    return false;
+                }
+            }
+        }
+
+        if self.avoided.is_empty() {
+            return true;
+        }
+
+        let tail = DnaSequence::new(tail.to_vec());
+
+        self.avoided.iter().all(|enzyme| enzyme.find_sites(&tail).is_empty())
+    }
+}
@@ -0,0 +1,393 @@
+// codon usage: how often each codon is used by a set of coding sequences, and how close a gene's codons are to it
+//
+// usage tables are read and written in the format of the Codon Usage Database (https://www.kazusa.or.jp/codon/),
+// each codon followed by its frequency per thousand and its count:
+//
+//     UUU 17.6(714298)  UCU 15.2(618711)  UAU 12.2(495699)  UGU 10.6(430311)
+//     UUC 20.3(824692)  UCC 17.7(718892)  UAC 15.3(622407)  UGC 12.6(513028)
+//     ...
+//
+// only the counts are read back, the frequencies follow from them; T is accepted for U and missing codons are never used
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::Sequence as DnaSequence;
+    use genetic_code::GeneticCode;
+    use rna::Codon;
+    use super::{CodonUsage, CodonUsageError};
+
+    fn codon(string: &str) -> Codon {
+        Codon::try_from(string).unwrap()
+    }
+
+    fn reference() -> CodonUsage {
+        let genes = vec![
+            DnaSequence::try_from("CTGCTGCTGTTA").unwrap(),
+            // the trailing nucleobase isn't a codon
+            DnaSequence::try_from("AAAAAGAAGC").unwrap(),
+        ];
+
+        CodonUsage::from_sequences(&genes)
+    }
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!((value - expected).abs() < 1e-6, "{} instead of {}", value, expected);
+    }
+
+    #[test]
+    fn count() {
+        let usage = reference();
+
+        assert_eq!(usage.total(), 7);
+        assert_eq!(usage.count(codon("CUG")), 3);
+        assert_eq!(usage.count(codon("AAG")), 2);
+        assert_eq!(usage.count(codon("CUU")), 0);
+        assert_close(usage.frequency(codon("CUG")), 3000.0 / 7.0);
+    }
+
+    #[test]
+    fn rscu() {
+        let usage = reference();
+        let standard = GeneticCode::standard();
+
+        assert_close(usage.rscu(codon("CUG"), &standard).unwrap(), 4.5);
+        assert_close(usage.rscu(codon("UUA"), &standard).unwrap(), 1.5);
+        assert_close(usage.rscu(codon("CUU"), &standard).unwrap(), 0.0);
+        assert_close(usage.rscu(codon("AAA"), &standard).unwrap(), 2.0 / 3.0);
+        assert!(usage.rscu(codon("GGG"), &standard).is_none());
+    }
+
+    #[test]
+    fn cai() {
+        let usage = reference();
+        let standard = GeneticCode::standard();
+        let gene = |string| DnaSequence::try_from(string).unwrap();
+
+        assert_close(usage.cai(&gene("CTGAAG"), &standard).unwrap(), 1.0);
+
+        // ATG (single codon) and TAA (stop) don't count, AAA is half as adapted as AAG
+        assert_close(usage.cai(&gene("ATGCTGAAATAA"), &standard).unwrap(), 0.5f64.sqrt());
+
+        // an unused codon counts as used half a time
+        assert_close(usage.cai(&gene("CTT"), &standard).unwrap(), 0.5 / 3.0);
+
+        // glycine isn't in the reference at all
+        assert!(usage.cai(&gene("ATGGGG"), &standard).is_none());
+    }
+
+    #[test]
+    fn export_and_import() {
+        let usage = reference();
+        let table = usage.to_string();
+
+        assert_eq!(table.lines().count(), 16 + 3);
+        assert!(table.starts_with("UUU   0.0(     0)  UCU   0.0(     0)  UAU   0.0(     0)  UGU   0.0(     0)\n"));
+        assert!(table.contains("CUG 428.6(     3)"));
+
+        assert_eq!(CodonUsage::try_from(table.as_str()).unwrap(), usage);
+    }
+
+    #[test]
+    fn import() {
+        let table = "# human, partial\n\
+                     TTT 17.6(714298)  TCT 15.2(618711)\n\
+                     UUC 20.3(  824692)";
+
+        let usage = CodonUsage::try_from(table).unwrap();
+
+        assert_eq!(usage.count(codon("UUU")), 714298);
+        assert_eq!(usage.count(codon("UUC")), 824692);
+        assert_eq!(usage.count(codon("GGG")), 0);
+        assert_eq!(usage.total(), 714298 + 618711 + 824692);
+
+        match CodonUsage::try_from("UUU 17.6") {
+            Err(CodonUsageError::InvalidEntry { line: 1 }) => {}
+            _ => panic!("An entry without count shouldn't be parsed"),
+        }
+
+        match CodonUsage::try_from("UUU 1.0(1)\nTTT 1.0(1)") {
+            Err(CodonUsageError::DuplicateCodon { line: 2 }) => {}
+            _ => panic!("A codon can't be counted twice"),
+        }
+    }
+}
+
+use std::{error, fmt, fs, io};
+use std::convert::{self, TryFrom};
+use amino_acids::AminoAcid;
+use dna::Sequence as DnaSequence;
+use genetic_code::GeneticCode;
+use rna::{Codon, Sequence as RnaSequence, StrictCodonIterator};
+
+#[derive(Debug)]
+pub enum CodonUsageError {
+    Io(io::Error),
+    InvalidEntry { line: usize },
+    DuplicateCodon { line: usize },
+}
+
+impl error::Error for CodonUsageError {
+    fn description(&self) -> &str {
+        match self {
+            CodonUsageError::Io(_) => "couldn't read the codon usage table",
+            CodonUsageError::InvalidEntry { .. } => "an entry isn't a codon followed by its frequency and count",
+            CodonUsageError::DuplicateCodon { .. } => "a codon appears more than once",
+        }
+    }
+}
+
+impl fmt::Display for CodonUsageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodonUsageError::Io(error) => write!(f, "couldn't read the codon usage table: {}", error),
+            CodonUsageError::InvalidEntry { line } => write!(
+                f,
+                "an entry of line {} isn't a codon followed by its frequency and count",
+                line
+            ),
+            CodonUsageError::DuplicateCodon { line } => {
+                write!(f, "a codon of line {} appears more than once", line)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for CodonUsageError {
+    fn from(error: io::Error) -> Self {
+        CodonUsageError::Io(error)
+    }
+}
+
+/// Number of times each codon is used.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodonUsage {
+    // count of each codon in the TCAG order
+    counts: Vec<u64>,
+}
+
+impl Default for CodonUsage {
+    fn default() -> Self {
+        CodonUsage::new()
+    }
+}
+
+impl CodonUsage {
+    /// Creates a table where no codon is used.
+    pub fn new() -> Self {
+        CodonUsage { counts: vec![0; 64] }
+    }
+
+    /// Counts the codons of the coding `sequences`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::codon_usage::CodonUsage;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::rna::Codon;
+    ///
+    /// let genes = vec![Sequence::try_from("ATGAAATAA").unwrap(), Sequence::try_from("ATGTAG").unwrap()];
+    ///
+    /// let usage = CodonUsage::from_sequences(&genes);
+    ///
+    /// assert_eq!(usage.count(Codon::try_from("AUG").unwrap()), 2);
+    /// ```
+    pub fn from_sequences(sequences: &[DnaSequence]) -> Self {
+        let mut usage = CodonUsage::new();
+
+        for sequence in sequences {
+            usage.add(sequence);
+        }
+
+        usage
+    }
+
+    /// Counts the codons of the coding `sequence`, read from its first nucleobase.
+    /// The trailing nucleobases which don't make a codon are ignored.
+    pub fn add(&mut self, sequence: &DnaSequence) {
+        let rna = RnaSequence::from(sequence);
+
+        for codon in StrictCodonIterator::new(&rna) {
+            self.counts[codon.index()] += 1;
+        }
+    }
+
+    /// Loads a usage table from a file, see the format at the top of this module.
+    pub fn load(path: &str) -> Result<Self, CodonUsageError> {
+        let contents = fs::read_to_string(path)?;
+
+        CodonUsage::try_from(contents.as_str())
+    }
+
+    pub fn count(&self, codon: Codon) -> u64 {
+        self.counts[codon.index()]
+    }
+
+    /// Returns the number of codons counted.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns how many times `codon` is used per thousand codons, 0 if no codon was counted.
+    pub fn frequency(&self, codon: Codon) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => 1000.0 * self.count(codon) as f64 / total as f64,
+        }
+    }
+
+    /// Returns the relative synonymous codon usage of `codon`: its count divided by the average count
+    /// of the codons coding for the same amino acid in `genetic_code`,
+    /// so 1 if all synonymous codons are equally used.
+    /// Returns None if none of them was used.
+    pub fn rscu(&self, codon: Codon, genetic_code: &GeneticCode) -> Option<f64> {
+        let synonymous = genetic_code.codons(&genetic_code.amino_acid(codon));
+        let total: u64 = synonymous.iter().map(|&codon| self.count(codon)).sum();
+
+        if total == 0 {
+            return None;
+        }
+
+        Some(self.count(codon) as f64 * synonymous.len() as f64 / total as f64)
+    }
+
+    /// Returns the relative adaptiveness of `codon`: its count divided by the count of the most used codon
+    /// coding for the same amino acid in `genetic_code`.
+    /// Following Sharp and Li, unused codons count as used 0.5 time so that they don't make the CAI 0.
+    ///
+    /// Returns None for the stop codons, the amino acids coded by a single codon,
+    /// and the amino acids whose codons were never used, which don't tell anything about adaptation.
+    pub fn relative_adaptiveness(&self, codon: Codon, genetic_code: &GeneticCode) -> Option<f64> {
+        let amino_acid = genetic_code.amino_acid(codon);
+        let synonymous = genetic_code.codons(&amino_acid);
+
+        if amino_acid == AminoAcid::Stop || synonymous.len() < 2 {
+            return None;
+        }
+
+        let most_used = synonymous.iter().map(|&codon| self.count(codon)).max().unwrap_or(0);
+
+        if most_used == 0 {
+            return None;
+        }
+
+        let count = match self.count(codon) {
+            0 => 0.5,
+            count => count as f64,
+        };
+
+        Some(count / most_used as f64)
+    }
+
+    /// Returns the codon adaptation index of the coding `gene` against this reference usage,
+    /// the geometric mean of the relative adaptiveness of its codons, between 0 and 1.
+    /// The codons without relative adaptiveness are left out, the result is None if there is none left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::codon_usage::CodonUsage;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::genetic_code::GeneticCode;
+    ///
+    /// let highly_expressed = vec![Sequence::try_from("CTGCTGCTGAAGAAG").unwrap()];
+    /// let reference = CodonUsage::from_sequences(&highly_expressed);
+    ///
+    /// let gene = Sequence::try_from("ATGCTGAAGTAA").unwrap();
+    ///
+    /// assert_eq!(reference.cai(&gene, &GeneticCode::standard()), Some(1.0));
+    /// ```
+    pub fn cai(&self, gene: &DnaSequence, genetic_code: &GeneticCode) -> Option<f64> {
+        let rna = RnaSequence::from(gene);
+        let mut log_sum = 0.0;
+        let mut codons = 0;
+
+        for codon in StrictCodonIterator::new(&rna) {
+            if let Some(adaptiveness) = self.relative_adaptiveness(codon, genetic_code) {
+                log_sum += adaptiveness.ln();
+                codons += 1;
+            }
+        }
+
+        if codons == 0 {
+            None
+        } else {
+            Some((log_sum / codons as f64).exp())
+        }
+    }
+}
+
+impl fmt::Display for CodonUsage {
+    /// Writes the table in the format described at the top of this module,
+    /// a line per first and third nucleobases, a column per second nucleobase.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for first in 0..4 {
+            if first > 0 {
+                writeln!(f)?;
+            }
+
+            for third in 0..4 {
+                let entries: Vec<String> = (0..4)
+                    .map(|second| {
+                        let codon = Codon::from_index(16 * first + 4 * second + third);
+
+                        format!("{} {:5.1}({:6})", codon, self.frequency(codon), self.count(codon))
+                    })
+                    .collect();
+
+                writeln!(f, "{}", entries.join("  "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> convert::TryFrom<&'a str> for CodonUsage {
+    type Error = CodonUsageError;
+
+    /// Parses a usage table in the format described at the top of this module.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut usage = CodonUsage::new();
+        let mut seen = [false; 64];
+
+        for (line_index, line) in value.lines().enumerate() {
+            let line = line.trim();
+            let invalid_entry = || CodonUsageError::InvalidEntry { line: line_index + 1 };
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // the counts may be padded inside their parentheses
+            let line = line.replace(|ch| ch == '(' || ch == ')', " ");
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            if tokens.len() % 3 != 0 {
+                return Err(invalid_entry());
+            }
+
+            for entry in tokens.chunks(3) {
+                let codon = Codon::try_from(entry[0].replace('T', "U").as_str()).map_err(|_| invalid_entry())?;
+
+                if entry[1].parse::<f64>().is_err() {
+                    return Err(invalid_entry());
+                }
+
+                let count = entry[2].parse::<u64>().map_err(|_| invalid_entry())?;
+
+                if seen[codon.index()] {
+                    return Err(CodonUsageError::DuplicateCodon { line: line_index + 1 });
+                }
+
+                seen[codon.index()] = true;
+                usage.counts[codon.index()] = count;
+            }
+        }
+
+        Ok(usage)
+    }
+}
@@ -1,5 +1,5 @@
 pub use self::nucleobase::Nucleobase;
-pub use self::sequence::Sequence;
+pub use self::sequence::{Sequence, Topology};
 
 pub mod nucleobase {
     #[cfg(test)]
@@ -33,9 +33,30 @@ pub mod nucleobase {
             assert_eq!(Cytosine.complement(), Guanine);
             assert_eq!(Guanine.complement(), Cytosine);
         }
+
+        #[test]
+        fn from_rna_nucleobase() {
+            use rna::Nucleobase as RnaNucleobase;
+            use super::{Nucleobase, Nucleobase::*};
+
+            assert_eq!(Nucleobase::from(RnaNucleobase::Adenine), Adenine);
+            assert_eq!(Nucleobase::from(RnaNucleobase::Uracil), Thymine);
+            assert_eq!(Nucleobase::from(RnaNucleobase::Cytosine), Cytosine);
+            assert_eq!(Nucleobase::from(RnaNucleobase::Guanine), Guanine);
+        }
+
+        #[test]
+        fn purines_and_pyrimidines() {
+            use super::Nucleobase::*;
+
+            assert!(Adenine.is_purine() && Guanine.is_purine());
+            assert!(Cytosine.is_pyrimidine() && Thymine.is_pyrimidine());
+            assert!(!Adenine.is_pyrimidine() && !Thymine.is_purine());
+        }
     }
 
     use std::{convert, error, fmt};
+    use rna::Nucleobase as RnaNucleobase;
 
     #[derive(Clone, Debug, PartialEq)]
     pub enum Nucleobase {
@@ -66,6 +87,28 @@ pub mod nucleobase {
                 Guanine => Cytosine,
             }
         }
+
+        /// Returns whether the nucleobase is a purine (A or G, two rings).
+        pub fn is_purine(&self) -> bool {
+            match self {
+                Nucleobase::Adenine | Nucleobase::Guanine => true,
+                Nucleobase::Cytosine | Nucleobase::Thymine => false,
+            }
+        }
+
+        /// Returns whether the nucleobase is a pyrimidine (C or T, one ring).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rosalind::dna::Nucleobase;
+        ///
+        /// assert!(Nucleobase::Cytosine.is_pyrimidine());
+        /// assert!(!Nucleobase::Guanine.is_pyrimidine());
+        /// ```
+        pub fn is_pyrimidine(&self) -> bool {
+            !self.is_purine()
+        }
     }
 
     impl fmt::Display for Nucleobase {
@@ -139,12 +182,33 @@ pub mod nucleobase {
             Ok(nucleobase)
         }
     }
+
+    impl From<RnaNucleobase> for Nucleobase {
+        /// Converts a RNA nucleobase to its equivalent DNA nucleobase (uracil to thymine).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rosalind::dna::Nucleobase as DnaNucleobase;
+        /// use rosalind::rna::Nucleobase as RnaNucleobase;
+        ///
+        /// assert_eq!(DnaNucleobase::from(RnaNucleobase::Uracil), DnaNucleobase::Thymine);
+        /// ```
+        fn from(rna_nucleobase: RnaNucleobase) -> Self {
+            match rna_nucleobase {
+                RnaNucleobase::Adenine => Nucleobase::Adenine,
+                RnaNucleobase::Uracil => Nucleobase::Thymine,
+                RnaNucleobase::Guanine => Nucleobase::Guanine,
+                RnaNucleobase::Cytosine => Nucleobase::Cytosine,
+            }
+        }
+    }
 }
 
 pub mod sequence {
     #[cfg(test)]
     mod tests {
-        use super::Sequence;
+        use super::{Sequence, Topology};
         use super::super::{nucleobase, Nucleobase};
         use std::convert::TryFrom;
 
@@ -165,7 +229,7 @@ pub mod sequence {
 
             let sequence = "AATGCGA";
 
-            let expected_sequence = Sequence(vec![
+            let expected_sequence = Sequence::new(vec![
                 Adenine, Adenine, Thymine, Guanine, Cytosine, Guanine, Adenine
             ]);
 
@@ -186,6 +250,19 @@ pub mod sequence {
             assert_eq!(sequence.count_nucleobases(), expected_count);
         }
 
+        #[test]
+        fn from_rna_sequence() {
+            let rna_sequence = ::rna::Sequence::try_from("AAUGGCCAU").unwrap();
+
+            assert_eq!(Sequence::from(&rna_sequence), Sequence::try_from("AATGGCCAT").unwrap());
+        }
+
+        #[test]
+        fn gc_content() {
+            assert_eq!(Sequence::try_from("AATAGGCTA").unwrap().gc_content(), 3.0 / 9.0);
+            assert_eq!(Sequence::try_from("").unwrap().gc_content(), 0.0);
+        }
+
         #[test]
         fn reverse_complement() {
             let sequence = Sequence::try_from("AATAGGCTA").unwrap();
@@ -194,13 +271,69 @@ pub mod sequence {
 
             assert_eq!(sequence.reverse_complement(), expected_reverse_complement);
         }
+
+        #[test]
+        fn topology() {
+            let sequence = Sequence::try_from("AATAGGCTA").unwrap();
+
+            assert_eq!(sequence.topology(), Topology::Linear);
+
+            let plasmid = sequence.with_topology(Topology::Circular);
+
+            assert!(plasmid.is_circular());
+            assert!(plasmid.reverse_complement().is_circular());
+            assert_ne!(plasmid, Sequence::try_from("AATAGGCTA").unwrap());
+        }
+
+        #[test]
+        fn subsequence() {
+            let sequence = Sequence::try_from("AATAGGCTA").unwrap();
+
+            assert_eq!(sequence.subsequence(2, 4), Sequence::try_from("TAGG").unwrap());
+            assert_eq!(sequence.subsequence(9, 0), Sequence::new(Vec::new()));
+
+            let plasmid = sequence.with_topology(Topology::Circular);
+
+            assert_eq!(plasmid.subsequence(7, 4), Sequence::try_from("TAAA").unwrap());
+            assert_eq!(plasmid.subsequence(8, 12), Sequence::try_from("AAATAGGCTAAA").unwrap());
+        }
+
+        #[test]
+        #[should_panic]
+        fn subsequence_beyond_the_end_of_a_linear_sequence() {
+            Sequence::try_from("AATAGGCTA").unwrap().subsequence(7, 4);
+        }
+
+        #[test]
+        fn rotate() {
+            let plasmid = Sequence::try_from("AATAGGCTA").unwrap().with_topology(Topology::Circular);
+
+            let rotated = plasmid.rotate(4);
+
+            assert_eq!(rotated.to_string(), "GGCTAAATA");
+            assert!(rotated.is_circular());
+            assert_eq!(rotated.rotate(5), plasmid);
+            assert_eq!(plasmid.rotate(9), plasmid);
+        }
     }
 
     use std::{convert, fmt, ops};
     use super::Nucleobase;
 
+    /// Sequence of DNA nucleobases, linear unless told otherwise.
+    /// Two sequences with the same nucleobases but different topologies are different.
     #[derive(Debug, PartialEq)]
-    pub struct Sequence(Vec<Nucleobase>);
+    pub struct Sequence {
+        nucleobases: Vec<Nucleobase>,
+        topology: Topology,
+    }
+
+    /// Shape of a DNA molecule: a linear one has two ends, a circular one (e.g. a plasmid) has none.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Topology {
+        Linear,
+        Circular,
+    }
 
     // as of 2018, the largest genome ever sequenced is the Axolotl genome: approx. 32 billion base pairs
     // if we ever happen to count the nucleobases of such a big genome,
@@ -214,8 +347,105 @@ pub mod sequence {
     }
 
     impl Sequence {
+        /// Creates a linear sequence.
+        pub fn new(nucleobases: Vec<Nucleobase>) -> Self {
+            Sequence {
+                nucleobases: nucleobases,
+                topology: Topology::Linear,
+            }
+        }
+
+        /// Returns the same sequence with another topology.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use rosalind::dna::{Sequence, Topology};
+        ///
+        /// let plasmid = Sequence::try_from("GAATTCAAAAGGATCC").unwrap().with_topology(Topology::Circular);
+        ///
+        /// assert!(plasmid.is_circular());
+        /// ```
+        pub fn with_topology(self, topology: Topology) -> Self {
+            Sequence {
+                topology: topology,
+                ..self
+            }
+        }
+
+        pub fn topology(&self) -> Topology {
+            self.topology
+        }
+
+        pub fn is_circular(&self) -> bool {
+            self.topology == Topology::Circular
+        }
+
         pub fn len(&self) -> usize {
-            self.0.len()
+            self.nucleobases.len()
+        }
+
+        /// Returns the linear sequence of `length` nucleobases starting at `start`.
+        /// In a circular sequence, it goes on from the beginning after the end, as many times as needed.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the subsequence goes beyond the end of a linear sequence,
+        /// or if `start` is outside of a circular one.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use rosalind::dna::{Sequence, Topology};
+        ///
+        /// let plasmid = Sequence::try_from("ATTCAAAAGGATCCAAAAGA").unwrap().with_topology(Topology::Circular);
+        ///
+        /// // the EcoRI site spans the origin
+        /// assert_eq!(plasmid.subsequence(18, 6).to_string(), "GAATTC");
+        /// ```
+        pub fn subsequence(&self, start: usize, length: usize) -> Self {
+            let nucleobases = match self.topology {
+                Topology::Linear => self.nucleobases[start..start + length].to_vec(),
+                Topology::Circular => {
+                    assert!(
+                        start < self.len() || (start == 0 && length == 0),
+                        "the start of the subsequence is outside of the circular sequence"
+                    );
+
+                    (start..start + length)
+                        .map(|position| self.nucleobases[position % self.len()].clone())
+                        .collect()
+                }
+            };
+
+            Sequence::new(nucleobases)
+        }
+
+        /// Returns the sequence starting at `origin`, which must be within the sequence, and going around from there.
+        /// It keeps its topology, even though rotating only makes sense for circular sequences.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use rosalind::dna::{Sequence, Topology};
+        ///
+        /// let plasmid = Sequence::try_from("ATTCAAAAGGATCCAAAAGA").unwrap().with_topology(Topology::Circular);
+        ///
+        /// println!("{}", plasmid.rotate(18));
+        /// ```
+        pub fn rotate(&self, origin: usize) -> Self {
+            let mut nucleobases = self.nucleobases.clone();
+
+            // rotating by the whole length is a no-op, like in a circle
+            nucleobases.rotate_left(if origin == self.len() { 0 } else { origin });
+
+            Sequence {
+                nucleobases: nucleobases,
+                topology: self.topology,
+            }
         }
 
         /// Returns the reverse complement of the DNA sequence.
@@ -229,13 +459,15 @@ pub mod sequence {
         /// println!("{}", sequence.reverse_complement());
         /// ```
         pub fn reverse_complement(&self) -> Self {
-            Sequence(
-                self.0
+            Sequence {
+                nucleobases: self
+                    .nucleobases
                     .iter()
                     .rev()
                     .map(|nucleobase| nucleobase.complement())
                     .collect(),
-            )
+                topology: self.topology,
+            }
         }
 
         /// Returns the number of each A, T, C, G nucleobase in the `dna` sequence.
@@ -263,7 +495,7 @@ pub mod sequence {
                 guanines: 0,
             };
 
-            for nucleobase in self.0.iter() {
+            for nucleobase in self.nucleobases.iter() {
                 match *nucleobase {
                     Adenine => count.adenines += 1,
                     Thymine => count.thymines += 1,
@@ -274,11 +506,32 @@ pub mod sequence {
 
             count
         }
+
+        /// Returns the proportion of guanines and cytosines, in [0, 1], 0 for an empty sequence.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// let sequence = rosalind::dna::Sequence::try_from("AATGGCCA").unwrap();
+        ///
+        /// assert_eq!(sequence.gc_content(), 0.5);
+        /// ```
+        pub fn gc_content(&self) -> f64 {
+            if self.nucleobases.is_empty() {
+                return 0.0;
+            }
+
+            let count = self.count_nucleobases();
+
+            (count.guanines + count.cytosines) as f64 / self.nucleobases.len() as f64
+        }
     }
 
     impl fmt::Display for Sequence {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            for nucleobase in &self.0 {
+            for nucleobase in &self.nucleobases {
                 write!(f, "{}", nucleobase)?;
             }
 
@@ -286,6 +539,31 @@ pub mod sequence {
         }
     }
 
+    impl AsRef<[Nucleobase]> for Sequence {
+        fn as_ref(&self) -> &[Nucleobase] {
+            &self.nucleobases
+        }
+    }
+
+    impl<'a> From<&'a ::rna::Sequence> for Sequence {
+        /// Converts a RNA sequence back to a linear DNA sequence (replaces all uracil nucleobases with thymine),
+        /// e.g. to get the cDNA of a mRNA.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// let rna_sequence = rosalind::rna::Sequence::try_from("UUACGGGCAU").unwrap();
+        /// let dna_sequence = rosalind::dna::Sequence::from(&rna_sequence);
+        ///
+        /// assert_eq!(dna_sequence.to_string(), "TTACGGGCAT");
+        /// ```
+        fn from(rna_sequence: &'a ::rna::Sequence) -> Self {
+            Sequence::new(rna_sequence.into_iter().map(Nucleobase::from).collect())
+        }
+    }
+
     /// ```
     /// use std::convert::TryFrom;
     ///
@@ -299,7 +577,7 @@ pub mod sequence {
 
         #[inline]
         fn index(&self, index: ops::Range<usize>) -> &[Nucleobase] {
-            &self.0[index]
+            &self.nucleobases[index]
         }
     }
 
@@ -343,7 +621,7 @@ pub mod sequence {
                 sequence.push(nucleobase);
             }
 
-            Ok(Sequence(sequence))
+            Ok(Sequence::new(sequence))
         }
     }
 
@@ -365,7 +643,7 @@ pub mod sequence {
         type IntoIter = ::std::iter::Cloned<::std::slice::Iter<'a, Nucleobase>>;
 
         fn into_iter(self) -> Self::IntoIter {
-            self.0.iter().cloned()
+            self.nucleobases.iter().cloned()
         }
     }
 }
@@ -0,0 +1,747 @@
+// genetic codes: which amino acid each of the 64 codons codes for, and which codons can start a translation
+//
+// the built-in tables are NCBI's (https://www.ncbi.nlm.nih.gov/Taxonomy/Utils/wprintgc.cgi), identified by their number,
+// written the way NCBI prints them: one character per codon, stops as *, in the TCAG order of the codons
+// (TTT, TTC, TTA, TTG, TCT, ...) unless the Base1, Base2 and Base3 lines tell otherwise
+//
+// custom tables are read from files in the same format:
+//
+//     # comments and blank lines are ignored
+//     Name   = Vertebrate Mitochondrial
+//     Id     = 2
+//     AAs    = FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG
+//     Starts = ----------**--------------------MMMM----------**---M------------
+//
+// only AAs is mandatory, without Starts there is no start codon at all
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::AminoAcid;
+    use rna::{Codon, Sequence as RnaSequence};
+    use super::{GeneticCode, GeneticCodeError, TranslationError, TranslationMode};
+
+    fn codon(string: &str) -> Codon {
+        Codon::try_from(string).unwrap()
+    }
+
+    fn rna(string: &str) -> RnaSequence {
+        RnaSequence::try_from(string).unwrap()
+    }
+
+    #[test]
+    fn standard() {
+        let code = GeneticCode::standard();
+
+        assert_eq!(code.id(), 1);
+        assert_eq!(code.amino_acid(codon("AUG")), AminoAcid::Methionine);
+        assert_eq!(code.amino_acid(codon("UGG")), AminoAcid::Tryptophan);
+        assert_eq!(code.amino_acid(codon("UAA")), AminoAcid::Stop);
+        assert!(code.is_stop(codon("UGA")));
+        assert!(code.is_start(codon("CUG")));
+        assert!(!code.is_start(codon("GUG")));
+    }
+
+    #[test]
+    fn ncbi_tables() {
+        let tables = GeneticCode::ncbi_tables();
+
+        assert_eq!(tables.len(), 27);
+        assert!(tables.iter().all(|table| GeneticCode::ncbi(table.id()) == Some(table.clone())));
+        assert!(GeneticCode::ncbi(7).is_none());
+
+        // vertebrate mitochondrial: UGA is tryptophan, AGA and AGG are stops
+        let mitochondrial = GeneticCode::ncbi(2).unwrap();
+
+        assert_eq!(mitochondrial.amino_acid(codon("UGA")), AminoAcid::Tryptophan);
+        assert!(mitochondrial.is_stop(codon("AGA")));
+        assert_eq!(mitochondrial.amino_acid(codon("AUA")), AminoAcid::Methionine);
+
+        // bacterial: same amino acids as the standard code, more start codons
+        let bacterial = GeneticCode::ncbi(11).unwrap();
+
+        assert!(bacterial.is_start(codon("GUG")));
+        assert!(bacterial.is_start(codon("AUU")));
+    }
+
+    #[test]
+    fn codons() {
+        let standard = GeneticCode::standard();
+
+        assert_eq!(standard.codons(&AminoAcid::Methionine), vec![codon("AUG")]);
+        assert_eq!(standard.codons(&AminoAcid::Leucine).len(), 6);
+        assert_eq!(GeneticCode::ncbi(2).unwrap().codons(&AminoAcid::Methionine), vec![codon("AUA"), codon("AUG")]);
+    }
+
+    fn protein(code: &GeneticCode, sequence: &str, mode: TranslationMode) -> String {
+        code.translate(&rna(sequence), mode).unwrap().protein.to_string()
+    }
+
+    #[test]
+    fn translate() {
+        let standard = GeneticCode::standard();
+        let mitochondrial = GeneticCode::ncbi(2).unwrap();
+
+        assert_eq!(protein(&standard, "AUGAGAUGAAUA", TranslationMode::StopAtFirstStop), "MR");
+        assert_eq!(protein(&standard, "AUGAGAUGAAUA", TranslationMode::ReadThrough), "MR*I");
+        assert_eq!(protein(&mitochondrial, "AUGAGAUGAAUA", TranslationMode::ReadThrough), "M*WM");
+        assert_eq!(protein(&mitochondrial, "AUGUGAAUA", TranslationMode::FailOnInternalStop), "MWM");
+    }
+
+    #[test]
+    fn translate_fail_on_internal_stop() {
+        let standard = GeneticCode::standard();
+
+        assert_eq!(protein(&standard, "AUGAGAUGA", TranslationMode::FailOnInternalStop), "MR");
+
+        assert_eq!(
+            standard.translate(&rna("AUGUGAAUA"), TranslationMode::FailOnInternalStop),
+            Err(TranslationError::InternalStop { position: 1 })
+        );
+    }
+
+    #[test]
+    fn translate_partial_codon() {
+        use rna::Nucleobase::*;
+
+        let standard = GeneticCode::standard();
+
+        let translation = standard.translate(&rna("AUGAGAGC"), TranslationMode::ReadThrough).unwrap();
+
+        assert_eq!(translation.protein.to_string(), "MR");
+        assert_eq!(translation.partial_codon, vec![Guanine, Cytosine]);
+
+        // nothing is left over once translation has stopped
+        let translation = standard.translate(&rna("AUGUAGGC"), TranslationMode::StopAtFirstStop).unwrap();
+
+        assert_eq!(translation.protein.to_string(), "M");
+        assert!(translation.partial_codon.is_empty());
+    }
+
+    #[test]
+    fn parse_custom_table() {
+        let table = "# the Euplotid code, with UUG and CUG as start codons too\n\
+                     Name = Custom\n\
+                     Id = 101\n\
+                     AAs    = FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG\n\
+                     Starts = ---M------**-------M---------------M----------------------------\n";
+
+        let code = GeneticCode::try_from(table).unwrap();
+
+        assert_eq!(code.name(), "Custom");
+        assert_eq!(code.id(), 101);
+        assert_eq!(code.amino_acid(codon("UGA")), AminoAcid::Cysteine);
+        assert!(code.is_start(codon("UUG")));
+        assert!(!code.is_start(codon("GUG")));
+    }
+
+    #[test]
+    fn parse_custom_table_with_codon_order() {
+        // the standard code, with the codons in alphabetical (ACGU) order
+        let table = "AAs   = KNKNTTTTRSRSIIMIQHQHPPPPRRRRLLLLEDEDAAAAGGGGVVVV*Y*YSSSS*CWCLFLF\n\
+                     Base1 = AAAAAAAAAAAAAAAACCCCCCCCCCCCCCCCGGGGGGGGGGGGGGGGUUUUUUUUUUUUUUUU\n\
+                     Base2 = AAAACCCCGGGGUUUUAAAACCCCGGGGUUUUAAAACCCCGGGGUUUUAAAACCCCGGGGUUUU\n\
+                     Base3 = ACGUACGUACGUACGUACGUACGUACGUACGUACGUACGUACGUACGUACGUACGUACGUACGU\n";
+
+        let code = GeneticCode::try_from(table).unwrap();
+
+        assert_eq!(protein(&code, "AUGAGAUGAAUA", TranslationMode::ReadThrough), "MR*I");
+    }
+
+    #[test]
+    fn parse_invalid_table() {
+        match GeneticCode::try_from("Name = Nothing") {
+            Err(GeneticCodeError::MissingKey("AAs")) => {}
+            _ => panic!("A table without amino acids shouldn't be parsed"),
+        }
+
+        match GeneticCode::try_from("AAs = FFLL") {
+            Err(GeneticCodeError::InvalidLength { length: 4, .. }) => {}
+            _ => panic!("A table without 64 codons shouldn't be parsed"),
+        }
+
+        match GeneticCode::try_from("AAs FFLL") {
+            Err(GeneticCodeError::InvalidLine { line: 1 }) => {}
+            _ => panic!("A line without = shouldn't be parsed"),
+        }
+    }
+}
+
+use std::{error, fmt, fs, io};
+use std::convert::{self, TryFrom};
+use amino_acids::{AminoAcid, Sequence as AminoAcidSequence};
+use rna::{Codon, Nucleobase, Sequence as RnaSequence, StrictCodonIterator};
+
+// id, name, amino acids and start codons in the TCAG order
+const NCBI_TABLES: &[(u32, &str, &str, &str)] = &[
+    (
+        1,
+        "Standard",
+        "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**--*----M---------------M----------------------------",
+    ),
+    (
+        2,
+        "Vertebrate Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+        "----------**--------------------MMMM----------**---M------------",
+    ),
+    (
+        3,
+        "Yeast Mitochondrial",
+        "FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**----------------------MM----------------------------",
+    ),
+    (
+        4,
+        "Mold, Protozoan, and Coelenterate Mitochondrial and the Mycoplasma/Spiroplasma",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--MM------**-------M------------MMMM---------------M------------",
+    ),
+    (
+        5,
+        "Invertebrate Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+        "---M------**--------------------MMMM---------------M------------",
+    ),
+    (
+        6,
+        "Ciliate, Dasycladacean and Hexamita Nuclear",
+        "FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        9,
+        "Echinoderm and Flatworm Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "----------**-----------------------M---------------M------------",
+    ),
+    (
+        10,
+        "Euplotid Nuclear",
+        "FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**-----------------------M----------------------------",
+    ),
+    (
+        11,
+        "Bacterial, Archaeal and Plant Plastid",
+        "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**--*----M------------MMMM---------------M------------",
+    ),
+    (
+        12,
+        "Alternative Yeast Nuclear",
+        "FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*----M---------------M----------------------------",
+    ),
+    (
+        13,
+        "Ascidian Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+        "---M------**----------------------MM---------------M------------",
+    ),
+    (
+        14,
+        "Alternative Flatworm Mitochondrial",
+        "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "-----------*-----------------------M----------------------------",
+    ),
+    (
+        15,
+        "Blepharisma Nuclear",
+        "FFLLSSSSYY*QCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------*---*--------------------M----------------------------",
+    ),
+    (
+        16,
+        "Chlorophycean Mitochondrial",
+        "FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------*---*--------------------M----------------------------",
+    ),
+    (
+        21,
+        "Trematode Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "----------**-----------------------M---------------M------------",
+    ),
+    (
+        22,
+        "Scenedesmus obliquus Mitochondrial",
+        "FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "------*---*---*--------------------M----------------------------",
+    ),
+    (
+        23,
+        "Thraustochytrium Mitochondrial",
+        "FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--*-------**--*-----------------M--M---------------M------------",
+    ),
+    (
+        24,
+        "Rhabdopleuridae Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        "---M------**-------M---------------M---------------M------------",
+    ),
+    (
+        25,
+        "Candidate Division SR1 and Gracilibacteria",
+        "FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**-----------------------M---------------M------------",
+    ),
+    (
+        26,
+        "Pachysolen tannophilus Nuclear",
+        "FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*----M---------------M----------------------------",
+    ),
+    (
+        27,
+        "Karyorelict Nuclear",
+        "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        28,
+        "Condylostoma Nuclear",
+        "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*--------------------M----------------------------",
+    ),
+    (
+        29,
+        "Mesodinium Nuclear",
+        "FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        30,
+        "Peritrich Nuclear",
+        "FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        31,
+        "Blastocrithidia Nuclear",
+        "FFLLSSSSYYEECCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**-----------------------M----------------------------",
+    ),
+    (
+        32,
+        "Balanophoraceae Plastid",
+        "FFLLSSSSYY*WCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------*---*----M------------MMMM---------------M------------",
+    ),
+    (
+        33,
+        "Cephalodiscidae Mitochondrial",
+        "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        "---M-------*-------M---------------M---------------M------------",
+    ),
+];
+
+#[derive(Debug)]
+pub enum GeneticCodeError {
+    Io(io::Error),
+    InvalidLine { line: usize },
+    MissingKey(&'static str),
+    InvalidId,
+    InvalidLength { key: String, length: usize },
+    IllegalChar { key: String, ch: char, index: usize },
+    DuplicateCodon { index: usize },
+}
+
+impl error::Error for GeneticCodeError {
+    fn description(&self) -> &str {
+        match self {
+            GeneticCodeError::Io(_) => "couldn't read the genetic code",
+            GeneticCodeError::InvalidLine { .. } => "a line is neither a comment nor a key = value pair",
+            GeneticCodeError::MissingKey(_) => "a mandatory key is missing",
+            GeneticCodeError::InvalidId => "the id isn't a number",
+            GeneticCodeError::InvalidLength { .. } => "there must be one character per codon",
+            GeneticCodeError::IllegalChar { .. } => "illegal character",
+            GeneticCodeError::DuplicateCodon { .. } => "a codon appears more than once",
+        }
+    }
+}
+
+impl fmt::Display for GeneticCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeneticCodeError::Io(error) => write!(f, "couldn't read the genetic code: {}", error),
+            GeneticCodeError::InvalidLine { line } => {
+                write!(f, "line {} is neither a comment nor a key = value pair", line)
+            }
+            GeneticCodeError::MissingKey(key) => write!(f, "the {} key is missing", key),
+            GeneticCodeError::InvalidId => write!(f, "the id isn't a number"),
+            GeneticCodeError::InvalidLength { key, length } => {
+                write!(f, "{} has {} characters instead of 64, one per codon", key, length)
+            }
+            GeneticCodeError::IllegalChar { key, ch, index } => {
+                write!(f, "illegal character {} at index {} of {}", ch, index, key)
+            }
+            GeneticCodeError::DuplicateCodon { index } => {
+                write!(f, "the codon at index {} appears more than once", index)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for GeneticCodeError {
+    fn from(error: io::Error) -> Self {
+        GeneticCodeError::Io(error)
+    }
+}
+
+/// How translation handles stop codons.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TranslationMode {
+    /// Translation ends at the first stop codon, which isn't translated.
+    StopAtFirstStop,
+    /// Stop codons are translated to AminoAcid::Stop (printed as *) and translation goes on.
+    ReadThrough,
+    /// The sequence must end with its only stop codon (which isn't translated), or have none at all.
+    FailOnInternalStop,
+}
+
+/// Result of a translation.
+#[derive(Debug, PartialEq)]
+pub struct Translation {
+    pub protein: AminoAcidSequence,
+    /// Trailing nucleobases which don't make a full codon, empty if translation ended at a stop codon.
+    pub partial_codon: Vec<Nucleobase>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TranslationError {
+    /// There is a stop codon before the end of the sequence, `position` is its index in codons.
+    InternalStop { position: usize },
+}
+
+impl error::Error for TranslationError {
+    fn description(&self) -> &str {
+        match self {
+            TranslationError::InternalStop { .. } => "there is a stop codon before the end of the sequence",
+        }
+    }
+}
+
+impl fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TranslationError::InternalStop { position } => {
+                write!(f, "codon {} is a stop codon before the end of the sequence", position)
+            }
+        }
+    }
+}
+
+/// Genetic code, i.e. translation table from codons to amino acids.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneticCode {
+    id: u32,
+    name: String,
+    // amino acid of each codon in the TCAG order, AminoAcid::Stop for stop codons
+    amino_acids: Vec<AminoAcid>,
+    starts: Vec<bool>,
+}
+
+impl GeneticCode {
+    /// Returns the standard genetic code (NCBI table 1).
+    pub fn standard() -> Self {
+        GeneticCode::ncbi(1).expect("The standard table is built in")
+    }
+
+    /// Returns the NCBI translation table with this id, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::genetic_code::{GeneticCode, TranslationMode};
+    /// use rosalind::rna::Sequence;
+    ///
+    /// let mitochondrial = GeneticCode::ncbi(2).unwrap();
+    ///
+    /// let rna = Sequence::try_from("AUGUGAAUA").unwrap();
+    ///
+    /// let translation = mitochondrial.translate(&rna, TranslationMode::StopAtFirstStop).unwrap();
+    ///
+    /// assert_eq!(translation.protein.to_string(), "MWM");
+    /// ```
+    pub fn ncbi(id: u32) -> Option<Self> {
+        NCBI_TABLES
+            .iter()
+            .find(|&&(table_id, ..)| table_id == id)
+            .map(|&(id, name, amino_acids, starts)| {
+                GeneticCode::from_strings(id, name, amino_acids, starts, None).expect("The NCBI tables are valid")
+            })
+    }
+
+    /// Returns all the built-in NCBI translation tables, by increasing id.
+    pub fn ncbi_tables() -> Vec<Self> {
+        NCBI_TABLES
+            .iter()
+            .map(|&(id, name, amino_acids, starts)| {
+                GeneticCode::from_strings(id, name, amino_acids, starts, None).expect("The NCBI tables are valid")
+            })
+            .collect()
+    }
+
+    /// Loads a custom genetic code from a file, see the format at the top of this module.
+    pub fn load(path: &str) -> Result<Self, GeneticCodeError> {
+        let contents = fs::read_to_string(path)?;
+
+        GeneticCode::try_from(contents.as_str())
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the amino acid `codon` codes for, AminoAcid::Stop if it is a stop codon.
+    pub fn amino_acid(&self, codon: Codon) -> AminoAcid {
+        self.amino_acids[codon.index()].clone()
+    }
+
+    pub fn is_stop(&self, codon: Codon) -> bool {
+        self.amino_acids[codon.index()] == AminoAcid::Stop
+    }
+
+    /// Returns whether translation can start at `codon`.
+    pub fn is_start(&self, codon: Codon) -> bool {
+        self.starts[codon.index()]
+    }
+
+    /// Returns the codons coding for `amino_acid` (the stop codons for AminoAcid::Stop), in the TCAG order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosalind::amino_acids::AminoAcid;
+    /// use rosalind::genetic_code::GeneticCode;
+    ///
+    /// let codons = GeneticCode::standard().codons(&AminoAcid::Stop);
+    ///
+    /// assert_eq!(codons.iter().map(|codon| codon.to_string()).collect::<Vec<_>>(), ["UAA", "UAG", "UGA"]);
+    /// ```
+    pub fn codons(&self, amino_acid: &AminoAcid) -> Vec<Codon> {
+        Codon::all().filter(|&codon| self.amino_acids[codon.index()] == *amino_acid).collect()
+    }
+
+    /// Translates `rna` codon by codon, handling the stop codons as told by `mode`.
+    /// The trailing nucleobases which don't make a codon aren't translated but are returned in the translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::genetic_code::{GeneticCode, TranslationMode};
+    /// use rosalind::rna::Sequence;
+    ///
+    /// let rna = Sequence::try_from("AUGUAAUGGCA").unwrap();
+    ///
+    /// let translation = GeneticCode::standard().translate(&rna, TranslationMode::ReadThrough).unwrap();
+    ///
+    /// assert_eq!(translation.protein.to_string(), "M*W");
+    /// assert_eq!(translation.partial_codon.len(), 2);
+    ///
+    /// assert!(GeneticCode::standard().translate(&rna, TranslationMode::FailOnInternalStop).is_err());
+    /// ```
+    pub fn translate(&self, rna: &RnaSequence, mode: TranslationMode) -> Result<Translation, TranslationError> {
+        let mut codons = StrictCodonIterator::new(rna);
+        let mut protein = Vec::new();
+        // position of the stop codon met last, if it is the last codon read so far
+        let mut stop = None;
+
+        for (position, codon) in codons.by_ref().enumerate() {
+            if let Some(position) = stop {
+                return Err(TranslationError::InternalStop { position: position });
+            }
+
+            let amino_acid = self.amino_acid(codon);
+
+            if amino_acid != AminoAcid::Stop {
+                protein.push(amino_acid);
+                continue;
+            }
+
+            match mode {
+                TranslationMode::StopAtFirstStop => {
+                    return Ok(Translation {
+                        protein: AminoAcidSequence(protein),
+                        partial_codon: Vec::new(),
+                    });
+                }
+                TranslationMode::ReadThrough => protein.push(amino_acid),
+                // only an error if another codon follows
+                TranslationMode::FailOnInternalStop => stop = Some(position),
+            }
+        }
+
+        let partial_codon = codons.remainder().to_vec();
+
+        // a final stop followed by a partial codon is internal too
+        match stop {
+            Some(position) if !partial_codon.is_empty() => Err(TranslationError::InternalStop { position: position }),
+            _ => Ok(Translation {
+                protein: AminoAcidSequence(protein),
+                partial_codon: partial_codon,
+            }),
+        }
+    }
+
+    fn from_strings(
+        id: u32,
+        name: &str,
+        amino_acids: &str,
+        starts: &str,
+        bases: Option<[&str; 3]>,
+    ) -> Result<Self, GeneticCodeError> {
+        // codon index of every column of the table
+        let indexes: Vec<usize> = match bases {
+            None => (0..64).collect(),
+            Some(bases) => {
+                let mut columns = Vec::with_capacity(bases.len());
+
+                for (position, &base) in bases.iter().enumerate() {
+                    let key = format!("Base{}", position + 1);
+
+                    columns.push(parse_column(&key, base, |ch| Nucleobase::try_from(ch).ok())?);
+                }
+
+                (0..64)
+                    .map(|column| Codon([columns[0][column], columns[1][column], columns[2][column]]).index())
+                    .collect()
+            }
+        };
+
+        let amino_acids = parse_column("AAs", amino_acids, |ch| AminoAcid::try_from(ch).ok())?;
+
+        let starts = parse_column("Starts", starts, |ch| match ch {
+            'M' => Some(true),
+            // NCBI also marks the stop codons on this line
+            '-' | '*' => Some(false),
+            _ => None,
+        })?;
+
+        let mut table = GeneticCode {
+            id: id,
+            name: name.to_string(),
+            amino_acids: vec![AminoAcid::Stop; 64],
+            starts: vec![false; 64],
+        };
+        let mut seen = [false; 64];
+
+        for (column, &index) in indexes.iter().enumerate() {
+            if seen[index] {
+                return Err(GeneticCodeError::DuplicateCodon { index: column });
+            }
+
+            seen[index] = true;
+            table.amino_acids[index] = amino_acids[column].clone();
+            table.starts[index] = starts[column];
+        }
+
+        Ok(table)
+    }
+}
+
+impl<'a> convert::TryFrom<&'a str> for GeneticCode {
+    type Error = GeneticCodeError;
+
+    /// Parses a genetic code in the format described at the top of this module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::genetic_code::GeneticCode;
+    ///
+    /// let code = GeneticCode::try_from(
+    ///     "Name = Standard\n\
+    ///      AAs = FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    /// ).unwrap();
+    ///
+    /// assert_eq!(code.name(), "Standard");
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut id = 0;
+        let mut name = "Custom";
+        let mut amino_acids = None;
+        let mut starts = None;
+        let mut bases = [None, None, None];
+
+        for (line_index, line) in value.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut key_value = line.splitn(2, '=');
+
+            let (key, value) = match (key_value.next(), key_value.next()) {
+                (Some(key), Some(value)) => (key.trim(), value.trim()),
+                _ => return Err(GeneticCodeError::InvalidLine { line: line_index + 1 }),
+            };
+
+            match key.to_ascii_lowercase().as_str() {
+                "id" => id = value.parse().map_err(|_| GeneticCodeError::InvalidId)?,
+                "name" => name = value,
+                "aas" => amino_acids = Some(value),
+                "starts" => starts = Some(value),
+                "base1" => bases[0] = Some(value),
+                "base2" => bases[1] = Some(value),
+                "base3" => bases[2] = Some(value),
+                // unknown keys are ignored, so that tables can be annotated
+                _ => {}
+            }
+        }
+
+        let amino_acids = amino_acids.ok_or(GeneticCodeError::MissingKey("AAs"))?;
+        let no_start = "-".repeat(64);
+        let starts = starts.unwrap_or(&no_start);
+
+        let bases = match bases {
+            [None, None, None] => None,
+            [Some(base1), Some(base2), Some(base3)] => Some([base1, base2, base3]),
+            [None, ..] => return Err(GeneticCodeError::MissingKey("Base1")),
+            [_, None, _] => return Err(GeneticCodeError::MissingKey("Base2")),
+            [.., None] => return Err(GeneticCodeError::MissingKey("Base3")),
+        };
+
+        GeneticCode::from_strings(id, name, amino_acids, starts, bases)
+    }
+}
+
+// parses one character per codon, DNA thymines being accepted for uracils
+fn parse_column<T, F>(key: &str, value: &str, parse: F) -> Result<Vec<T>, GeneticCodeError>
+where
+    F: Fn(char) -> Option<T>,
+{
+    let length = value.chars().count();
+
+    if length != 64 {
+        return Err(GeneticCodeError::InvalidLength {
+            key: key.to_string(),
+            length: length,
+        });
+    }
+
+    value
+        .chars()
+        .enumerate()
+        .map(|(index, ch)| {
+            let ch = if ch == 'T' && key.starts_with("Base") { 'U' } else { ch };
+
+            parse(ch).ok_or(GeneticCodeError::IllegalChar {
+                key: key.to_string(),
+                ch: ch,
+                index: index,
+            })
+        })
+        .collect()
+}
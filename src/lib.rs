@@ -1,6 +1,21 @@
+extern crate num_bigint;
+extern crate num_traits;
+
 pub mod io;
 pub mod fasta;
 pub mod probabilities;
 pub mod amino_acids;
 pub mod dna;
 pub mod rna;
+pub mod alignment;
+pub mod motif;
+pub mod random;
+pub mod search;
+pub mod suffix;
+pub mod restriction;
+pub mod orf;
+pub mod genetic_code;
+pub mod reverse_translation;
+pub mod codon_usage;
+pub mod codon_optimization;
+pub mod substitution;
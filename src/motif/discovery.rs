@@ -0,0 +1,361 @@
+// discovery of k-mer motifs shared by a set of sequences (e.g. promoters), without knowing them in advance
+//
+// both algorithms start from random k-mers, one in each sequence, and improve them with the profile they form:
+// - randomized motif search replaces all the k-mers at once by the most probable ones according to the profile,
+//   and stops as soon as it doesn't improve the score anymore
+// - Gibbs sampling replaces one k-mer at a time, chosen at random according to the profile of the other k-mers,
+//   which makes it less likely to get stuck on a local optimum
+//
+// both are restarted several times from different random k-mers and the best motifs found are kept;
+// profiles use a pseudocount of 1 (Laplace's rule of succession) so that no k-mer gets a probability of 0
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::Sequence as DnaSequence;
+    use random::SplitMix64;
+    use super::DiscoveryError;
+
+    const PLANTED_MOTIF: &str = "ACGTTGCAAG";
+
+    // random sequences, each containing the planted motif at a random position
+    fn sequences_with_planted_motif() -> Vec<DnaSequence> {
+        let mut rng = SplitMix64::new(1);
+
+        (0..8)
+            .map(|_| {
+                let mut string: String = (0..60).map(|_| "ACGT".as_bytes()[rng.below(4)] as char).collect();
+                let position = rng.below(50);
+                string.replace_range(position..position + PLANTED_MOTIF.len(), PLANTED_MOTIF);
+
+                DnaSequence::try_from(string.as_str()).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn randomized_motif_search_finds_planted_motif() {
+        let sequences = sequences_with_planted_motif();
+
+        let found = super::randomized_motif_search(&sequences, PLANTED_MOTIF.len(), 500, 42).unwrap();
+
+        assert_eq!(found.score, 0);
+        assert_eq!(found.profile.consensus().to_string(), PLANTED_MOTIF);
+
+        for (motif, (position, sequence)) in found.motifs.iter().zip(found.positions.iter().zip(&sequences)) {
+            assert_eq!(motif.to_string(), PLANTED_MOTIF);
+            assert_eq!(&sequence[*position..*position + PLANTED_MOTIF.len()], motif.as_ref());
+        }
+    }
+
+    #[test]
+    fn gibbs_sampler_finds_planted_motif() {
+        let sequences = sequences_with_planted_motif();
+
+        let found = super::gibbs_sampler(&sequences, PLANTED_MOTIF.len(), 200, 20, 42).unwrap();
+
+        assert_eq!(found.score, 0);
+        assert_eq!(found.profile.consensus().to_string(), PLANTED_MOTIF);
+    }
+
+    #[test]
+    fn same_seed_same_motifs() {
+        let sequences = sequences_with_planted_motif();
+
+        let found_1 = super::gibbs_sampler(&sequences, 6, 50, 2, 7).unwrap();
+        let found_2 = super::gibbs_sampler(&sequences, 6, 50, 2, 7).unwrap();
+
+        assert_eq!(found_1, found_2);
+    }
+
+    #[test]
+    fn invalid_motif_length() {
+        let sequences = vec![
+            DnaSequence::try_from("ACGTACGT").unwrap(),
+            DnaSequence::try_from("ACG").unwrap(),
+        ];
+
+        assert_eq!(
+            super::randomized_motif_search(&sequences, 4, 10, 0).unwrap_err(),
+            DiscoveryError::InvalidMotifLength {
+                motif_length: 4,
+                shortest_sequence: 3,
+            }
+        );
+
+        assert_eq!(
+            super::gibbs_sampler(&[], 4, 10, 1, 0).unwrap_err(),
+            DiscoveryError::NoSequences
+        );
+    }
+}
+
+use std::{error, fmt};
+use dna::{Nucleobase, Sequence as DnaSequence};
+use random::SplitMix64;
+use super::Profile;
+
+const PSEUDOCOUNT: f64 = 1.0;
+
+/// Best set of motifs found, one per input sequence.
+#[derive(Debug, PartialEq)]
+pub struct DiscoveredMotifs {
+    /// 0-based position of the motif in each sequence.
+    pub positions: Vec<usize>,
+    pub motifs: Vec<DnaSequence>,
+    /// Profile of the motifs, without pseudocount.
+    pub profile: Profile,
+    /// Number of nucleobases of the motifs which differ from their consensus (the lower, the better).
+    pub score: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DiscoveryError {
+    NoSequences,
+    InvalidMotifLength {
+        motif_length: usize,
+        shortest_sequence: usize,
+    },
+}
+
+impl error::Error for DiscoveryError {
+    fn description(&self) -> &str {
+        match self {
+            DiscoveryError::NoSequences => "cannot search motifs in 0 sequences",
+            DiscoveryError::InvalidMotifLength { .. } => {
+                "the motif length must be > 0 and fit in every sequence"
+            }
+        }
+    }
+}
+
+impl fmt::Display for DiscoveryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiscoveryError::NoSequences => write!(f, "cannot search motifs in 0 sequences"),
+            DiscoveryError::InvalidMotifLength {
+                motif_length,
+                shortest_sequence,
+            } => write!(
+                f,
+                "cannot search motifs of length {} when the shortest sequence has a length of {}",
+                motif_length, shortest_sequence
+            ),
+        }
+    }
+}
+
+/// Runs randomized motif search `runs` times and returns the best motifs of length `k` found.
+/// The same `seed` always gives the same result.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+/// use rosalind::motif::discovery;
+///
+/// let promoters: Vec<Sequence> = [
+///     "CGCCCCTCTCGGGGGTGTTCAGTAAACGGCCA",
+///     "GGGCGAGGTATGTGTAAGTGCCAAGGTGCCAG",
+///     "TAGTACCGAGACCGAAAGAAGTATACAGGCGT",
+///     "TAGATCAAGTTTCAGGTGCACGTCGGTGAACC",
+///     "AATCCACCAGCTCCACGTGCAATGTTGGCCTA",
+/// ].iter().map(|string| Sequence::try_from(*string).unwrap()).collect();
+///
+/// let found = discovery::randomized_motif_search(&promoters, 8, 1000, 2018).unwrap();
+///
+/// for motif in &found.motifs {
+///     println!("{}", motif);
+/// }
+/// ```
+pub fn randomized_motif_search(
+    sequences: &[DnaSequence],
+    k: usize,
+    runs: usize,
+    seed: u64,
+) -> Result<DiscoveredMotifs, DiscoveryError> {
+    check_motif_length(sequences, k)?;
+
+    let mut rng = SplitMix64::new(seed);
+
+    let mut best = random_positions(sequences, k, &mut rng);
+    let mut best_score = score(sequences, &best, k);
+
+    for _ in 0..runs {
+        let mut positions = random_positions(sequences, k, &mut rng);
+        let mut positions_score = score(sequences, &positions, k);
+
+        loop {
+            let profile = profile(sequences, &positions, k, None);
+
+            let next: Vec<usize> = sequences
+                .iter()
+                .map(|sequence| most_probable_position(&profile, sequence.as_ref()))
+                .collect();
+            let next_score = score(sequences, &next, k);
+
+            if next_score < positions_score {
+                positions = next;
+                positions_score = next_score;
+            } else {
+                break;
+            }
+        }
+
+        if positions_score < best_score {
+            best = positions;
+            best_score = positions_score;
+        }
+    }
+
+    Ok(discovered_motifs(sequences, best, k))
+}
+
+/// Runs Gibbs sampling `restarts` times, for `iterations` iterations each,
+/// and returns the best motifs of length `k` found. The same `seed` always gives the same result.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+/// use rosalind::motif::discovery;
+///
+/// let promoters: Vec<Sequence> = [
+///     "CGCCCCTCTCGGGGGTGTTCAGTAAACGGCCA",
+///     "GGGCGAGGTATGTGTAAGTGCCAAGGTGCCAG",
+///     "TAGTACCGAGACCGAAAGAAGTATACAGGCGT",
+///     "TAGATCAAGTTTCAGGTGCACGTCGGTGAACC",
+///     "AATCCACCAGCTCCACGTGCAATGTTGGCCTA",
+/// ].iter().map(|string| Sequence::try_from(*string).unwrap()).collect();
+///
+/// let found = discovery::gibbs_sampler(&promoters, 8, 100, 20, 2018).unwrap();
+///
+/// println!("{} (score {})", found.profile.consensus(), found.score);
+/// ```
+pub fn gibbs_sampler(
+    sequences: &[DnaSequence],
+    k: usize,
+    iterations: usize,
+    restarts: usize,
+    seed: u64,
+) -> Result<DiscoveredMotifs, DiscoveryError> {
+    check_motif_length(sequences, k)?;
+
+    let mut rng = SplitMix64::new(seed);
+
+    let mut best = random_positions(sequences, k, &mut rng);
+    let mut best_score = score(sequences, &best, k);
+
+    for _ in 0..restarts {
+        let mut positions = random_positions(sequences, k, &mut rng);
+
+        for _ in 0..iterations {
+            let excluded = rng.below(sequences.len());
+
+            let profile = profile(sequences, &positions, k, Some(excluded));
+
+            let sequence = sequences[excluded].as_ref();
+            let probabilities: Vec<f64> = (0..sequence.len() - k + 1)
+                .map(|position| profile.probability(&sequence[position..position + k]))
+                .collect();
+
+            // with a pseudocount, every k-mer has a probability > 0 so there is always one to pick
+            positions[excluded] = rng.weighted_index(&probabilities).unwrap_or(0);
+
+            let positions_score = score(sequences, &positions, k);
+
+            if positions_score < best_score {
+                best = positions.clone();
+                best_score = positions_score;
+            }
+        }
+    }
+
+    Ok(discovered_motifs(sequences, best, k))
+}
+
+fn check_motif_length(sequences: &[DnaSequence], k: usize) -> Result<(), DiscoveryError> {
+    let shortest_sequence = match sequences.iter().map(|sequence| sequence.len()).min() {
+        Some(length) => length,
+        None => return Err(DiscoveryError::NoSequences),
+    };
+
+    if k == 0 || k > shortest_sequence {
+        return Err(DiscoveryError::InvalidMotifLength {
+            motif_length: k,
+            shortest_sequence: shortest_sequence,
+        });
+    }
+
+    Ok(())
+}
+
+fn random_positions(sequences: &[DnaSequence], k: usize, rng: &mut SplitMix64) -> Vec<usize> {
+    sequences
+        .iter()
+        .map(|sequence| rng.below(sequence.len() - k + 1))
+        .collect()
+}
+
+fn motifs<'a>(sequences: &'a [DnaSequence], positions: &[usize], k: usize) -> Vec<&'a [Nucleobase]> {
+    sequences
+        .iter()
+        .zip(positions)
+        .map(|(sequence, &position)| &sequence.as_ref()[position..position + k])
+        .collect()
+}
+
+// profile of the motifs with a pseudocount, leaving out the motif of sequence `excluded` if any
+fn profile(sequences: &[DnaSequence], positions: &[usize], k: usize, excluded: Option<usize>) -> Profile {
+    let mut motifs = motifs(sequences, positions, k);
+
+    if let Some(excluded) = excluded {
+        motifs.remove(excluded);
+    }
+
+    // the only sequence was excluded, the profile of no motif is as good as any
+    if motifs.is_empty() {
+        motifs.push(&sequences[0].as_ref()[positions[0]..positions[0] + k]);
+    }
+
+    Profile::from_sequences(&motifs, PSEUDOCOUNT).expect("All the motifs have the same length")
+}
+
+fn score(sequences: &[DnaSequence], positions: &[usize], k: usize) -> u32 {
+    Profile::from_sequences(&motifs(sequences, positions, k), 0.0)
+        .expect("All the motifs have the same length")
+        .score()
+}
+
+// position of the most probable k-mer of the sequence, the first one in case of ties
+fn most_probable_position(profile: &Profile, sequence: &[Nucleobase]) -> usize {
+    let k = profile.len();
+
+    (0..sequence.len() - k + 1)
+        .map(|position| (position, profile.probability(&sequence[position..position + k])))
+        .fold((0, -1.0), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+        .0
+}
+
+fn discovered_motifs(sequences: &[DnaSequence], positions: Vec<usize>, k: usize) -> DiscoveredMotifs {
+    let motifs = motifs(sequences, &positions, k);
+    let profile = Profile::from_sequences(&motifs, 0.0).expect("All the motifs have the same length");
+
+    DiscoveredMotifs {
+        score: profile.score(),
+        motifs: motifs
+            .into_iter()
+            .map(|motif| DnaSequence::new(motif.to_vec()))
+            .collect(),
+        positions: positions,
+        profile: profile,
+    }
+}
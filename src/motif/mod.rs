@@ -0,0 +1,8 @@
+// sequence motifs: position frequency and weight matrices built from aligned DNA sequences,
+// the discovery of motifs shared by unaligned sequences, and PROSITE patterns for protein motifs
+
+pub use self::profile::{Background, Hit, PositionWeightMatrix, Profile, ProfileError};
+
+pub mod discovery;
+pub mod profile;
+pub mod prosite;
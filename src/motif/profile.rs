@@ -0,0 +1,537 @@
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::{Nucleobase::*, Sequence as DnaSequence, Topology};
+    use super::{Background, Profile, ProfileError};
+
+    fn dna_sequences(strings: &[&str]) -> Vec<DnaSequence> {
+        strings
+            .iter()
+            .map(|string| DnaSequence::try_from(*string).unwrap())
+            .collect()
+    }
+
+    fn rosalind_sample_profile() -> Profile {
+        let sequences = dna_sequences(&[
+            "ATCCAGCT", "GGGCAACT", "ATGGATCT", "AAGCAACC", "TTGGAACT", "ATGCCATT", "ATGGCACT",
+        ]);
+
+        Profile::from_sequences(&sequences, 0.0).unwrap()
+    }
+
+    #[test]
+    fn counts() {
+        let profile = rosalind_sample_profile();
+
+        let adenines: Vec<u32> = (0..8).map(|position| profile.count(position, &Adenine)).collect();
+        let cytosines: Vec<u32> = (0..8).map(|position| profile.count(position, &Cytosine)).collect();
+
+        assert_eq!(adenines, vec![5, 1, 0, 0, 5, 5, 0, 0]);
+        assert_eq!(cytosines, vec![0, 0, 1, 4, 2, 0, 6, 1]);
+        assert_eq!(profile.len(), 8);
+        assert_eq!(profile.number_of_sequences(), 7);
+    }
+
+    #[test]
+    fn consensus() {
+        let profile = rosalind_sample_profile();
+
+        assert_eq!(profile.consensus().to_string(), "ATGCAACT");
+    }
+
+    #[test]
+    fn score() {
+        let profile = rosalind_sample_profile();
+
+        assert_eq!(profile.score(), 2 + 2 + 1 + 3 + 2 + 2 + 1 + 1);
+    }
+
+    #[test]
+    fn consensus_ties() {
+        let sequences = dna_sequences(&["ACGT", "TCGA"]);
+        let profile = Profile::from_sequences(&sequences, 0.0).unwrap();
+
+        assert_eq!(profile.consensus_candidates(0), vec![Adenine, Thymine]);
+        assert_eq!(profile.consensus_candidates(1), vec![Cytosine]);
+        assert_eq!(profile.consensus().to_string(), "ACGA");
+
+        let last_candidate = profile.consensus_by(|_, candidates| candidates[candidates.len() - 1].clone());
+        assert_eq!(last_candidate.to_string(), "TCGT");
+    }
+
+    #[test]
+    fn frequencies_with_pseudocounts() {
+        let sequences = dna_sequences(&["AC", "AG"]);
+        let profile = Profile::from_sequences(&sequences, 1.0).unwrap();
+
+        assert_eq!(profile.frequency(0, &Adenine), 3.0 / 6.0);
+        assert_eq!(profile.frequency(0, &Thymine), 1.0 / 6.0);
+        assert_eq!(profile.frequency(1, &Cytosine), 2.0 / 6.0);
+    }
+
+    #[test]
+    fn probability() {
+        let sequences = dna_sequences(&["AC", "AG"]);
+        let profile = Profile::from_sequences(&sequences, 0.0).unwrap();
+
+        assert_eq!(profile.probability(&[Adenine, Cytosine]), 0.5);
+        assert_eq!(profile.probability(&[Adenine, Thymine]), 0.0);
+    }
+
+    #[test]
+    fn from_sequences_errors() {
+        assert_eq!(
+            Profile::from_sequences::<DnaSequence>(&[], 0.0).unwrap_err(),
+            ProfileError::NoSequences
+        );
+
+        assert_eq!(
+            Profile::from_sequences(&dna_sequences(&["ACG", "AC"]), 0.0).unwrap_err(),
+            ProfileError::LengthMismatch {
+                index: 1,
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn log_odds_and_scan() {
+        let motifs = dna_sequences(&["TATAAT", "TATAAT", "TATATT", "TACAAT"]);
+        let profile = Profile::from_sequences(&motifs, 0.5).unwrap();
+        let weights = profile.log_odds(&Background::uniform());
+
+        let genome = DnaSequence::try_from("GCGCTATAATGCGCGCGCGCTACAATCC").unwrap();
+
+        let consensus_score = weights.score(&genome[4..10]);
+        assert_eq!(consensus_score, weights.max_score());
+
+        let hits = weights.scan(&genome, 5.0);
+
+        let positions: Vec<usize> = hits.iter().map(|hit| hit.position).collect();
+        assert_eq!(positions, vec![4, 20]);
+        assert!(hits[0].score > hits[1].score);
+
+        // the second site now spans the origin
+        let plasmid = genome.with_topology(Topology::Circular).rotate(23);
+
+        let positions: Vec<usize> = weights.scan(&plasmid, 5.0).iter().map(|hit| hit.position).collect();
+        assert_eq!(positions, vec![9, 25]);
+    }
+
+    #[test]
+    fn background_from_sequence() {
+        let background = Background::from_sequence(&DnaSequence::try_from("AACG").unwrap(), 1.0);
+
+        assert_eq!(background.frequency(&Adenine), 3.0 / 8.0);
+        assert_eq!(background.frequency(&Thymine), 1.0 / 8.0);
+
+        // T is missing from the background but still scores finitely
+        let profile = Profile::from_sequences(&dna_sequences(&["TT"]), 1.0).unwrap();
+        let weights = profile.log_odds(&background);
+
+        assert!(weights.max_score().is_finite());
+        assert!(weights.scan(&DnaSequence::try_from("TTTT").unwrap(), 100.0).is_empty());
+
+        let empty = DnaSequence::try_from("").unwrap();
+
+        assert_eq!(Background::from_sequence(&empty, 0.0), Background::uniform());
+        assert_eq!(Background::from_sequence(&empty, 1.0), Background::uniform());
+    }
+}
+
+use std::{error, f64, fmt};
+use alignment::{multiple::Alignment, Symbol};
+use dna::{Nucleobase, Nucleobase::*, Sequence as DnaSequence};
+use search::unroll;
+
+// nucleobases in the order of their index (see Symbol for Nucleobase)
+const NUCLEOBASES: [Nucleobase; 4] = [Adenine, Cytosine, Guanine, Thymine];
+
+/// Position frequency matrix: the number of times each nucleobase occurs at each position of a set of aligned sequences.
+///
+/// A pseudocount is added to every count when computing frequencies,
+/// so that a nucleobase which was never seen at a position doesn't get a probability of 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    // counts[position][nucleobase index]
+    counts: Vec<[u32; 4]>,
+    number_of_sequences: u32,
+    pseudocount: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ProfileError {
+    NoSequences,
+    LengthMismatch {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl error::Error for ProfileError {
+    fn description(&self) -> &str {
+        match self {
+            ProfileError::NoSequences => "cannot build a profile from 0 sequences",
+            ProfileError::LengthMismatch { .. } => "all the sequences of a profile must have the same length",
+        }
+    }
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProfileError::NoSequences => write!(f, "cannot build a profile from 0 sequences"),
+            ProfileError::LengthMismatch {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "sequences should have a length of {} but sequence {} has a length of {}",
+                expected, index, actual
+            ),
+        }
+    }
+}
+
+impl Profile {
+    /// Builds the profile of aligned sequences which must all have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::motif::Profile;
+    ///
+    /// let sequences = vec![
+    ///     Sequence::try_from("ATCCAGCT").unwrap(),
+    ///     Sequence::try_from("GGGCAACT").unwrap(),
+    ///     Sequence::try_from("ATGGATCT").unwrap(),
+    /// ];
+    ///
+    /// let profile = Profile::from_sequences(&sequences, 1.0).expect("Sequences of different lengths");
+    ///
+    /// println!("{}", profile.consensus());
+    /// ```
+    pub fn from_sequences<Q: AsRef<[Nucleobase]>>(sequences: &[Q], pseudocount: f64) -> Result<Self, ProfileError> {
+        let length = match sequences.first() {
+            Some(sequence) => sequence.as_ref().len(),
+            None => return Err(ProfileError::NoSequences),
+        };
+
+        let mut counts = vec![[0; 4]; length];
+
+        for (index, sequence) in sequences.iter().enumerate() {
+            let sequence = sequence.as_ref();
+
+            if sequence.len() != length {
+                return Err(ProfileError::LengthMismatch {
+                    index: index,
+                    expected: length,
+                    actual: sequence.len(),
+                });
+            }
+
+            for (position, nucleobase) in sequence.iter().enumerate() {
+                counts[position][nucleobase.index()] += 1;
+            }
+        }
+
+        Ok(Profile {
+            counts: counts,
+            number_of_sequences: sequences.len() as u32,
+            pseudocount: pseudocount,
+        })
+    }
+
+    /// Builds the profile of the sequences of an alignment, gaps are not counted.
+    pub fn from_alignment(alignment: &Alignment<Nucleobase>, pseudocount: f64) -> Self {
+        let mut counts = vec![[0; 4]; alignment.len()];
+
+        for row in alignment.rows() {
+            for (position, nucleobase) in row.iter().enumerate() {
+                if let Some(nucleobase) = nucleobase {
+                    counts[position][nucleobase.index()] += 1;
+                }
+            }
+        }
+
+        Profile {
+            counts: counts,
+            number_of_sequences: alignment.number_of_sequences() as u32,
+            pseudocount: pseudocount,
+        }
+    }
+
+    /// Returns the number of positions of the profile.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    pub fn number_of_sequences(&self) -> u32 {
+        self.number_of_sequences
+    }
+
+    pub fn pseudocount(&self) -> f64 {
+        self.pseudocount
+    }
+
+    /// Returns the number of times `nucleobase` occurs at `position` (without pseudocount).
+    pub fn count(&self, position: usize, nucleobase: &Nucleobase) -> u32 {
+        self.counts[position][nucleobase.index()]
+    }
+
+    /// Returns the frequency of `nucleobase` at `position`, pseudocount included.
+    pub fn frequency(&self, position: usize, nucleobase: &Nucleobase) -> f64 {
+        let counts = &self.counts[position];
+        let total: u32 = counts.iter().sum();
+
+        (counts[nucleobase.index()] as f64 + self.pseudocount) / (total as f64 + 4.0 * self.pseudocount)
+    }
+
+    /// Returns the frequencies of every nucleobase at every position, in the A, C, G, T order.
+    pub fn frequencies(&self) -> Vec<[f64; 4]> {
+        (0..self.len())
+            .map(|position| {
+                let mut frequencies = [0.0; 4];
+
+                for (index, nucleobase) in NUCLEOBASES.iter().enumerate() {
+                    frequencies[index] = self.frequency(position, nucleobase);
+                }
+
+                frequencies
+            })
+            .collect()
+    }
+
+    /// Returns the probability that the profile generates `window`, which must be as long as the profile.
+    pub fn probability(&self, window: &[Nucleobase]) -> f64 {
+        window
+            .iter()
+            .enumerate()
+            .map(|(position, nucleobase)| self.frequency(position, nucleobase))
+            .product()
+    }
+
+    /// Returns the most frequent nucleobases at `position`, several of them if they are tied, in the A, C, G, T order.
+    pub fn consensus_candidates(&self, position: usize) -> Vec<Nucleobase> {
+        let counts = &self.counts[position];
+        let max_count = counts.iter().cloned().max().unwrap_or(0);
+
+        NUCLEOBASES
+            .iter()
+            .zip(counts.iter())
+            .filter(|&(_, &count)| count == max_count)
+            .map(|(nucleobase, _)| nucleobase.clone())
+            .collect()
+    }
+
+    /// Returns the consensus sequence, made of the most frequent nucleobase at each position.
+    /// Ties are broken by taking the first nucleobase in the A, C, G, T order, see `consensus_by` for other strategies.
+    pub fn consensus(&self) -> DnaSequence {
+        self.consensus_by(|_, candidates| candidates[0].clone())
+    }
+
+    /// Returns the consensus sequence, calling `choose(position, candidates)` to pick a nucleobase
+    /// among the tied most frequent ones (`candidates` is never empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::motif::Profile;
+    ///
+    /// let sequences = vec![Sequence::try_from("AC").unwrap(), Sequence::try_from("TC").unwrap()];
+    /// let profile = Profile::from_sequences(&sequences, 0.0).unwrap();
+    ///
+    /// let consensus = profile.consensus_by(|_, candidates| candidates[candidates.len() - 1].clone());
+    ///
+    /// assert_eq!(consensus.to_string(), "TC");
+    /// ```
+    pub fn consensus_by<F>(&self, mut choose: F) -> DnaSequence
+    where
+        F: FnMut(usize, &[Nucleobase]) -> Nucleobase,
+    {
+        DnaSequence::new(
+            (0..self.len())
+                .map(|position| choose(position, &self.consensus_candidates(position)))
+                .collect(),
+        )
+    }
+
+    /// Returns the number of nucleobases which differ from the consensus, summed over all positions
+    /// (the lower, the more conserved the motif).
+    pub fn score(&self) -> u32 {
+        self.counts
+            .iter()
+            .map(|counts| {
+                let total: u32 = counts.iter().sum();
+                total - counts.iter().cloned().max().unwrap_or(0)
+            })
+            .sum()
+    }
+
+    /// Returns the log-odds (in bits) of every nucleobase at every position against `background`.
+    pub fn log_odds(&self, background: &Background) -> PositionWeightMatrix {
+        let weights = self.frequencies()
+            .into_iter()
+            .map(|frequencies| {
+                let mut weights = [0.0; 4];
+
+                for index in 0..4 {
+                    weights[index] = (frequencies[index] / background.frequencies[index]).log2();
+                }
+
+                weights
+            })
+            .collect();
+
+        PositionWeightMatrix { weights: weights }
+    }
+}
+
+/// Frequencies of the nucleobases expected outside of motifs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Background {
+    frequencies: [f64; 4],
+}
+
+impl Background {
+    /// Creates a background from the frequencies of A, C, G and T, in this order.
+    pub fn new(frequencies: [f64; 4]) -> Self {
+        Background {
+            frequencies: frequencies,
+        }
+    }
+
+    /// Every nucleobase has a frequency of 1/4.
+    pub fn uniform() -> Self {
+        Background::new([0.25; 4])
+    }
+
+    /// Uses the composition of `sequence` as background, adding `pseudocount` to the count of each nucleobase
+    /// so that those missing from `sequence` don't get an infinite log-odds score.
+    /// Falls back to the uniform background when there is nothing to count.
+    pub fn from_sequence(sequence: &DnaSequence, pseudocount: f64) -> Self {
+        let count = sequence.count_nucleobases();
+        let total = sequence.len() as f64 + 4.0 * pseudocount;
+
+        if total == 0.0 {
+            return Background::uniform();
+        }
+
+        Background::new([
+            (count.adenines as f64 + pseudocount) / total,
+            (count.cytosines as f64 + pseudocount) / total,
+            (count.guanines as f64 + pseudocount) / total,
+            (count.thymines as f64 + pseudocount) / total,
+        ])
+    }
+
+    pub fn frequency(&self, nucleobase: &Nucleobase) -> f64 {
+        self.frequencies[nucleobase.index()]
+    }
+}
+
+/// Position weight matrix: log-odds score of each nucleobase at each position of a motif.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionWeightMatrix {
+    // weights[position][nucleobase index]
+    weights: Vec<[f64; 4]>,
+}
+
+/// Window of a sequence whose score is above the threshold of a scan.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hit {
+    /// 0-based position of the first nucleobase of the window.
+    pub position: usize,
+    pub score: f64,
+}
+
+impl PositionWeightMatrix {
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    pub fn weight(&self, position: usize, nucleobase: &Nucleobase) -> f64 {
+        self.weights[position][nucleobase.index()]
+    }
+
+    /// Returns the score of `window`, which must be as long as the matrix.
+    pub fn score(&self, window: &[Nucleobase]) -> f64 {
+        window
+            .iter()
+            .zip(&self.weights)
+            .map(|(nucleobase, weights)| weights[nucleobase.index()])
+            .sum()
+    }
+
+    /// Returns the highest score a window can get.
+    pub fn max_score(&self) -> f64 {
+        self.weights
+            .iter()
+            .map(|weights| weights.iter().cloned().fold(f64::NEG_INFINITY, f64::max))
+            .sum()
+    }
+
+    /// Returns the lowest score a window can get.
+    pub fn min_score(&self) -> f64 {
+        self.weights
+            .iter()
+            .map(|weights| weights.iter().cloned().fold(f64::INFINITY, f64::min))
+            .sum()
+    }
+
+    /// Returns every window of `sequence` whose score is >= `threshold`, by increasing position.
+    /// In a circular sequence, the windows spanning the origin are scanned too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::motif::{Background, Profile};
+    ///
+    /// let sites = vec![Sequence::try_from("TATAAT").unwrap(), Sequence::try_from("TATATT").unwrap()];
+    /// let weights = Profile::from_sequences(&sites, 0.5).unwrap().log_odds(&Background::uniform());
+    ///
+    /// let genome = Sequence::try_from("GCGCTATAATGCGCGC").unwrap();
+    ///
+    /// for hit in weights.scan(&genome, 4.0) {
+    ///     println!("{} {}", hit.position, hit.score);
+    /// }
+    /// ```
+    pub fn scan(&self, sequence: &DnaSequence, threshold: f64) -> Vec<Hit> {
+        if self.len() == 0 {
+            return Vec::new();
+        }
+
+        let unrolled = unroll(sequence, self.len());
+        let windows = match unrolled {
+            // a circular sequence has as many windows as nucleobases
+            Some(_) => sequence.len(),
+            None => (sequence.len() + 1).saturating_sub(self.len()),
+        };
+        let sequence = unrolled.as_ref().unwrap_or(sequence).as_ref();
+
+        (0..windows)
+            .map(|position| Hit {
+                position: position,
+                score: self.score(&sequence[position..position + self.len()]),
+            })
+            .filter(|hit| hit.score >= threshold)
+            .collect()
+    }
+}
@@ -0,0 +1,420 @@
+// protein motifs written with the PROSITE pattern syntax, e.g. N-{P}-[ST]-{P} for N-glycosylation:
+// - an amino acid letter, or x for any amino acid
+// - [ST]: any of the amino acids between brackets, {P}: any amino acid but the ones between braces
+// - (3) or (2,4) after an element: the element is repeated 3 times, or between 2 and 4 times
+// - < at the start / > at the end: the motif must be at the N-terminal / C-terminal end of the protein
+// elements may be separated by dashes (as in PROSITE) or not (as in Rosalind), and the final period is optional
+//
+// because of the repetitions, a pattern can match several substrings starting at the same position:
+// all the positions they can end at are followed together, element after element
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::Sequence as AminoAcidSequence;
+    use super::{Match, Pattern, PatternError};
+
+    fn positions(pattern: &str, protein: &str) -> Vec<usize> {
+        let pattern = Pattern::try_from(pattern).unwrap();
+        let protein = AminoAcidSequence::try_from(protein).unwrap();
+
+        pattern.find_all(&protein).iter().map(|found| found.position).collect()
+    }
+
+    #[test]
+    fn n_glycosylation() {
+        let protein = "MKNKFKTQEELVNHLKTVGFVFANSEIYNGLANTWDYGPLGVLLKNNLKNLWWKEFVTKQKDVVGLDSAIILNPLVWKASGHLDNFSDPLIDCKNCKARYRADKLIESFDENIHIAENSSNEEFAKVLNDYEISCPTCKQFNWTEIRHFNLMFKTYQGVIEDAKNVVYLRPETAQGIFVNFKNVQRSMRLHLPFGIAQIGKSFRNEITPGNFIFRTREFEQMEIEFFLKEESAYDIFDKYLNQIENWLVSACGLSLNNLRKHEHPKEELSHYSKKTIDFEYNFLHGFSELYGIAYRTNYDLSVHMNLSKKDLTYFDEQTKEKYVPHVIEPSVGVERLLYAILTEATFIEKLENDDERILMDLKYDLAPYKIAVMPLVNKLKDKAEEIYGKILDLNISATFDNSGSIGKRYRRQDAIGTIYCLTIDFDSLDDQQDPSFTIRERNSMAQKRIKLSELPLYLNQKAHEDFQRQCQKHKEQ";
+
+        assert_eq!(positions("N{P}[ST]{P}", protein), vec![84, 117, 141, 305, 394]);
+        assert_eq!(positions("N-{P}-[ST]-{P}.", protein), vec![84, 117, 141, 305, 394]);
+    }
+
+    #[test]
+    fn match_in_last_window() {
+        assert_eq!(positions("N{P}[ST]{P}", "AANGSA"), vec![2]);
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        assert_eq!(positions("NxN", "NANANAN"), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn stops_never_match() {
+        assert_eq!(positions("NxN", "NAN*N"), vec![0]);
+        assert!(Pattern::try_from("N-*").is_err());
+    }
+
+    #[test]
+    fn repetitions() {
+        let pattern = Pattern::try_from("C-x(2,4)-C").unwrap();
+        let protein = AminoAcidSequence::try_from("CAACAAAACAAAAAC").unwrap();
+
+        // the longest match is reported for each position
+        assert_eq!(
+            pattern.find_all(&protein),
+            vec![
+                Match {
+                    position: 0,
+                    length: 4,
+                },
+                Match {
+                    position: 3,
+                    length: 6,
+                },
+            ]
+        );
+
+        assert_eq!(positions("A(3)", "AAAAA"), vec![0, 1, 2]);
+        assert_eq!(positions("G-x(0,2)-G", "GGAG"), vec![0, 1]);
+
+        // as many as the protein holds
+        assert_eq!(positions("G-x(0,18446744073709551615)", "GAAG"), vec![0, 3]);
+    }
+
+    #[test]
+    fn no_empty_matches() {
+        // only the positions of non-empty matches, none at the end of the protein
+        assert_eq!(positions("A(0,1)-C(0,1)", "GAGCG"), vec![1, 3]);
+        assert_eq!(positions("A(0,1)>", "GA"), vec![1]);
+        assert_eq!(positions("A(0,1)>", "AG"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn anchors() {
+        assert_eq!(positions("<M-x-K", "MAKMAK"), vec![0]);
+        assert_eq!(positions("M-x-K>", "MAKMAK"), vec![3]);
+        assert_eq!(positions("<M-x-K>", "MAKMAK"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        assert_eq!(
+            Pattern::try_from("N{P").unwrap_err(),
+            PatternError::UnexpectedEnd
+        );
+        assert_eq!(
+            Pattern::try_from("N-B").unwrap_err(),
+            PatternError::UnexpectedChar { ch: 'B', index: 2 }
+        );
+        assert_eq!(
+            Pattern::try_from("(2)-N").unwrap_err(),
+            PatternError::UnexpectedChar { ch: '(', index: 0 }
+        );
+        assert_eq!(
+            Pattern::try_from("N-x(4,2)").unwrap_err(),
+            PatternError::InvalidRepetition { index: 3 }
+        );
+        assert_eq!(
+            Pattern::try_from("N>-x").unwrap_err(),
+            PatternError::UnexpectedChar { ch: '>', index: 1 }
+        );
+        assert_eq!(Pattern::try_from("<").unwrap_err(), PatternError::Empty);
+    }
+}
+
+use std::{cmp, convert, error, fmt, mem};
+use std::convert::TryFrom;
+use alignment::Symbol;
+use amino_acids::{AminoAcid, Sequence as AminoAcidSequence};
+
+const ALPHABET_SIZE: usize = 20;
+
+// index of the stops in the proteins to search (see Symbol for AminoAcid), which no element accepts
+const STOP: usize = ALPHABET_SIZE;
+
+#[derive(Debug, PartialEq)]
+pub enum PatternError {
+    Empty,
+    UnexpectedEnd,
+    UnexpectedChar { ch: char, index: usize },
+    InvalidRepetition { index: usize },
+}
+
+impl error::Error for PatternError {
+    fn description(&self) -> &str {
+        match self {
+            PatternError::Empty => "the pattern has no element",
+            PatternError::UnexpectedEnd => "the pattern ends in the middle of an element",
+            PatternError::UnexpectedChar { .. } => "unexpected character in the pattern",
+            PatternError::InvalidRepetition { .. } => "invalid repetition in the pattern",
+        }
+    }
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatternError::Empty => write!(f, "the pattern has no element"),
+            PatternError::UnexpectedEnd => write!(f, "the pattern ends in the middle of an element"),
+            PatternError::UnexpectedChar { ch, index } => {
+                write!(f, "unexpected character {} at index {} of the pattern", ch, index)
+            }
+            PatternError::InvalidRepetition { index } => write!(
+                f,
+                "invalid repetition at index {} of the pattern, expected (n) or (n,m) with n <= m",
+                index
+            ),
+        }
+    }
+}
+
+// one position of the pattern, repeated between min and max times
+#[derive(Debug, PartialEq)]
+struct Element {
+    // whether each amino acid (by index) is accepted
+    accepted: [bool; ALPHABET_SIZE],
+    min: usize,
+    max: usize,
+}
+
+/// Substring of a protein matching a pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// 0-based position of the first amino acid of the match.
+    pub position: usize,
+    pub length: usize,
+}
+
+/// A PROSITE pattern.
+#[derive(Debug, PartialEq)]
+pub struct Pattern {
+    elements: Vec<Element>,
+    n_terminal: bool,
+    c_terminal: bool,
+}
+
+impl Pattern {
+    /// Returns all the matches of the pattern in `protein`, overlapping ones included, sorted by position.
+    /// When several substrings starting at the same position match, the longest one is returned,
+    /// and empty matches (of patterns whose elements are all optional) are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::amino_acids::Sequence;
+    /// use rosalind::motif::prosite::Pattern;
+    ///
+    /// let n_glycosylation = Pattern::try_from("N-{P}-[ST]-{P}").unwrap();
+    ///
+    /// let protein = Sequence::try_from("MKNKFKTQEELVNHLKTVGFVFANSEIYNGLANTWDYGP").unwrap();
+    ///
+    /// for found in n_glycosylation.find_all(&protein) {
+    ///     println!("{}", found.position);
+    /// }
+    /// ```
+    pub fn find_all(&self, protein: &AminoAcidSequence) -> Vec<Match> {
+        let indexes: Vec<usize> = protein
+            .as_ref()
+            .iter()
+            .map(|amino_acid| amino_acid.index())
+            .collect();
+
+        // no match is longer than all the elements repeated as much as possible
+        let span = self
+            .elements
+            .iter()
+            .fold(0usize, |span, element| span.saturating_add(element.max));
+
+        let starts = if self.n_terminal {
+            0..cmp::min(1, indexes.len())
+        } else {
+            0..indexes.len()
+        };
+
+        // reused from one start to the next
+        let mut ends = Vec::new();
+        let mut next_ends = Vec::new();
+
+        starts
+            .filter_map(|start| {
+                let window = &indexes[start..cmp::min(start.saturating_add(span), indexes.len())];
+                let at_c_terminal = start + window.len() == indexes.len();
+
+                self.longest_match(window, at_c_terminal, &mut ends, &mut next_ends)
+                    .map(|length| Match {
+                        position: start,
+                        length: length,
+                    })
+            })
+            .collect()
+    }
+
+    // length of the longest non-empty match at the start of window, if any
+    fn longest_match(
+        &self,
+        window: &[usize],
+        at_c_terminal: bool,
+        ends: &mut Vec<bool>,
+        next_ends: &mut Vec<bool>,
+    ) -> Option<usize> {
+        if self.c_terminal && !at_c_terminal {
+            return None;
+        }
+
+        // ends[p] is true if the elements so far can match window[..p]
+        ends.clear();
+        ends.resize(window.len() + 1, false);
+        ends[0] = true;
+
+        for element in &self.elements {
+            next_ends.clear();
+            next_ends.resize(window.len() + 1, false);
+
+            for from in 0..window.len() + 1 {
+                if !ends[from] {
+                    continue;
+                }
+
+                for to in from..cmp::min(from.saturating_add(element.max), window.len()) + 1 {
+                    // every amino acid between from and to must be accepted
+                    // stops aren't amino acids, even x doesn't accept them
+                    if to > from && (window[to - 1] == STOP || !element.accepted[window[to - 1]]) {
+                        break;
+                    }
+
+                    if to - from >= element.min {
+                        next_ends[to] = true;
+                    }
+                }
+            }
+
+            mem::swap(ends, next_ends);
+        }
+
+        let end = if self.c_terminal {
+            Some(window.len()).filter(|&end| ends[end])
+        } else {
+            ends.iter().rposition(|&end| end)
+        };
+
+        end.filter(|&end| end > 0)
+    }
+}
+
+impl<'a> convert::TryFrom<&'a str> for Pattern {
+    type Error = PatternError;
+
+    /// Parses a PROSITE pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::motif::prosite::Pattern;
+    ///
+    /// let zinc_finger = Pattern::try_from("C-x(2,4)-C-x(3)-[LIVMFYWC]-x(8)-H-x(3,5)-H.").unwrap();
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars: Vec<char> = value.trim().trim_end_matches('.').chars().collect();
+
+        let mut elements: Vec<Element> = Vec::new();
+        let mut n_terminal = false;
+        let mut c_terminal = false;
+        // whether the last element already has a repetition
+        let mut repeated = true;
+
+        let mut index = 0;
+
+        while index < chars.len() {
+            let ch = chars[index];
+
+            // nothing can follow the C-terminal anchor
+            if c_terminal {
+                return Err(PatternError::UnexpectedChar {
+                    ch: chars[index - 1],
+                    index: index - 1,
+                });
+            }
+
+            match ch {
+                '<' if index == 0 => n_terminal = true,
+                '>' if !elements.is_empty() => c_terminal = true,
+                '-' if !elements.is_empty() => {}
+                'x' | 'X' => {
+                    elements.push(Element::repeated_once([true; ALPHABET_SIZE]));
+                    repeated = false;
+                }
+                '[' | '{' => {
+                    let closing = if ch == '[' { ']' } else { '}' };
+
+                    let length = match chars[index + 1..].iter().position(|&other| other == closing) {
+                        Some(length) => length,
+                        None => return Err(PatternError::UnexpectedEnd),
+                    };
+
+                    let mut accepted = [ch == '{'; ALPHABET_SIZE];
+
+                    for (offset, &letter) in chars[index + 1..index + 1 + length].iter().enumerate() {
+                        accepted[amino_acid_index(letter, index + 1 + offset)?] = ch == '[';
+                    }
+
+                    elements.push(Element::repeated_once(accepted));
+                    repeated = false;
+                    index += length + 1;
+                }
+                '(' if !repeated => {
+                    let length = match chars[index + 1..].iter().position(|&other| other == ')') {
+                        Some(length) => length,
+                        None => return Err(PatternError::UnexpectedEnd),
+                    };
+
+                    let repetition: String = chars[index + 1..index + 1 + length].iter().collect();
+                    let bounds: Vec<Option<usize>> = repetition
+                        .split(',')
+                        .map(|bound| bound.trim().parse().ok())
+                        .collect();
+
+                    let (min, max) = match bounds[..] {
+                        [Some(count)] => (count, count),
+                        [Some(min), Some(max)] if min <= max => (min, max),
+                        _ => return Err(PatternError::InvalidRepetition { index: index }),
+                    };
+
+                    let last = elements.len() - 1;
+                    elements[last].min = min;
+                    elements[last].max = max;
+                    repeated = true;
+                    index += length + 1;
+                }
+                _ => {
+                    let mut accepted = [false; ALPHABET_SIZE];
+                    accepted[amino_acid_index(ch, index)?] = true;
+
+                    elements.push(Element::repeated_once(accepted));
+                    repeated = false;
+                }
+            }
+
+            index += 1;
+        }
+
+        if elements.is_empty() {
+            return Err(PatternError::Empty);
+        }
+
+        Ok(Pattern {
+            elements: elements,
+            n_terminal: n_terminal,
+            c_terminal: c_terminal,
+        })
+    }
+}
+
+impl Element {
+    fn repeated_once(accepted: [bool; ALPHABET_SIZE]) -> Self {
+        Element {
+            accepted: accepted,
+            min: 1,
+            max: 1,
+        }
+    }
+}
+
+fn amino_acid_index(ch: char, index: usize) -> Result<usize, PatternError> {
+    match AminoAcid::try_from(ch) {
+        Ok(AminoAcid::Stop) | Err(_) => Err(PatternError::UnexpectedChar { ch: ch, index: index }),
+        Ok(amino_acid) => Ok(amino_acid.index()),
+    }
+}
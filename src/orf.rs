@@ -0,0 +1,373 @@
+// open reading frames: stretches of DNA going from a start codon to the next stop codon in the same frame,
+// on either strand, which could be translated into a protein
+//
+// every start codon gives an ORF, so the ORFs starting downstream of another one in the same frame
+// are nested in it: they end at the same stop codon and their protein is a suffix of its protein
+//
+// ORFs which don't reach a stop codon before the end of a linear sequence are left out,
+// in a circular one they may go on across the origin
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::Sequence as AminoAcidSequence;
+    use dna::{Sequence as DnaSequence, Topology};
+    use genetic_code::GeneticCode;
+    use search::Strand;
+    use super::{Orf, OrfFinder};
+
+    fn dna(string: &str) -> DnaSequence {
+        DnaSequence::try_from(string).unwrap()
+    }
+
+    fn proteins(orfs: &[Orf]) -> Vec<String> {
+        let mut proteins: Vec<String> = orfs.iter().map(|orf| orf.protein.to_string()).collect();
+
+        proteins.sort();
+        proteins.dedup();
+
+        proteins
+    }
+
+    #[test]
+    fn find_all() {
+        let sequence = dna(
+            "AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG",
+        );
+
+        let orfs = OrfFinder::new().find_all(&sequence);
+
+        assert_eq!(
+            proteins(&orfs),
+            vec!["M", "MGMTPRLGLESLLE", "MLLGSFRLIPKETLIQVAGSSPCNLS", "MTPRLGLESLLE"]
+        );
+
+        let nested = orfs.iter().find(|orf| orf.protein.to_string() == "MTPRLGLESLLE").unwrap();
+
+        assert_eq!(
+            *nested,
+            Orf {
+                start: 30,
+                end: 69,
+                frame: 0,
+                strand: Strand::Forward,
+                nested: true,
+                nucleotides: dna("ATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAA"),
+                protein: AminoAcidSequence::try_from("MTPRLGLESLLE").unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn find_all_on_reverse_strand() {
+        // the reverse complement is AAATGCCCTAAG
+        let orfs = OrfFinder::new().find_all(&dna("CTTAGGGCATTT"));
+
+        assert_eq!(orfs.len(), 1);
+        assert_eq!((orfs[0].start, orfs[0].end), (1, 10));
+        assert_eq!(orfs[0].strand, Strand::Reverse);
+        assert_eq!(orfs[0].frame, 2);
+        assert_eq!(orfs[0].nucleotides, dna("ATGCCCTAA"));
+        assert!(!orfs[0].nested);
+    }
+
+    #[test]
+    fn min_length_and_alternative_starts() {
+        let sequence = dna("GTGAAACCCATGTAA");
+
+        assert_eq!(proteins(&OrfFinder::new().find_all(&sequence)), vec!["M"]);
+        assert!(OrfFinder::new().min_length(2).find_all(&sequence).is_empty());
+
+        // the initiator tRNA always brings a methionine, whatever the start codon
+        let orfs = OrfFinder::new().alternative_starts(true).min_length(2).find_all(&sequence);
+
+        assert_eq!(proteins(&orfs), vec!["MKPM"]);
+    }
+
+    #[test]
+    fn genetic_code() {
+        // UGA is a stop codon in the standard code, and tryptophan in the mitochondrial ones
+        let sequence = dna("ATGTGAAAATAA");
+
+        assert_eq!(proteins(&OrfFinder::new().find_all(&sequence)), vec!["M"]);
+
+        let mitochondrial = GeneticCode::ncbi(2).unwrap();
+
+        assert_eq!(
+            proteins(&OrfFinder::new().genetic_code(mitochondrial).find_all(&sequence)),
+            vec!["MWK"]
+        );
+    }
+
+    #[test]
+    fn find_all_in_circular_sequence() {
+        let sequence = dna("GCCTTAACCAT");
+
+        assert!(OrfFinder::new().find_all(&sequence).is_empty());
+
+        let orfs = OrfFinder::new().find_all(&sequence.with_topology(Topology::Circular));
+
+        let spanning = orfs.iter().find(|orf| orf.strand == Strand::Forward).unwrap();
+
+        assert_eq!((spanning.start, spanning.end), (9, 18));
+        assert_eq!(spanning.nucleotides, dna("ATGCCTTAA"));
+        assert_eq!(spanning.protein.to_string(), "MP");
+    }
+
+    #[test]
+    fn gff_and_bed() {
+        let orfs = OrfFinder::new().find_all(&dna("CCATGAAATAGCTTAGGGCATTT"));
+
+        assert_eq!(
+            super::to_gff("chr1", &orfs),
+            "##gff-version 3\n\
+             chr1\trosalind\tORF\t3\t11\t.\t+\t.\tID=orf1;frame=2;nested=false\n\
+             chr1\trosalind\tORF\t13\t21\t.\t-\t.\tID=orf2;frame=2;nested=false\n"
+        );
+
+        assert_eq!(
+            super::to_bed("chr1", &orfs),
+            "chr1\t2\t11\torf1\t0\t+\n\
+             chr1\t12\t21\torf2\t0\t-\n"
+        );
+    }
+}
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use amino_acids::{AminoAcid, Sequence as AminoAcidSequence};
+use dna::{Nucleobase, Nucleobase::*, Sequence as DnaSequence};
+use genetic_code::{GeneticCode, TranslationMode};
+use rna::{Codon, Nucleobase as RnaNucleobase, Sequence as RnaSequence};
+use search::Strand;
+
+/// Open reading frame.
+#[derive(Debug, PartialEq)]
+pub struct Orf {
+    /// 0-based position of the leftmost nucleobase of the ORF, on the forward strand.
+    pub start: usize,
+    /// Position right after the rightmost nucleobase of the ORF, on the forward strand.
+    /// It is beyond the length of a circular sequence when the ORF spans the origin.
+    pub end: usize,
+    /// Position of the start codon on its strand, modulo 3.
+    pub frame: usize,
+    pub strand: Strand,
+    /// Whether there is a start codon upstream in the same frame, with no stop codon in between.
+    pub nested: bool,
+    /// Nucleobases of the ORF on its strand, from the start codon to the stop codon included.
+    pub nucleotides: DnaSequence,
+    /// Translation of the ORF, without the stop codon.
+    pub protein: AminoAcidSequence,
+}
+
+/// Searches ORFs, by default from ATG start codons, with the standard genetic code and without any minimum length.
+pub struct OrfFinder {
+    min_length: usize,
+    alternative_starts: bool,
+    genetic_code: GeneticCode,
+}
+
+impl Default for OrfFinder {
+    fn default() -> Self {
+        OrfFinder::new()
+    }
+}
+
+impl OrfFinder {
+    pub fn new() -> Self {
+        OrfFinder {
+            min_length: 0,
+            alternative_starts: false,
+            genetic_code: GeneticCode::standard(),
+        }
+    }
+
+    /// Uses `genetic_code` to tell the stop codons and translate the ORFs.
+    pub fn genetic_code(self, genetic_code: GeneticCode) -> Self {
+        OrfFinder {
+            genetic_code: genetic_code,
+            ..self
+        }
+    }
+
+    /// Only keeps the ORFs whose protein is at least `min_length` amino acids long.
+    pub fn min_length(self, min_length: usize) -> Self {
+        OrfFinder {
+            min_length: min_length,
+            ..self
+        }
+    }
+
+    /// Also starts ORFs at the GTG and TTG codons, which some bacteria use as start codons.
+    /// They are translated to methionine like ATG.
+    pub fn alternative_starts(self, alternative_starts: bool) -> Self {
+        OrfFinder {
+            alternative_starts: alternative_starts,
+            ..self
+        }
+    }
+
+    /// Returns the ORFs of both strands of `sequence`, sorted by start, strand and end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::orf::OrfFinder;
+    ///
+    /// let sequence = Sequence::try_from("CCATGAAATAGCTTAGGGCATTT").unwrap();
+    ///
+    /// for orf in OrfFinder::new().min_length(2).find_all(&sequence) {
+    ///     println!("{}..{} {:?} {}", orf.start, orf.end, orf.strand, orf.protein);
+    /// }
+    /// ```
+    pub fn find_all(&self, sequence: &DnaSequence) -> Vec<Orf> {
+        let length = sequence.len();
+
+        // a circular sequence is read 4 times in a row: every start codon is in the first lap
+        // and the 3 frames have all been gone through after the 3 others, so there is no stop codon left to find
+        let laps = if sequence.is_circular() { 4 } else { 1 };
+
+        let reverse_complement = sequence.reverse_complement();
+
+        // ORFs by strand and position of their start codon on their strand, with their length and whether they are nested
+        // the same ORF is found from several laps of a circular sequence, but it may only look nested from the later ones
+        let mut found: BTreeMap<(Strand, usize), (usize, bool)> = BTreeMap::new();
+
+        for &(strand, strand_sequence) in &[(Strand::Forward, sequence), (Strand::Reverse, &reverse_complement)] {
+            let unrolled = strand_sequence.subsequence(0, laps * length);
+            let nucleobases = unrolled.as_ref();
+
+            for frame in 0..3 {
+                let mut starts = Vec::new();
+                let mut position = frame;
+
+                while position + 3 <= nucleobases.len() {
+                    let codon = &nucleobases[position..position + 3];
+
+                    if self.is_stop_codon(codon) {
+                        for (index, &start) in starts.iter().enumerate() {
+                            let entry = found
+                                .entry((strand, start % length))
+                                .or_insert((position + 3 - start, false));
+
+                            entry.1 |= index > 0;
+                        }
+
+                        starts.clear();
+                    } else if self.is_start_codon(codon) {
+                        starts.push(position);
+                    }
+
+                    position += 3;
+                }
+            }
+        }
+
+        let mut orfs: Vec<Orf> = found
+            .into_iter()
+            .filter(|&(_, (orf_length, _))| orf_length / 3 - 1 >= self.min_length)
+            .map(|((strand, position), (orf_length, nested))| {
+                let (start, nucleotides) = match strand {
+                    Strand::Forward => (position, sequence.subsequence(position, orf_length)),
+                    Strand::Reverse => (
+                        (length as i64 - (position + orf_length) as i64).rem_euclid(length as i64) as usize,
+                        reverse_complement.subsequence(position, orf_length),
+                    ),
+                };
+
+                Orf {
+                    start: start,
+                    end: start + orf_length,
+                    frame: position % 3,
+                    strand: strand,
+                    nested: nested,
+                    protein: self.translate(&nucleotides),
+                    nucleotides: nucleotides,
+                }
+            })
+            .collect();
+
+        orfs.sort_by_key(|orf| (orf.start, orf.strand, orf.end));
+
+        orfs
+    }
+
+    fn is_start_codon(&self, codon: &[Nucleobase]) -> bool {
+        match codon {
+            [Adenine, Thymine, Guanine] => true,
+            [Guanine, Thymine, Guanine] | [Thymine, Thymine, Guanine] => self.alternative_starts,
+            _ => false,
+        }
+    }
+
+    fn is_stop_codon(&self, codon: &[Nucleobase]) -> bool {
+        let rna = |nucleobase: &Nucleobase| RnaNucleobase::from(nucleobase.clone());
+
+        self.genetic_code.is_stop(Codon([rna(&codon[0]), rna(&codon[1]), rna(&codon[2])]))
+    }
+
+    // the stop codon isn't translated, and the start codon is always translated to methionine
+    fn translate(&self, nucleotides: &DnaSequence) -> AminoAcidSequence {
+        let coding = nucleotides.subsequence(0, nucleotides.len() - 3);
+
+        let mut protein = self
+            .genetic_code
+            .translate(&RnaSequence::from(&coding), TranslationMode::ReadThrough)
+            .expect("Reading through stop codons can't fail")
+            .protein;
+        protein.0[0] = AminoAcid::Methionine;
+
+        protein
+    }
+}
+
+/// Returns `orfs` in the GFF3 format, as features of the sequence `sequence_id`.
+pub fn to_gff(sequence_id: &str, orfs: &[Orf]) -> String {
+    let mut gff = String::from("##gff-version 3\n");
+
+    for (index, orf) in orfs.iter().enumerate() {
+        // GFF positions are 1-based and inclusive
+        writeln!(
+            gff,
+            "{}\trosalind\tORF\t{}\t{}\t.\t{}\t.\tID=orf{};frame={};nested={}",
+            sequence_id,
+            orf.start + 1,
+            orf.end,
+            strand_symbol(orf.strand),
+            index + 1,
+            orf.frame,
+            orf.nested
+        ).expect("Writing to a String can't fail");
+    }
+
+    gff
+}
+
+/// Returns `orfs` in the BED6 format, on the sequence (chromosome) `sequence_id`.
+pub fn to_bed(sequence_id: &str, orfs: &[Orf]) -> String {
+    let mut bed = String::new();
+
+    for (index, orf) in orfs.iter().enumerate() {
+        // BED positions are 0-based and half-open, like ours
+        writeln!(
+            bed,
+            "{}\t{}\t{}\torf{}\t0\t{}",
+            sequence_id,
+            orf.start,
+            orf.end,
+            index + 1,
+            strand_symbol(orf.strand)
+        ).expect("Writing to a String can't fail");
+    }
+
+    bed
+}
+
+fn strand_symbol(strand: Strand) -> char {
+    match strand {
+        Strand::Forward => '+',
+        Strand::Reverse => '-',
+    }
+}
@@ -0,0 +1,116 @@
+// small seeded pseudo-random number generator, for the randomized algorithms of the crate
+//
+// we implement it ourselves instead of relying on an external crate so that a given seed
+// always produces the same results, whatever the version of the dependencies
+
+#[cfg(test)]
+mod tests {
+    use super::SplitMix64;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut rng_1 = SplitMix64::new(42);
+        let mut rng_2 = SplitMix64::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+        }
+    }
+
+    #[test]
+    fn reference_values() {
+        // first values of the reference implementation of SplitMix64 with seed 0
+        let mut rng = SplitMix64::new(0);
+
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+    }
+
+    #[test]
+    fn below_stays_below_bound() {
+        let mut rng = SplitMix64::new(7);
+
+        for bound in 1..50 {
+            assert!(rng.below(bound) < bound);
+        }
+    }
+
+    #[test]
+    fn weighted_index_never_picks_zero_weights() {
+        let mut rng = SplitMix64::new(3);
+
+        for _ in 0..1000 {
+            let index = rng.weighted_index(&[0.0, 1.0, 0.0, 2.0]).unwrap();
+            assert!(index == 1 || index == 3);
+        }
+
+        assert_eq!(rng.weighted_index(&[0.0, 0.0]), None);
+    }
+}
+
+/// SplitMix64 generator (Steele, Lea & Flood, 2014): fast, tiny state, and good enough for simulations.
+/// Not suitable for cryptography.
+///
+/// # Examples
+///
+/// ```
+/// let mut rng = rosalind::random::SplitMix64::new(2018);
+///
+/// let dice = 1 + rng.below(6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        // the 53 high bits fill the mantissa of a f64
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a number in [0, `bound`), `bound` must be > 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Cannot pick a number below 0");
+
+        // multiplying instead of taking the modulo keeps the bias negligible
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Returns an index of `weights` with a probability proportional to its weight,
+    /// or None if all the weights are 0 (weights must not be negative).
+    pub fn weighted_index(&mut self, weights: &[f64]) -> Option<usize> {
+        let total: f64 = weights.iter().sum();
+
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target = self.next_f64() * total;
+
+        for (index, &weight) in weights.iter().enumerate() {
+            if target < weight {
+                return Some(index);
+            }
+
+            target -= weight;
+        }
+
+        // rounding errors may leave us past the end, in which case the last non-zero weight wins
+        weights.iter().rposition(|&weight| weight > 0.0)
+    }
+}
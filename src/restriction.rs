@@ -0,0 +1,701 @@
+// restriction enzymes: where they recognize and cut DNA, and the fragments an in-silico digestion gives
+//
+// recognition sites are written with IUPAC codes (e.g. N for any nucleobase) and the cut positions
+// follow REBASE: both are counted from the start of the site on the top strand, the first one on the top strand
+// and the second one on the bottom strand, e.g. EcoRI cuts G^AATTC at 1 and GAATT^C at 5 (noted 1/5)
+//
+// when the two strands are cut at different positions, the fragments have single-stranded sticky ends:
+// a 5' overhang (e.g. AATT for EcoRI) when the top strand is cut first, a 3' overhang otherwise
+//
+// sites which aren't their own reverse complement are also searched on the bottom strand,
+// with the cut positions mirrored
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::{Sequence as DnaSequence, Topology};
+    use search::Strand;
+    use super::{End, Enzyme, Fragment, RestrictionError, Site};
+
+    fn dna(string: &str) -> DnaSequence {
+        DnaSequence::try_from(string).unwrap()
+    }
+
+    fn lengths(fragments: &[Fragment]) -> Vec<usize> {
+        fragments.iter().map(|fragment| fragment.len()).collect()
+    }
+
+    #[test]
+    fn catalogue() {
+        let catalogue = Enzyme::catalogue();
+
+        assert!(catalogue.len() > 20);
+
+        let eco_ri = Enzyme::by_name("EcoRI").unwrap();
+
+        assert_eq!(eco_ri.site(), "GAATTC");
+        assert!(eco_ri.is_palindromic());
+        assert!(!Enzyme::by_name("BsaI").unwrap().is_palindromic());
+        assert!(Enzyme::by_name("ecori").is_some());
+        assert!(Enzyme::by_name("NoSuchEnzyme").is_none());
+    }
+
+    #[test]
+    fn invalid_site() {
+        assert_eq!(
+            Enzyme::new("Custom", "GAZTC", 1, 4).unwrap_err(),
+            RestrictionError::IllegalChar { ch: 'Z', index: 2 }
+        );
+        assert_eq!(Enzyme::new("Custom", "", 0, 0).unwrap_err(), RestrictionError::EmptySite);
+    }
+
+    #[test]
+    fn find_sites() {
+        let hinf_i = Enzyme::by_name("HinfI").unwrap();
+
+        // GANTC
+        assert_eq!(
+            hinf_i.find_sites(&dna("AGAATCCGACTCA")),
+            vec![
+                Site {
+                    position: 1,
+                    strand: Strand::Forward,
+                    cut: 2,
+                    complement_cut: 5,
+                },
+                Site {
+                    position: 7,
+                    strand: Strand::Forward,
+                    cut: 8,
+                    complement_cut: 11,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn find_sites_on_reverse_strand() {
+        let bsa_i = Enzyme::by_name("BsaI").unwrap();
+
+        // GAGACC is the reverse complement of GGTCTC, which BsaI cuts 1 and 5 nucleobases after,
+        // so leftwards here and the top strand is cut at 11 on the bottom strand
+        let sequence = dna("AAAAAAAGAGACCAAAA");
+
+        assert_eq!(
+            bsa_i.find_sites(&sequence),
+            vec![Site {
+                position: 7,
+                strand: Strand::Reverse,
+                cut: 2,
+                complement_cut: 6,
+            }]
+        );
+    }
+
+    #[test]
+    fn digest_linear() {
+        let eco_ri = Enzyme::by_name("EcoRI").unwrap();
+
+        let fragments = super::digest(&dna("AAGAATTCTTTTGAATTCAA"), &[&eco_ri]);
+
+        assert_eq!(
+            fragments,
+            vec![
+                Fragment {
+                    start: 0,
+                    sequence: dna("AAG"),
+                    left_end: End::Uncut,
+                    right_end: End::FivePrimeOverhang(dna("AATT")),
+                },
+                Fragment {
+                    start: 3,
+                    sequence: dna("AATTCTTTTG"),
+                    left_end: End::FivePrimeOverhang(dna("AATT")),
+                    right_end: End::FivePrimeOverhang(dna("AATT")),
+                },
+                Fragment {
+                    start: 13,
+                    sequence: dna("AATTCAA"),
+                    left_end: End::FivePrimeOverhang(dna("AATT")),
+                    right_end: End::Uncut,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn digest_on_reverse_strand() {
+        let bsa_i = Enzyme::by_name("BsaI").unwrap();
+
+        // GAGACC at 10: the top strand is cut at 5 and the bottom strand at 9, leaving a 5' overhang
+        let sequence = dna("AAAAAACAGTGAGACCAAAA");
+
+        assert_eq!(
+            bsa_i.find_sites(&sequence),
+            vec![Site {
+                position: 10,
+                strand: Strand::Reverse,
+                cut: 5,
+                complement_cut: 9,
+            }]
+        );
+
+        let fragments = super::digest(&sequence, &[&bsa_i]);
+
+        assert_eq!(
+            fragments,
+            vec![
+                Fragment {
+                    start: 0,
+                    sequence: dna("AAAAA"),
+                    left_end: End::Uncut,
+                    right_end: End::FivePrimeOverhang(dna("ACAG")),
+                },
+                Fragment {
+                    start: 5,
+                    sequence: dna("ACAGTGAGACCAAAA"),
+                    left_end: End::FivePrimeOverhang(dna("ACAG")),
+                    right_end: End::Uncut,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn digest_linear_reverse_site() {
+        let bsa_i = Enzyme::by_name("BsaI").unwrap();
+
+        // the overhang of the reverse site, from 2 to 6, must not wrap around the linear sequence
+        let fragments = super::digest(&dna("AAAAAAAGAGACCAAAA"), &[&bsa_i]);
+
+        assert_eq!(lengths(&fragments), vec![2, 15]);
+        assert_eq!(fragments[0].right_end, End::FivePrimeOverhang(dna("AAAA")));
+        assert_eq!(fragments[1].left_end, End::FivePrimeOverhang(dna("AAAA")));
+    }
+
+    #[test]
+    fn digest_with_several_enzymes() {
+        let pst_i = Enzyme::by_name("PstI").unwrap();
+        let sma_i = Enzyme::by_name("SmaI").unwrap();
+
+        let fragments = super::digest(&dna("ACTGCAGTTCCCGGGTT"), &[&pst_i, &sma_i]);
+
+        assert_eq!(lengths(&fragments), vec![6, 6, 5]);
+        assert_eq!(fragments[0].right_end, End::ThreePrimeOverhang(dna("TGCA")));
+        assert_eq!(fragments[1].right_end, End::Blunt);
+    }
+
+    #[test]
+    fn digest_same_cut_by_several_enzymes() {
+        let sma_i = Enzyme::by_name("SmaI").unwrap();
+        // cuts the top strand of the SmaI site at the same position, but leaves a 3' overhang
+        let custom = Enzyme::new("Custom", "CCCGGG", 3, 1).unwrap();
+
+        let sequence = dna("AACCCGGGAA");
+
+        let fragments = super::digest(&sequence, &[&custom, &sma_i]);
+        assert_eq!(lengths(&fragments), vec![5, 5]);
+        assert_eq!(fragments[0].right_end, End::ThreePrimeOverhang(dna("CC")));
+
+        let fragments = super::digest(&sequence, &[&sma_i, &custom]);
+        assert_eq!(lengths(&fragments), vec![5, 5]);
+        assert_eq!(fragments[0].right_end, End::Blunt);
+    }
+
+    #[test]
+    fn digest_circular() {
+        let eco_ri = Enzyme::by_name("EcoRI").unwrap();
+        let bam_hi = Enzyme::by_name("BamHI").unwrap();
+
+        // the EcoRI site spans the origin of the sequence
+        let plasmid = dna("ATTCAAAAGGATCCAAAAGA").with_topology(Topology::Circular);
+
+        let fragments = super::digest(&plasmid, &[&eco_ri, &bam_hi]);
+
+        assert_eq!(
+            fragments,
+            vec![
+                Fragment {
+                    start: 9,
+                    sequence: dna("GATCCAAAAG"),
+                    left_end: End::FivePrimeOverhang(dna("GATC")),
+                    right_end: End::FivePrimeOverhang(dna("AATT")),
+                },
+                Fragment {
+                    start: 19,
+                    sequence: dna("AATTCAAAAG"),
+                    left_end: End::FivePrimeOverhang(dna("AATT")),
+                    right_end: End::FivePrimeOverhang(dna("GATC")),
+                },
+            ]
+        );
+
+        // a single cut opens the plasmid
+        let fragments = super::digest(&plasmid, &[&bam_hi]);
+
+        assert_eq!(lengths(&fragments), vec![20]);
+        assert_eq!(fragments[0].start, 9);
+
+        // no cut at all
+        let fragments = super::digest(&plasmid, &[&Enzyme::by_name("NotI").unwrap()]);
+
+        assert_eq!(fragments[0].left_end, End::Uncut);
+        assert!(fragments[0].sequence.is_circular());
+        assert_eq!(lengths(&fragments), vec![20]);
+    }
+
+    #[test]
+    fn digest_small_circular() {
+        let eco_ri = Enzyme::by_name("EcoRI").unwrap();
+
+        // the overhang is longer than half the plasmid
+        for &(plasmid, start) in &[("GAATTC", 1), ("GAATTCA", 1), ("TTCAGAA", 5)] {
+            let fragments = super::digest(&dna(plasmid).with_topology(Topology::Circular), &[&eco_ri]);
+
+            assert_eq!(lengths(&fragments), vec![plasmid.len()]);
+            assert_eq!(fragments[0].start, start);
+            assert_eq!(fragments[0].left_end, End::FivePrimeOverhang(dna("AATT")));
+            assert_eq!(fragments[0].right_end, End::FivePrimeOverhang(dna("AATT")));
+        }
+    }
+}
+
+use std::{cmp, error, fmt};
+use alignment::Symbol;
+use dna::{Nucleobase, Sequence as DnaSequence, Topology};
+use search::Strand;
+
+// name, recognition site, cut on the top strand, cut on the bottom strand
+const CATALOGUE: &[(&str, &str, i64, i64)] = &[
+    ("AatII", "GACGTC", 5, 1),
+    ("AccI", "GTMKAC", 2, 4),
+    ("AluI", "AGCT", 2, 2),
+    ("ApaI", "GGGCCC", 5, 1),
+    ("AvaI", "CYCGRG", 1, 5),
+    ("BamHI", "GGATCC", 1, 5),
+    ("BglII", "AGATCT", 1, 5),
+    ("BsaI", "GGTCTC", 7, 11),
+    ("BsmBI", "CGTCTC", 7, 11),
+    ("ClaI", "ATCGAT", 2, 4),
+    ("DpnII", "GATC", 0, 4),
+    ("EcoRI", "GAATTC", 1, 5),
+    ("EcoRV", "GATATC", 3, 3),
+    ("HaeII", "RGCGCY", 5, 1),
+    ("HaeIII", "GGCC", 2, 2),
+    ("HhaI", "GCGC", 3, 1),
+    ("HindIII", "AAGCTT", 1, 5),
+    ("HinfI", "GANTC", 1, 4),
+    ("HpaI", "GTTAAC", 3, 3),
+    ("KpnI", "GGTACC", 5, 1),
+    ("MluI", "ACGCGT", 1, 5),
+    ("MspI", "CCGG", 1, 3),
+    ("NcoI", "CCATGG", 1, 5),
+    ("NdeI", "CATATG", 2, 4),
+    ("NheI", "GCTAGC", 1, 5),
+    ("NotI", "GCGGCCGC", 2, 6),
+    ("PstI", "CTGCAG", 5, 1),
+    ("PvuII", "CAGCTG", 3, 3),
+    ("SacI", "GAGCTC", 5, 1),
+    ("SalI", "GTCGAC", 1, 5),
+    ("ScaI", "AGTACT", 3, 3),
+    ("SmaI", "CCCGGG", 3, 3),
+    ("SpeI", "ACTAGT", 1, 5),
+    ("SphI", "GCATGC", 5, 1),
+    ("StyI", "CCWWGG", 1, 5),
+    ("TaqI", "TCGA", 1, 3),
+    ("XbaI", "TCTAGA", 1, 5),
+    ("XhoI", "CTCGAG", 1, 5),
+    ("XmaI", "CCCGGG", 1, 5),
+];
+
+#[derive(Debug, PartialEq)]
+pub enum RestrictionError {
+    EmptySite,
+    IllegalChar { ch: char, index: usize },
+}
+
+impl error::Error for RestrictionError {
+    fn description(&self) -> &str {
+        match self {
+            RestrictionError::EmptySite => "the recognition site is empty",
+            RestrictionError::IllegalChar { .. } => "there is no such IUPAC code",
+        }
+    }
+}
+
+impl fmt::Display for RestrictionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RestrictionError::EmptySite => write!(f, "the recognition site is empty"),
+            RestrictionError::IllegalChar { ch, index } => write!(
+                f,
+                "there is no such IUPAC code as {} at index {} of the recognition site",
+                ch, index
+            ),
+        }
+    }
+}
+
+/// A restriction enzyme.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Enzyme {
+    name: String,
+    site: String,
+    cut: i64,
+    complement_cut: i64,
+    // nucleobases accepted at each position of the site, one bit per nucleobase index
+    masks: Vec<u8>,
+    // the same for the reverse complement of the site
+    reverse_masks: Vec<u8>,
+}
+
+/// Recognition site of an enzyme in a sequence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Site {
+    /// 0-based position of the leftmost nucleobase of the site, on the forward strand.
+    pub position: usize,
+    /// Strand the site reads on, always `Forward` for sites which are their own reverse complement.
+    pub strand: Strand,
+    /// Position of the cut on the top strand: the enzyme cuts between `cut - 1` and `cut`.
+    pub cut: usize,
+    /// Position of the cut on the bottom strand, in top strand coordinates.
+    pub complement_cut: usize,
+}
+
+/// End of a fragment.
+#[derive(Debug, PartialEq)]
+pub enum End {
+    /// Not made by an enzyme: an end of a linear sequence, or no end at all for a circular one which wasn't cut.
+    Uncut,
+    Blunt,
+    /// The bottom strand is cut after the top strand, the overhang is given on the top strand.
+    FivePrimeOverhang(DnaSequence),
+    /// The top strand is cut after the bottom strand, the overhang is given on the top strand.
+    ThreePrimeOverhang(DnaSequence),
+}
+
+/// Product of a digestion.
+#[derive(Debug, PartialEq)]
+pub struct Fragment {
+    /// 0-based position of the first nucleobase of the fragment's top strand in the digested sequence.
+    pub start: usize,
+    /// Top strand of the fragment.
+    pub sequence: DnaSequence,
+    pub left_end: End,
+    pub right_end: End,
+}
+
+impl Fragment {
+    /// Returns the size of the fragment, counted on its top strand.
+    pub fn len(&self) -> usize {
+        self.sequence.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Enzyme {
+    /// Creates an enzyme recognizing `site` (IUPAC codes) and cutting the top strand at `cut`
+    /// and the bottom strand at `complement_cut`, both counted from the start of the site.
+    /// Cuts may be outside of the site, or even before it with negative positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosalind::restriction::Enzyme;
+    ///
+    /// let bsm_ai = Enzyme::new("BsmAI", "GTCTCN", 6, 10).unwrap();
+    /// ```
+    pub fn new(name: &str, site: &str, cut: i64, complement_cut: i64) -> Result<Self, RestrictionError> {
+        if site.is_empty() {
+            return Err(RestrictionError::EmptySite);
+        }
+
+        let masks = site
+            .chars()
+            .enumerate()
+            .map(|(index, ch)| iupac_mask(ch).ok_or(RestrictionError::IllegalChar { ch: ch, index: index }))
+            .collect::<Result<Vec<u8>, RestrictionError>>()?;
+
+        let reverse_masks = masks.iter().rev().map(|&mask| complement_mask(mask)).collect();
+
+        Ok(Enzyme {
+            name: name.to_string(),
+            site: site.to_string(),
+            cut: cut,
+            complement_cut: complement_cut,
+            masks: masks,
+            reverse_masks: reverse_masks,
+        })
+    }
+
+    /// Returns all the enzymes of the built-in catalogue, by name.
+    pub fn catalogue() -> Vec<Enzyme> {
+        CATALOGUE
+            .iter()
+            .map(|&(name, site, cut, complement_cut)| {
+                Enzyme::new(name, site, cut, complement_cut).expect("The catalogue sites are valid")
+            })
+            .collect()
+    }
+
+    /// Returns the enzyme of the built-in catalogue with this name, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosalind::restriction::Enzyme;
+    ///
+    /// let eco_ri = Enzyme::by_name("EcoRI").unwrap();
+    ///
+    /// assert_eq!(eco_ri.site(), "GAATTC");
+    /// ```
+    pub fn by_name(name: &str) -> Option<Enzyme> {
+        CATALOGUE
+            .iter()
+            .find(|&&(other, ..)| other.eq_ignore_ascii_case(name))
+            .map(|&(name, site, cut, complement_cut)| {
+                Enzyme::new(name, site, cut, complement_cut).expect("The catalogue sites are valid")
+            })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the recognition site, with IUPAC codes.
+    pub fn site(&self) -> &str {
+        &self.site
+    }
+
+    /// Returns whether the recognition site is its own reverse complement.
+    pub fn is_palindromic(&self) -> bool {
+        self.masks == self.reverse_masks
+    }
+
+    /// Returns the sites of `sequence` the enzyme recognizes and can cut, sorted by position:
+    /// in a linear sequence, the sites whose cuts would fall beyond its ends are left out.
+    pub fn find_sites(&self, sequence: &DnaSequence) -> Vec<Site> {
+        let topology = sequence.topology();
+        let length = sequence.len() as i64;
+        let site_length = self.masks.len() as i64;
+
+        let mut strands = vec![(Strand::Forward, &self.masks)];
+
+        if !self.is_palindromic() {
+            strands.push((Strand::Reverse, &self.reverse_masks));
+        }
+
+        let last_position = match topology {
+            Topology::Linear => length - site_length,
+            Topology::Circular => length - 1,
+        };
+
+        let mut sites = Vec::new();
+
+        for position in 0..last_position + 1 {
+            for &(strand, masks) in &strands {
+                if !matches(sequence, position as usize, masks) {
+                    continue;
+                }
+
+                let (cut, complement_cut) = match strand {
+                    Strand::Forward => (position + self.cut, position + self.complement_cut),
+                    Strand::Reverse => (
+                        position + site_length - self.complement_cut,
+                        position + site_length - self.cut,
+                    ),
+                };
+
+                let (cut, complement_cut) = match topology {
+                    Topology::Linear => {
+                        if cut <= 0 || cut >= length || complement_cut <= 0 || complement_cut >= length {
+                            continue;
+                        }
+
+                        (cut, complement_cut)
+                    }
+                    Topology::Circular => (cut.rem_euclid(length), complement_cut.rem_euclid(length)),
+                };
+
+                sites.push(Site {
+                    position: position as usize,
+                    strand: strand,
+                    cut: cut as usize,
+                    complement_cut: complement_cut as usize,
+                });
+            }
+        }
+
+        sites
+    }
+}
+
+/// Digests `sequence` with all the `enzymes` at once and returns the fragments, in the order of the sequence.
+/// A circular sequence is cut across its origin too, its fragments are listed from the first cut,
+/// and if there is no cut at all the only fragment is the sequence itself, still circular.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::{Sequence, Topology};
+/// use rosalind::restriction::{self, Enzyme};
+///
+/// let plasmid = Sequence::try_from("GAATTCAAAAGGATCCAAAAAAGCTTAA").unwrap().with_topology(Topology::Circular);
+///
+/// let eco_ri = Enzyme::by_name("EcoRI").unwrap();
+/// let hind_iii = Enzyme::by_name("HindIII").unwrap();
+///
+/// for fragment in restriction::digest(&plasmid, &[&eco_ri, &hind_iii]) {
+///     println!("{} bp: {} ({:?} / {:?})", fragment.len(), fragment.sequence, fragment.left_end, fragment.right_end);
+/// }
+/// ```
+pub fn digest(sequence: &DnaSequence, enzymes: &[&Enzyme]) -> Vec<Fragment> {
+    let length = sequence.len();
+
+    // cut positions on the top strand and the bottom strand, the latter relative to the former
+    // so that overhangs across the origin of a circular sequence keep the right orientation
+    let mut cuts: Vec<(usize, i64)> = enzymes
+        .iter()
+        .flat_map(|enzyme| {
+            // taken from the enzyme, as the cuts of a circular sequence may have wrapped around its origin;
+            // mirroring a reverse site swaps both cuts and their direction, so the offset holds on both strands
+            let offset = enzyme.complement_cut - enzyme.cut;
+
+            enzyme.find_sites(sequence).into_iter().map(move |site| (site.cut, offset))
+        })
+        .collect();
+
+    // two enzymes can't cut the same top strand position differently, the first one given does it:
+    // the sort is stable and only looks at the position
+    cuts.sort_by_key(|cut| cut.0);
+    cuts.dedup_by_key(|cut| cut.0);
+
+    let end = |&(cut, offset): &(usize, i64)| {
+        let overhang_start = if offset < 0 { cut as i64 + offset } else { cut as i64 };
+        let overhang_length = offset.unsigned_abs() as usize;
+
+        let overhang = match sequence.topology() {
+            Topology::Circular => {
+                sequence.subsequence(overhang_start.rem_euclid(length as i64) as usize, overhang_length)
+            }
+            // the ends of a linear sequence are never wrapped: what lies beyond them is clipped
+            Topology::Linear => {
+                let clip = |position: i64| cmp::min(cmp::max(position, 0), length as i64) as usize;
+                let (start, end) = (clip(overhang_start), clip(overhang_start + overhang_length as i64));
+
+                sequence.subsequence(start, end - start)
+            }
+        };
+
+        if offset > 0 {
+            End::FivePrimeOverhang(overhang)
+        } else if offset < 0 {
+            End::ThreePrimeOverhang(overhang)
+        } else {
+            End::Blunt
+        }
+    };
+
+    match sequence.topology() {
+        Topology::Linear => {
+            let mut fragments = Vec::with_capacity(cuts.len() + 1);
+            let mut start = 0;
+            let mut left_end = End::Uncut;
+
+            for cut in &cuts {
+                fragments.push(Fragment {
+                    start: start,
+                    sequence: sequence.subsequence(start, cut.0 - start),
+                    left_end: left_end,
+                    right_end: end(cut),
+                });
+
+                start = cut.0;
+                left_end = end(cut);
+            }
+
+            fragments.push(Fragment {
+                start: start,
+                sequence: sequence.subsequence(start, length - start),
+                left_end: left_end,
+                right_end: End::Uncut,
+            });
+
+            fragments
+        }
+        Topology::Circular => {
+            if cuts.is_empty() {
+                return vec![Fragment {
+                    start: 0,
+                    sequence: sequence.subsequence(0, length).with_topology(Topology::Circular),
+                    left_end: End::Uncut,
+                    right_end: End::Uncut,
+                }];
+            }
+
+            (0..cuts.len())
+                .map(|index| {
+                    let from = &cuts[index];
+                    let to = &cuts[(index + 1) % cuts.len()];
+
+                    // with a single cut, the fragment goes all the way around
+                    let fragment_length = match (to.0 + length - from.0) % length {
+                        0 => length,
+                        fragment_length => fragment_length,
+                    };
+
+                    Fragment {
+                        start: from.0,
+                        sequence: sequence.subsequence(from.0, fragment_length),
+                        left_end: end(from),
+                        right_end: end(to),
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+// IUPAC code to the set of nucleobases it stands for, one bit per nucleobase index (A, C, G, T)
+fn iupac_mask(ch: char) -> Option<u8> {
+    let mask = match ch.to_ascii_uppercase() {
+        'A' => 0b0001,
+        'C' => 0b0010,
+        'G' => 0b0100,
+        'T' => 0b1000,
+        'R' => 0b0101,
+        'Y' => 0b1010,
+        'S' => 0b0110,
+        'W' => 0b1001,
+        'K' => 0b1100,
+        'M' => 0b0011,
+        'B' => 0b1110,
+        'D' => 0b1101,
+        'H' => 0b1011,
+        'V' => 0b0111,
+        'N' => 0b1111,
+        _ => return None,
+    };
+
+    Some(mask)
+}
+
+// A <=> T and C <=> G, which reverses the order of the bits
+fn complement_mask(mask: u8) -> u8 {
+    (0..4).fold(0, |complement, bit| complement | (((mask >> bit) & 1) << (3 - bit)))
+}
+
+// whether the site starting at position matches, wrapping around the end of the sequence
+fn matches(sequence: &DnaSequence, position: usize, masks: &[u8]) -> bool {
+    let nucleobases = sequence.as_ref();
+
+    masks.iter().enumerate().all(|(offset, &mask)| {
+        let nucleobase: &Nucleobase = &nucleobases[(position + offset) % nucleobases.len()];
+
+        mask & (1 << nucleobase.index()) != 0
+    })
+}
@@ -0,0 +1,238 @@
+// reverse translation: the mRNAs a genetic code translates to a given protein
+//
+// the protein is translated codon by codon, so a protein ending with a stop (*) only matches mRNAs ending with a stop codon,
+// and a protein without one matches mRNAs without any stop codon
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use num_bigint::BigUint;
+    use amino_acids::Sequence as AminoAcidSequence;
+    use genetic_code::{GeneticCode, TranslationMode};
+
+    fn protein(string: &str) -> AminoAcidSequence {
+        AminoAcidSequence::try_from(string).unwrap()
+    }
+
+    #[test]
+    fn count() {
+        let standard = GeneticCode::standard();
+
+        assert_eq!(super::count(&protein("MA*"), &standard), BigUint::from(12u32));
+        assert_eq!(super::count(&protein(""), &standard), BigUint::from(1u32));
+
+        // vertebrate mitochondrial: AUA is a methionine too, and only AGA, AGG, UAA and UAG are stops
+        assert_eq!(super::count(&protein("MA*"), &GeneticCode::ncbi(2).unwrap()), BigUint::from(32u32));
+
+        // way more than a u64 can hold
+        let long = "L".repeat(30);
+
+        assert_eq!(super::count(&protein(&long), &standard), num_traits::pow(BigUint::from(6u32), 30));
+    }
+
+    #[test]
+    fn mrnas() {
+        let standard = GeneticCode::standard();
+
+        let mrnas: Vec<String> = super::mrnas(&protein("MF*"), &standard).map(|mrna| mrna.to_string()).collect();
+
+        assert_eq!(
+            mrnas,
+            vec![
+                "AUGUUUUAA", "AUGUUUUAG", "AUGUUUUGA", "AUGUUCUAA", "AUGUUCUAG", "AUGUUCUGA",
+            ]
+        );
+
+        for mrna in super::mrnas(&protein("WKR"), &standard) {
+            let translation = standard.translate(&mrna, TranslationMode::ReadThrough).unwrap();
+
+            assert_eq!(translation.protein.to_string(), "WKR");
+        }
+
+        assert_eq!(super::mrnas(&protein("WKR"), &standard).count(), 12);
+        assert_eq!(super::mrnas(&protein(""), &standard).count(), 1);
+    }
+
+    #[test]
+    fn consensus() {
+        let standard = GeneticCode::standard();
+
+        assert_eq!(super::consensus(&protein("MAFK*"), &standard).unwrap(), "AUGGCNUUYAARURR");
+
+        // 6-codon amino acids: the consensus stands for more codons than there are
+        assert_eq!(super::consensus(&protein("LS"), &standard).unwrap(), "YUNWSN");
+    }
+}
+
+use num_bigint::BigUint;
+use amino_acids::Sequence as AminoAcidSequence;
+use genetic_code::GeneticCode;
+use rna::{Codon, Nucleobase, Sequence as RnaSequence};
+
+/// Returns the number of mRNAs `genetic_code` translates to `protein`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate num_bigint;
+/// extern crate rosalind;
+///
+/// use std::convert::TryFrom;
+/// use num_bigint::BigUint;
+/// use rosalind::amino_acids::Sequence;
+/// use rosalind::genetic_code::GeneticCode;
+///
+/// # fn main() {
+/// let protein = Sequence::try_from("MA*").unwrap();
+///
+/// let count = rosalind::reverse_translation::count(&protein, &GeneticCode::standard());
+///
+/// assert_eq!(count, BigUint::from(12u32));
+/// # }
+/// ```
+pub fn count(protein: &AminoAcidSequence, genetic_code: &GeneticCode) -> BigUint {
+    protein.into_iter().fold(BigUint::from(1u32), |count, amino_acid| {
+        count * BigUint::from(genetic_code.codons(&amino_acid).len() as u64)
+    })
+}
+
+/// Iterates over the mRNAs `genetic_code` translates to `protein`, the codons of each amino acid in the TCAG order
+/// (the last codon changing first).
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::amino_acids::Sequence;
+/// use rosalind::genetic_code::GeneticCode;
+///
+/// let protein = Sequence::try_from("MK").unwrap();
+///
+/// for mrna in rosalind::reverse_translation::mrnas(&protein, &GeneticCode::standard()) {
+///     println!("{}", mrna);
+/// }
+/// ```
+pub fn mrnas(protein: &AminoAcidSequence, genetic_code: &GeneticCode) -> Mrnas {
+    let codons: Vec<Vec<Codon>> = protein
+        .into_iter()
+        .map(|amino_acid| genetic_code.codons(&amino_acid))
+        .collect();
+    let done = codons.iter().any(|codons| codons.is_empty());
+
+    Mrnas {
+        indexes: vec![0; codons.len()],
+        codons: codons,
+        done: done,
+    }
+}
+
+/// Iterator over the mRNAs coding for a protein, see `mrnas`.
+pub struct Mrnas {
+    // codons of every amino acid of the protein
+    codons: Vec<Vec<Codon>>,
+    // index of the codon of every amino acid in the next mRNA
+    indexes: Vec<usize>,
+    done: bool,
+}
+
+impl Iterator for Mrnas {
+    type Item = RnaSequence;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut nucleobases = Vec::with_capacity(3 * self.codons.len());
+
+        for (codons, &index) in self.codons.iter().zip(&self.indexes) {
+            nucleobases.extend_from_slice(&codons[index].0);
+        }
+
+        // like an odometer: increments the last index, carrying over to the previous ones
+        self.done = true;
+
+        for (codons, index) in self.codons.iter().zip(self.indexes.iter_mut()).rev() {
+            *index += 1;
+
+            if *index < codons.len() {
+                self.done = false;
+                break;
+            }
+
+            *index = 0;
+        }
+
+        Some(RnaSequence::new(nucleobases))
+    }
+}
+
+/// Returns the degenerate sequence, with IUPAC codes (e.g. Y for C or U), standing for all the mRNAs `genetic_code`
+/// translates to `protein`, or None if an amino acid has no codon at all.
+///
+/// Each codon position gets the nucleobases found there among the codons of its amino acid,
+/// so the consensus of an amino acid with unrelated codons (like leucine: CUN and UUR) stands for other codons too.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::amino_acids::Sequence;
+/// use rosalind::genetic_code::GeneticCode;
+///
+/// let protein = Sequence::try_from("MK*").unwrap();
+///
+/// let consensus = rosalind::reverse_translation::consensus(&protein, &GeneticCode::standard());
+///
+/// assert_eq!(consensus.unwrap(), "AUGAARURR");
+/// ```
+pub fn consensus(protein: &AminoAcidSequence, genetic_code: &GeneticCode) -> Option<String> {
+    let mut consensus = String::with_capacity(3 * protein.len());
+
+    for amino_acid in protein {
+        let codons = genetic_code.codons(&amino_acid);
+
+        if codons.is_empty() {
+            return None;
+        }
+
+        for position in 0..3 {
+            let mask = codons.iter().fold(0, |mask, codon| mask | nucleobase_mask(codon.0[position]));
+
+            consensus.push(iupac_code(mask));
+        }
+    }
+
+    Some(consensus)
+}
+
+// one bit per nucleobase: A, C, G, U
+fn nucleobase_mask(nucleobase: Nucleobase) -> u8 {
+    match nucleobase {
+        Nucleobase::Adenine => 0b0001,
+        Nucleobase::Cytosine => 0b0010,
+        Nucleobase::Guanine => 0b0100,
+        Nucleobase::Uracil => 0b1000,
+    }
+}
+
+fn iupac_code(mask: u8) -> char {
+    match mask {
+        0b0001 => 'A',
+        0b0010 => 'C',
+        0b0100 => 'G',
+        0b1000 => 'U',
+        0b0101 => 'R',
+        0b1010 => 'Y',
+        0b0110 => 'S',
+        0b1001 => 'W',
+        0b1100 => 'K',
+        0b0011 => 'M',
+        0b1110 => 'B',
+        0b1101 => 'D',
+        0b1011 => 'H',
+        0b0111 => 'V',
+        0b1111 => 'N',
+        _ => unreachable!("Every amino acid has at least one codon here"),
+    }
+}
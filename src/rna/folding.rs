@@ -0,0 +1,752 @@
+// minimum free energy folding: the secondary structure of a RNA sequence with the lowest nearest-neighbor energy
+// (a simplified Zuker algorithm)
+//
+// the energy of a structure is the sum of the energies of its loops:
+// stacks of two pairs, hairpins, bulges, internal loops and multiloops, the exterior loop being free;
+// hairpins, bulges and internal loops only depend on their size, with no sequence-dependent bonus
+//
+// the default parameters are Turner 1999 (https://rna.urmc.rochester.edu/NNDB/turner99/), in kcal/mol at 37 °C,
+// and custom ones are read from files in this format, any missing parameter keeping its default value:
+//
+//     # comments and blank lines are ignored
+//     # the outer pair of the stack, then the inner one, each written 5' nucleobase first:
+//     # 5'-AC-3' paired with 3'-UG-5'
+//     stack AU CG = -2.24
+//     # penalties of the loops by size, from 3 nucleobases for hairpins, 1 for bulges and 2 for internal loops
+//     hairpin   = 5.7 5.6 5.6 5.4 5.9 5.6 6.4
+//     bulge     = 3.8 2.8 3.2 3.6 4.0 4.4
+//     internal  = 4.1 5.1 1.7 1.8 2.0
+//     # initiation, per unpaired nucleobase and per branch, the closing pair being a branch too
+//     multiloop = 3.4 0.0 0.4
+//
+// longer loops are extrapolated from the last size given, logarithmically
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use rna::Sequence;
+    use super::{EnergyParameters, EnergyParametersError, LoopKind, Zuker};
+
+    fn rna(string: &str) -> Sequence {
+        Sequence::try_from(string).unwrap()
+    }
+
+    fn assert_energy(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn fold_hairpin() {
+        let folding = Zuker::new().fold(&rna("GGGGAAAACCCC"));
+
+        assert_eq!(folding.structure.to_string(), "((((....))))");
+        // 3 G-C/G-C stacks and a 4-nucleobase hairpin
+        assert_energy(folding.energy, 3.0 * -3.26 + 5.6);
+
+        let kinds: Vec<LoopKind> = folding.loops.iter().map(|loop_| loop_.kind).collect();
+
+        assert_eq!(kinds, [LoopKind::Stack, LoopKind::Stack, LoopKind::Stack, LoopKind::Hairpin]);
+        assert_eq!((folding.loops[3].opening, folding.loops[3].closing), (3, 8));
+        assert_energy(folding.loops[3].energy, 5.6);
+    }
+
+    #[test]
+    fn fold_unstructured() {
+        // nothing pairs
+        let folding = Zuker::new().fold(&rna("AAAAAAAAAA"));
+
+        assert_eq!(folding.structure.to_string(), "..........");
+        assert_energy(folding.energy, 0.0);
+        assert!(folding.loops.is_empty());
+
+        // the hairpin costs more than its only stack gains
+        let folding = Zuker::new().fold(&rna("GCAAAGC"));
+
+        assert_eq!(folding.structure.to_string(), ".......");
+
+        assert!(Zuker::new().fold(&rna("")).structure.is_empty());
+    }
+
+    #[test]
+    fn fold_multiloop() {
+        let folding = Zuker::new().fold(&rna("CUGACAGGCGCGAAAGCGCCACCAGGGAAACCUGGAGUCAG"));
+
+        assert_eq!(folding.structure.to_string(), "(((((.(((((....))))).(((((....))))).)))))");
+
+        let multiloop = folding
+            .loops
+            .iter()
+            .find(|loop_| loop_.kind == LoopKind::Multiloop)
+            .unwrap();
+
+        assert_eq!((multiloop.opening, multiloop.closing), (4, 36));
+        // 3 branches, the unpaired nucleobases being free
+        assert_energy(multiloop.energy, 3.4 + 3.0 * 0.4);
+
+        let total: f64 = folding.loops.iter().map(|loop_| loop_.energy).sum();
+
+        assert_energy(folding.energy, total);
+    }
+
+    #[test]
+    fn fold_bulge() {
+        let folding = Zuker::new().fold(&rna("GGGGAGGGAAAACCCCCCC"));
+
+        assert_eq!(folding.structure.to_string(), "((((.(((....)))))))");
+
+        let bulge = folding.loops.iter().find(|loop_| loop_.kind == LoopKind::Bulge).unwrap();
+
+        // a single-nucleobase bulge keeps the stacking of the pairs around it
+        assert_eq!((bulge.opening, bulge.closing), (3, 15));
+        assert_energy(bulge.energy, 3.8 - 3.26);
+    }
+
+    #[test]
+    fn energy_parameters() {
+        let parameters = EnergyParameters::try_from(
+            "# weaker G-C stacks\n\
+             stack GC GC = -1.5\n\
+             hairpin = 3.0\n",
+        ).unwrap();
+
+        let folding = Zuker::new().parameters(parameters.clone()).fold(&rna("GGGGAAAACCCC"));
+
+        // the hairpin penalty grows logarithmically past the last size given
+        assert_energy(folding.energy, 3.0 * -1.5 + 3.0 + (1.07856f64 * (4.0f64 / 3.0).ln() * 100.0).round() / 100.0);
+
+        // written and read back
+        assert_eq!(EnergyParameters::try_from(parameters.to_string().as_str()).unwrap(), parameters);
+        assert_eq!(
+            EnergyParameters::try_from(EnergyParameters::turner_1999().to_string().as_str()).unwrap(),
+            EnergyParameters::turner_1999()
+        );
+
+        match EnergyParameters::try_from("stack AU CG -2.2") {
+            Err(EnergyParametersError::InvalidLine { line: 1 }) => {}
+            result => panic!("unexpected {:?}", result),
+        }
+        match EnergyParameters::try_from("\nstack AU CA = -2.2") {
+            Err(EnergyParametersError::InvalidPair { line: 2 }) => {}
+            result => panic!("unexpected {:?}", result),
+        }
+        match EnergyParameters::try_from("multiloop = 3.4 0.0") {
+            Err(EnergyParametersError::InvalidEnergy { line: 1 }) => {}
+            result => panic!("unexpected {:?}", result),
+        }
+        match EnergyParameters::try_from("hairpin = ") {
+            Err(EnergyParametersError::InvalidEnergy { line: 1 }) => {}
+            result => panic!("unexpected {:?}", result),
+        }
+        match EnergyParameters::try_from("hairpn = 5.7") {
+            Err(EnergyParametersError::UnknownKey { line: 1 }) => {}
+            result => panic!("unexpected {:?}", result),
+        }
+    }
+}
+
+use std::{cmp, error, fmt, fs, io};
+use std::convert::{self, TryFrom};
+use super::{Nucleobase, Sequence};
+use super::structure::{pair_kind, Pair, Structure};
+
+// energies are handled in hundredths of kcal/mol, so that sums are exact
+type Energy = i32;
+
+// energy of what can't be formed, small enough for a few of them to be added without overflowing
+const INFINITY: Energy = Energy::MAX / 4;
+
+const MIN_HAIRPIN_LOOP: usize = 3;
+const MIN_BULGE: usize = 1;
+const MIN_INTERNAL_LOOP: usize = 2;
+
+// 1.75 RT at 37 °C, in hundredths of kcal/mol
+const LOOP_EXTRAPOLATION: f64 = 107.856;
+
+// the pairs in the order of the stack table
+const PAIRS: [&str; 6] = ["AU", "CG", "GC", "UA", "GU", "UG"];
+
+fn pair_index(a: Nucleobase, b: Nucleobase) -> Option<usize> {
+    use super::Nucleobase::*;
+
+    match (a, b) {
+        (Adenine, Uracil) => Some(0),
+        (Cytosine, Guanine) => Some(1),
+        (Guanine, Cytosine) => Some(2),
+        (Uracil, Adenine) => Some(3),
+        (Guanine, Uracil) => Some(4),
+        (Uracil, Guanine) => Some(5),
+        _ => None,
+    }
+}
+
+// index of the same pair read from the other strand (A-U <=> U-A)
+fn reversed_pair_index(index: usize) -> usize {
+    [3, 2, 1, 0, 5, 4][index]
+}
+
+fn to_energy(kcal_per_mol: f64) -> Energy {
+    (kcal_per_mol * 100.0).round() as Energy
+}
+
+fn to_kcal_per_mol(energy: Energy) -> f64 {
+    f64::from(energy) / 100.0
+}
+
+#[derive(Debug)]
+pub enum EnergyParametersError {
+    Io(io::Error),
+    InvalidLine { line: usize },
+    UnknownKey { line: usize },
+    InvalidPair { line: usize },
+    InvalidEnergy { line: usize },
+}
+
+impl error::Error for EnergyParametersError {
+    fn description(&self) -> &str {
+        match self {
+            EnergyParametersError::Io(_) => "couldn't read the energy parameters",
+            EnergyParametersError::InvalidLine { .. } => "a line is neither a comment nor a key = value pair",
+            EnergyParametersError::UnknownKey { .. } => "unknown parameter",
+            EnergyParametersError::InvalidPair { .. } => "a stack has an invalid pair",
+            EnergyParametersError::InvalidEnergy { .. } => "invalid energies",
+        }
+    }
+}
+
+impl fmt::Display for EnergyParametersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnergyParametersError::Io(error) => write!(f, "couldn't read the energy parameters: {}", error),
+            EnergyParametersError::InvalidLine { line } => {
+                write!(f, "line {} is neither a comment nor a key = value pair", line)
+            }
+            EnergyParametersError::UnknownKey { line } => write!(f, "line {} sets an unknown parameter", line),
+            EnergyParametersError::InvalidPair { line } => write!(
+                f,
+                "the stack of line {} must be made of two of the AU, CG, GC, UA, GU and UG pairs",
+                line
+            ),
+            EnergyParametersError::InvalidEnergy { line } => {
+                write!(f, "line {} hasn't the right number of energies in kcal/mol", line)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for EnergyParametersError {
+    fn from(error: io::Error) -> Self {
+        EnergyParametersError::Io(error)
+    }
+}
+
+/// Nearest-neighbor energy parameters, see the format at the top of this module.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnergyParameters {
+    // by outer pair, then inner pair, in the order of PAIRS
+    stacks: [[Energy; 6]; 6],
+    // penalties by size, from the smallest loop possible
+    hairpin: Vec<Energy>,
+    bulge: Vec<Energy>,
+    internal: Vec<Energy>,
+    // initiation, per unpaired nucleobase, per branch
+    multiloop: [Energy; 3],
+}
+
+impl EnergyParameters {
+    /// Returns the Turner 1999 stacking energies and loop penalties.
+    pub fn turner_1999() -> Self {
+        EnergyParameters {
+            stacks: [
+                [-93, -224, -208, -110, -55, -136],
+                [-211, -326, -236, -208, -141, -211],
+                [-235, -342, -326, -224, -153, -251],
+                [-133, -235, -211, -93, -100, -127],
+                [-127, -251, -211, -136, -50, 129],
+                [-100, -153, -141, -55, 30, -50],
+            ],
+            hairpin: vec![570, 560, 560, 540, 590, 560, 640],
+            bulge: vec![380, 280, 320, 360, 400, 440],
+            internal: vec![410, 510, 170, 180, 200],
+            multiloop: [340, 0, 40],
+        }
+    }
+
+    /// Loads energy parameters from a file, see the format at the top of this module.
+    pub fn load(path: &str) -> Result<Self, EnergyParametersError> {
+        let contents = fs::read_to_string(path)?;
+
+        EnergyParameters::try_from(contents.as_str())
+    }
+
+    fn stack(&self, outer: usize, inner: usize) -> Energy {
+        self.stacks[outer][inner]
+    }
+
+    fn hairpin(&self, size: usize) -> Energy {
+        loop_penalty(&self.hairpin, MIN_HAIRPIN_LOOP, size)
+    }
+
+    fn bulge(&self, size: usize) -> Energy {
+        loop_penalty(&self.bulge, MIN_BULGE, size)
+    }
+
+    fn internal(&self, size: usize) -> Energy {
+        loop_penalty(&self.internal, MIN_INTERNAL_LOOP, size)
+    }
+}
+
+impl Default for EnergyParameters {
+    fn default() -> Self {
+        EnergyParameters::turner_1999()
+    }
+}
+
+// penalty of a loop of `size` nucleobases, `penalties` starting at `min_size` nucleobases
+fn loop_penalty(penalties: &[Energy], min_size: usize, size: usize) -> Energy {
+    let last = penalties.len() - 1;
+
+    if size - min_size <= last {
+        penalties[size - min_size]
+    } else {
+        let ratio = size as f64 / (min_size + last) as f64;
+
+        penalties[last] + (LOOP_EXTRAPOLATION * ratio.ln()).round() as Energy
+    }
+}
+
+impl<'a> convert::TryFrom<&'a str> for EnergyParameters {
+    type Error = EnergyParametersError;
+
+    /// Parses energy parameters in the format described at the top of this module,
+    /// the missing ones being Turner 1999's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::rna::folding::EnergyParameters;
+    ///
+    /// let parameters = EnergyParameters::try_from("multiloop = 3.4 0.0 0.4").unwrap();
+    ///
+    /// assert_eq!(parameters, EnergyParameters::turner_1999());
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parameters = EnergyParameters::turner_1999();
+
+        for (line_index, line) in value.lines().enumerate() {
+            let line_number = line_index + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut key_value = line.splitn(2, '=');
+
+            let (key, value) = match (key_value.next(), key_value.next()) {
+                (Some(key), Some(value)) => (key.to_ascii_lowercase(), value),
+                _ => return Err(EnergyParametersError::InvalidLine { line: line_number }),
+            };
+
+            let energies = value
+                .split_whitespace()
+                .map(|energy| energy.parse().map(to_energy))
+                .collect::<Result<Vec<Energy>, _>>()
+                .map_err(|_| EnergyParametersError::InvalidEnergy { line: line_number })?;
+
+            let words: Vec<&str> = key.split_whitespace().collect();
+
+            match words.as_slice() {
+                ["stack", outer, inner] => {
+                    let pair = |name: &str| {
+                        PAIRS
+                            .iter()
+                            .position(|&pair| pair.eq_ignore_ascii_case(name))
+                            .ok_or(EnergyParametersError::InvalidPair { line: line_number })
+                    };
+                    let (outer, inner) = (pair(outer)?, pair(inner)?);
+
+                    if energies.len() != 1 {
+                        return Err(EnergyParametersError::InvalidEnergy { line: line_number });
+                    }
+
+                    // the same stack, read from the other strand
+                    parameters.stacks[outer][inner] = energies[0];
+                    parameters.stacks[reversed_pair_index(inner)][reversed_pair_index(outer)] = energies[0];
+                }
+                ["hairpin"] | ["bulge"] | ["internal"] => {
+                    if energies.is_empty() {
+                        return Err(EnergyParametersError::InvalidEnergy { line: line_number });
+                    }
+
+                    match words[0] {
+                        "hairpin" => parameters.hairpin = energies,
+                        "bulge" => parameters.bulge = energies,
+                        _ => parameters.internal = energies,
+                    }
+                }
+                ["multiloop"] => {
+                    if energies.len() != 3 {
+                        return Err(EnergyParametersError::InvalidEnergy { line: line_number });
+                    }
+
+                    parameters.multiloop.copy_from_slice(&energies);
+                }
+                _ => return Err(EnergyParametersError::UnknownKey { line: line_number }),
+            }
+        }
+
+        Ok(parameters)
+    }
+}
+
+impl fmt::Display for EnergyParameters {
+    /// Writes the parameters in the format described at the top of this module.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let energies = |energies: &[Energy]| {
+            energies
+                .iter()
+                .map(|&energy| format!("{:.2}", to_kcal_per_mol(energy)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        for (outer, outer_name) in PAIRS.iter().enumerate() {
+            for (inner, inner_name) in PAIRS.iter().enumerate() {
+                writeln!(
+                    f,
+                    "stack {} {} = {:.2}",
+                    outer_name,
+                    inner_name,
+                    to_kcal_per_mol(self.stacks[outer][inner])
+                )?;
+            }
+        }
+
+        writeln!(f, "hairpin = {}", energies(&self.hairpin))?;
+        writeln!(f, "bulge = {}", energies(&self.bulge))?;
+        writeln!(f, "internal = {}", energies(&self.internal))?;
+        writeln!(f, "multiloop = {}", energies(&self.multiloop))
+    }
+}
+
+/// Kind of a loop of a secondary structure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoopKind {
+    /// Unpaired nucleobases closed by a single pair.
+    Hairpin,
+    /// Two consecutive pairs.
+    Stack,
+    /// Two pairs with unpaired nucleobases on one side only.
+    Bulge,
+    /// Two pairs with unpaired nucleobases on both sides.
+    InternalLoop,
+    /// A pair closing several other pairs.
+    Multiloop,
+}
+
+/// Loop closed by the pair between `opening` and `closing`, with its energy in kcal/mol.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loop {
+    pub kind: LoopKind,
+    pub opening: usize,
+    pub closing: usize,
+    pub energy: f64,
+}
+
+/// Result of a minimum free energy folding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Folding {
+    pub structure: Structure,
+    /// Energy of the structure in kcal/mol, the sum of the energies of its loops.
+    pub energy: f64,
+    /// Loops of the structure, by increasing opening nucleobase.
+    pub loops: Vec<Loop>,
+}
+
+/// Predicts secondary structures by minimizing their free energy (simplified Zuker algorithm).
+/// Watson-Crick and G-U pairs are made, hairpin loops have at least 3 nucleobases,
+/// and bulges and internal loops at most 30 unpaired nucleobases.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::rna::Sequence;
+/// use rosalind::rna::folding::Zuker;
+///
+/// let sequence = Sequence::try_from("GGGGAAAACCCC").unwrap();
+///
+/// let folding = Zuker::new().fold(&sequence);
+///
+/// assert_eq!(folding.structure.to_string(), "((((....))))");
+/// assert!(folding.energy < 0.0);
+/// ```
+pub struct Zuker {
+    parameters: EnergyParameters,
+    max_internal_loop: usize,
+}
+
+// minimum energies of the subsequences between i and j included
+struct Tables {
+    // i and j paired
+    paired: Vec<Vec<Energy>>,
+    // inside a multiloop, with at least one branch
+    branches: Vec<Vec<Energy>>,
+    // exterior[j]: the nucleobases before j
+    exterior: Vec<Energy>,
+}
+
+impl Default for Zuker {
+    fn default() -> Self {
+        Zuker::new()
+    }
+}
+
+impl Zuker {
+    pub fn new() -> Self {
+        Zuker {
+            parameters: EnergyParameters::turner_1999(),
+            max_internal_loop: 30,
+        }
+    }
+
+    pub fn parameters(self, parameters: EnergyParameters) -> Self {
+        Zuker {
+            parameters: parameters,
+            ..self
+        }
+    }
+
+    /// Limits bulges and internal loops to `max_internal_loop` unpaired nucleobases.
+    pub fn max_internal_loop(self, max_internal_loop: usize) -> Self {
+        Zuker {
+            max_internal_loop: max_internal_loop,
+            ..self
+        }
+    }
+
+    /// Returns the structure of `sequence` with the lowest energy.
+    pub fn fold(&self, sequence: &Sequence) -> Folding {
+        let tables = self.tables(sequence);
+        let energy = tables.exterior[sequence.len()];
+        let (pairs, loops) = self.traceback(sequence, &tables);
+
+        Folding {
+            structure: Structure::new(sequence.len(), pairs),
+            energy: to_kcal_per_mol(energy),
+            loops: loops,
+        }
+    }
+
+    fn tables(&self, sequence: &Sequence) -> Tables {
+        let n = sequence.len();
+        let [_, unpaired, branch] = self.parameters.multiloop;
+        let mut paired = vec![vec![INFINITY; n]; n];
+        let mut branches = vec![vec![INFINITY; n]; n];
+
+        for span in MIN_HAIRPIN_LOOP + 1..n {
+            for i in 0..n - span {
+                let j = i + span;
+
+                if pair_index(sequence[i], sequence[j]).is_some() {
+                    let mut best = self.parameters.hairpin(span - 1);
+
+                    for (p, q) in self.inner_pairs(i, j) {
+                        if paired[p][q] < INFINITY {
+                            best = cmp::min(best, self.two_pair_loop(sequence, i, j, p, q).1 + paired[p][q]);
+                        }
+                    }
+
+                    best = cmp::min(best, self.multiloop(&branches, i, j));
+                    paired[i][j] = best;
+                }
+
+                let mut best = cmp::min(
+                    paired[i][j] + branch,
+                    cmp::min(branches[i + 1][j], branches[i][j - 1]) + unpaired,
+                );
+
+                for k in i + 1..j {
+                    best = cmp::min(best, branches[i][k] + branches[k + 1][j]);
+                }
+
+                branches[i][j] = best;
+            }
+        }
+
+        let mut exterior = vec![0; n + 1];
+
+        for j in 1..n + 1 {
+            exterior[j] = exterior[j - 1];
+
+            for i in 0..j {
+                exterior[j] = cmp::min(exterior[j], exterior[i] + paired[i][j - 1]);
+            }
+        }
+
+        Tables {
+            paired: paired,
+            branches: branches,
+            exterior: exterior,
+        }
+    }
+
+    // the pairs p-q which can close a stack, bulge or internal loop with i-j
+    fn inner_pairs(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> {
+        let max_internal_loop = self.max_internal_loop;
+
+        (i + 1..cmp::min(i + 2 + max_internal_loop, j)).flat_map(move |p| {
+            let left = p - i - 1;
+
+            (p + MIN_HAIRPIN_LOOP + 1..j)
+                .rev()
+                .take_while(move |q| left + (j - q - 1) <= max_internal_loop)
+                .map(move |q| (p, q))
+        })
+    }
+
+    // the loop closed by i-j with p-q inside, both pairs being valid
+    fn two_pair_loop(&self, sequence: &Sequence, i: usize, j: usize, p: usize, q: usize) -> (LoopKind, Energy) {
+        let outer = pair_index(sequence[i], sequence[j]).expect("i and j pair");
+        let inner = pair_index(sequence[p], sequence[q]).expect("p and q pair");
+        let (left, right) = (p - i - 1, j - q - 1);
+
+        match (left, right) {
+            (0, 0) => (LoopKind::Stack, self.parameters.stack(outer, inner)),
+            // the pairs around a single nucleobase still stack
+            (0, 1) | (1, 0) => (
+                LoopKind::Bulge,
+                self.parameters.bulge(1) + self.parameters.stack(outer, inner),
+            ),
+            (0, size) | (size, 0) => (LoopKind::Bulge, self.parameters.bulge(size)),
+            _ => (LoopKind::InternalLoop, self.parameters.internal(left + right)),
+        }
+    }
+
+    // i-j closing a multiloop of at least two other branches
+    fn multiloop(&self, branches: &[Vec<Energy>], i: usize, j: usize) -> Energy {
+        let [initiation, _, branch] = self.parameters.multiloop;
+
+        (i + 2..j - 1)
+            .map(|k| initiation + branch + branches[i + 1][k] + branches[k + 1][j - 1])
+            .min()
+            .unwrap_or(INFINITY)
+    }
+
+    fn traceback(&self, sequence: &Sequence, tables: &Tables) -> (Vec<Pair>, Vec<Loop>) {
+        enum Segment {
+            // paired ends
+            Paired(usize, usize),
+            // inside the multiloop of index `loop` in `loops`
+            Branches(usize, usize, usize),
+        }
+
+        let [initiation, unpaired, branch] = self.parameters.multiloop;
+        let mut pairs = Vec::new();
+        let mut loops = Vec::new();
+        let mut segments = Vec::new();
+
+        // the exterior loop, from the 3' end
+        let mut j = sequence.len();
+
+        while j > 0 {
+            if tables.exterior[j] == tables.exterior[j - 1] {
+                j -= 1;
+                continue;
+            }
+
+            let i = (0..j)
+                .rev()
+                .find(|&i| tables.exterior[i] + tables.paired[i][j - 1] == tables.exterior[j])
+                .expect("The exterior loop comes from a pair");
+
+            segments.push(Segment::Paired(i, j - 1));
+            j = i;
+        }
+
+        while let Some(segment) = segments.pop() {
+            match segment {
+                Segment::Paired(i, j) => {
+                    let energy = tables.paired[i][j];
+
+                    pairs.push(Pair {
+                        opening: i,
+                        closing: j,
+                        kind: pair_kind(sequence[i], sequence[j]).expect("i and j pair"),
+                    });
+
+                    if energy == self.parameters.hairpin(j - i - 1) {
+                        loops.push((LoopKind::Hairpin, i, j, energy));
+                        continue;
+                    }
+
+                    let inner = self.inner_pairs(i, j).find_map(|(p, q)| {
+                        if tables.paired[p][q] >= INFINITY {
+                            return None;
+                        }
+
+                        let (kind, loop_energy) = self.two_pair_loop(sequence, i, j, p, q);
+
+                        if loop_energy + tables.paired[p][q] == energy {
+                            Some((kind, loop_energy, p, q))
+                        } else {
+                            None
+                        }
+                    });
+
+                    if let Some((kind, loop_energy, p, q)) = inner {
+                        loops.push((kind, i, j, loop_energy));
+                        segments.push(Segment::Paired(p, q));
+                        continue;
+                    }
+
+                    let k = (i + 2..j - 1)
+                        .find(|&k| {
+                            initiation + branch + tables.branches[i + 1][k] + tables.branches[k + 1][j - 1] == energy
+                        })
+                        .expect("The pair closes a multiloop");
+
+                    loops.push((LoopKind::Multiloop, i, j, initiation + branch));
+                    segments.push(Segment::Branches(i + 1, k, loops.len() - 1));
+                    segments.push(Segment::Branches(k + 1, j - 1, loops.len() - 1));
+                }
+                Segment::Branches(i, j, multiloop) => {
+                    let energy = tables.branches[i][j];
+
+                    if tables.paired[i][j] + branch == energy {
+                        loops[multiloop].3 += branch;
+                        segments.push(Segment::Paired(i, j));
+                    } else if tables.branches[i + 1][j] + unpaired == energy {
+                        loops[multiloop].3 += unpaired;
+                        segments.push(Segment::Branches(i + 1, j, multiloop));
+                    } else if tables.branches[i][j - 1] + unpaired == energy {
+                        loops[multiloop].3 += unpaired;
+                        segments.push(Segment::Branches(i, j - 1, multiloop));
+                    } else {
+                        let k = (i + 1..j)
+                            .find(|&k| tables.branches[i][k] + tables.branches[k + 1][j] == energy)
+                            .expect("The branches split somewhere");
+
+                        segments.push(Segment::Branches(i, k, multiloop));
+                        segments.push(Segment::Branches(k + 1, j, multiloop));
+                    }
+                }
+            }
+        }
+
+        loops.sort_by_key(|&(_, opening, ..)| opening);
+
+        let loops = loops
+            .into_iter()
+            .map(|(kind, opening, closing, energy)| Loop {
+                kind: kind,
+                opening: opening,
+                closing: closing,
+                energy: to_kcal_per_mol(energy),
+            })
+            .collect();
+
+        (pairs, loops)
+    }
+}
@@ -0,0 +1,783 @@
+pub use self::nucleobase::Nucleobase;
+pub use self::sequence::{NucleobaseCount, Sequence, StrictCodonIterator};
+pub use self::codon::Codon;
+
+pub mod folding;
+pub mod structure;
+
+pub mod nucleobase {
+    #[cfg(test)]
+    mod tests {
+        use dna::Nucleobase as DnaNucleobase;
+
+        #[test]
+        fn from_dna_nucleobase() {
+            use super::{Nucleobase, Nucleobase::*};
+
+            assert_eq!(Nucleobase::from(DnaNucleobase::Adenine), Adenine);
+            assert_eq!(Nucleobase::from(DnaNucleobase::Thymine), Uracil);
+            assert_eq!(Nucleobase::from(DnaNucleobase::Cytosine), Cytosine);
+            assert_eq!(Nucleobase::from(DnaNucleobase::Guanine), Guanine);
+        }
+
+        #[test]
+        fn complement() {
+            use super::Nucleobase::*;
+
+            assert_eq!(Adenine.complement(), Uracil);
+            assert_eq!(Uracil.complement(), Adenine);
+            assert_eq!(Cytosine.complement(), Guanine);
+            assert_eq!(Guanine.complement(), Cytosine);
+        }
+    }
+
+    use std::{convert, error, fmt};
+    use dna::Nucleobase as DnaNucleobase;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum Nucleobase {
+        Adenine,
+        Uracil,
+        Cytosine,
+        Guanine,
+    }
+
+    impl Nucleobase {
+        /// Returns the complement of the nucleobase: A <=> U and C <=> G.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rosalind::rna::Nucleobase;
+        ///
+        /// assert_eq!(Nucleobase::Adenine.complement(), Nucleobase::Uracil);
+        /// ```
+        pub fn complement(&self) -> Self {
+            use self::Nucleobase::*;
+
+            match self {
+                Adenine => Uracil,
+                Uracil => Adenine,
+                Cytosine => Guanine,
+                Guanine => Cytosine,
+            }
+        }
+    }
+
+    impl fmt::Display for Nucleobase {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            use self::Nucleobase::*;
+
+            match self {
+                Adenine => write!(f, "A"),
+                Uracil => write!(f, "U"),
+                Cytosine => write!(f, "C"),
+                Guanine => write!(f, "G"),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum ParseError {
+        IllegalChar { ch: char },
+    }
+
+    impl error::Error for ParseError {
+        fn description(&self) -> &str {
+            match self {
+                ParseError::IllegalChar { .. } => {
+                    "there is no such RNA nucleobase as represented by this character"
+                }
+            }
+        }
+    }
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ParseError::IllegalChar { ch } => write!(
+                    f,
+                    "there is no such RNA nucleobase as represented by character {}",
+                    ch
+                ),
+            }
+        }
+    }
+
+    impl convert::TryFrom<char> for Nucleobase {
+        type Error = ParseError;
+
+        /// Tries to parse a single char to its corresponding RNA nucleobase.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// match rosalind::rna::Nucleobase::try_from('C') {
+        ///     Ok(nucleobase) => println!("{}", nucleobase),
+        ///     Err(error) => println!("{:?}", error),
+        /// }
+        /// ```
+        fn try_from(ch: char) -> Result<Self, Self::Error> {
+            use self::Nucleobase::{Adenine, Cytosine, Guanine, Uracil};
+
+            let nucleobase = match ch {
+                'A' => Adenine,
+                'U' => Uracil,
+                'C' => Cytosine,
+                'G' => Guanine,
+                _ => {
+                    return Err(ParseError::IllegalChar { ch: ch });
+                }
+            };
+
+            Ok(nucleobase)
+        }
+    }
+
+    impl From<DnaNucleobase> for Nucleobase {
+        /// Converts a DNA nucleobase to its equivalent RNA nucleobase.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rosalind::dna::Nucleobase as DnaNucleobase;
+        /// use rosalind::rna::Nucleobase as RnaNucleobase;
+        ///
+        /// let dna_thymine = DnaNucleobase::Thymine;
+        /// let rna_uracil = RnaNucleobase::from(dna_thymine);
+        /// ```
+        fn from(dna_nucleobase: DnaNucleobase) -> Self {
+            match dna_nucleobase {
+                DnaNucleobase::Adenine => Nucleobase::Adenine,
+                DnaNucleobase::Thymine => Nucleobase::Uracil,
+                DnaNucleobase::Guanine => Nucleobase::Guanine,
+                DnaNucleobase::Cytosine => Nucleobase::Cytosine,
+            }
+        }
+    }
+}
+
+pub mod sequence {
+    #[cfg(test)]
+    mod tests {
+        use super::Sequence;
+        use super::super::{nucleobase, Codon};
+        use std::convert::TryFrom;
+
+        #[test]
+        fn from_dna_sequence() {
+            let dna_sequence = ::dna::Sequence::try_from("AATGGCCAT").unwrap();
+
+            let rna_sequence = Sequence::from(&dna_sequence);
+
+            assert_eq!(rna_sequence.to_string(), "AAUGGCCAU");
+        }
+
+        #[test]
+        fn len_and_index() {
+            use super::Nucleobase::*;
+
+            let sequence = Sequence::try_from("AUGCA").unwrap();
+
+            assert_eq!(sequence.len(), 5);
+            assert_eq!(sequence[1], Uracil);
+            assert_eq!(&sequence[2..4], &[Guanine, Cytosine]);
+            assert!(Sequence::try_from("").unwrap().is_empty());
+        }
+
+        #[test]
+        fn complement_and_reverse_complement() {
+            let sequence = Sequence::try_from("AAUAGGCUA").unwrap();
+
+            assert_eq!(sequence.complement(), Sequence::try_from("UUAUCCGAU").unwrap());
+            assert_eq!(sequence.reverse_complement(), Sequence::try_from("UAGCCUAUU").unwrap());
+        }
+
+        #[test]
+        fn count_nucleobases() {
+            let sequence = Sequence::try_from("AAUAGGCUA").unwrap();
+
+            let expected_count = super::NucleobaseCount {
+                adenines: 4,
+                uracils: 2,
+                cytosines: 1,
+                guanines: 2,
+            };
+
+            assert_eq!(sequence.count_nucleobases(), expected_count);
+        }
+
+        #[test]
+        fn try_from_erroneous_str() {
+            assert_eq!(
+                Sequence::try_from("AUCXCG").unwrap_err(),
+                super::ParseError::NucleobaseError {
+                    index: 3,
+                    error: nucleobase::ParseError::IllegalChar { ch: 'X' },
+                },
+            );
+        }
+
+        #[test]
+        fn try_from_str() {
+            use super::Nucleobase::*;
+
+            let sequence = "AAUGCGA";
+
+            let expected_sequence = Sequence(vec![
+                Adenine, Adenine, Uracil, Guanine, Cytosine, Guanine, Adenine
+            ]);
+
+            assert_eq!(Sequence::try_from(sequence).unwrap(), expected_sequence);
+        }
+
+        #[test]
+        fn strict_codon_iterator() {
+            use super::Nucleobase::*;
+
+            let rna_sequence = Sequence::try_from("AAUGGCCAU").unwrap();
+
+            let expected_codons = vec![
+                Codon([Adenine, Adenine, Uracil]),
+                Codon([Guanine, Guanine, Cytosine]),
+                Codon([Cytosine, Adenine, Uracil]),
+            ];
+
+            let codons = super::StrictCodonIterator::new(&rna_sequence).collect::<Vec<_>>();
+
+            assert_eq!(codons, expected_codons);
+        }
+
+        #[test]
+        fn strict_codon_iterator_skips_remaining_nucleobases_in_non_multiple_of_three_sequence() {
+            use super::Nucleobase::*;
+
+            let rna_sequence = Sequence::try_from("AAUGGCCAUAA").unwrap();
+            //                                              ^^ left over because there is no 3rd nucleobase to form a codon
+
+            let expected_codons = vec![
+                Codon([Adenine, Adenine, Uracil]),
+                Codon([Guanine, Guanine, Cytosine]),
+                Codon([Cytosine, Adenine, Uracil]),
+            ];
+
+            let mut iterator = super::StrictCodonIterator::new(&rna_sequence);
+            let codons = iterator.by_ref().collect::<Vec<_>>();
+
+            assert_eq!(codons, expected_codons);
+            assert_eq!(iterator.remainder(), &[Adenine, Adenine]);
+        }
+
+        #[test]
+        fn strict_codon_iterator_starting_from() {
+            use super::Nucleobase::*;
+
+            let rna_sequence = Sequence::try_from("UAUGGCCAU").unwrap();
+
+            let expected_codons = vec![Codon([Guanine, Guanine, Cytosine]), Codon([Cytosine, Adenine, Uracil])];
+
+            let codons =
+                super::StrictCodonIterator::new_starting_from(&rna_sequence, 3).collect::<Vec<_>>();
+
+            assert_eq!(codons, expected_codons);
+        }
+    }
+
+    use std::{convert, fmt, ops};
+    use super::{Codon, Nucleobase};
+
+    #[derive(Debug, PartialEq)]
+    pub struct Sequence(Vec<Nucleobase>);
+
+    // the u64 type should be more than enough to hold values without risk of overflowing
+    #[derive(Debug, PartialEq)]
+    pub struct NucleobaseCount {
+        pub adenines: u64,
+        pub uracils: u64,
+        pub cytosines: u64,
+        pub guanines: u64,
+    }
+
+    impl Sequence {
+        pub fn new(nucleobases: Vec<Nucleobase>) -> Self {
+            Sequence(nucleobases)
+        }
+
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        /// Returns the complement of the RNA sequence, read in the same direction.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// let sequence = rosalind::rna::Sequence::try_from("AAUAGGCUA").unwrap();
+        ///
+        /// assert_eq!(sequence.complement().to_string(), "UUAUCCGAU");
+        /// ```
+        pub fn complement(&self) -> Self {
+            Sequence(self.0.iter().map(|nucleobase| nucleobase.complement()).collect())
+        }
+
+        /// Returns the reverse complement of the RNA sequence.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// let sequence = rosalind::rna::Sequence::try_from("AAUAGGCUA").unwrap();
+        ///
+        /// assert_eq!(sequence.reverse_complement().to_string(), "UAGCCUAUU");
+        /// ```
+        pub fn reverse_complement(&self) -> Self {
+            Sequence(self.0.iter().rev().map(|nucleobase| nucleobase.complement()).collect())
+        }
+
+        /// Returns the number of each A, U, C, G nucleobase in the sequence.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// let sequence = rosalind::rna::Sequence::try_from("AAUAGGCUA").unwrap();
+        /// let count = sequence.count_nucleobases();
+        ///
+        /// println!(
+        ///     "Adenine: {}\nUracil: {}\nCytosine: {}\nGuanine: {}",
+        ///     count.adenines, count.uracils, count.cytosines, count.guanines
+        /// );
+        /// ```
+        pub fn count_nucleobases(&self) -> NucleobaseCount {
+            use self::Nucleobase::*;
+
+            let mut count = NucleobaseCount {
+                adenines: 0,
+                uracils: 0,
+                cytosines: 0,
+                guanines: 0,
+            };
+
+            for nucleobase in self.0.iter() {
+                match *nucleobase {
+                    Adenine => count.adenines += 1,
+                    Uracil => count.uracils += 1,
+                    Cytosine => count.cytosines += 1,
+                    Guanine => count.guanines += 1,
+                }
+            }
+
+            count
+        }
+    }
+
+    impl fmt::Display for Sequence {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            for nucleobase in &self.0 {
+                write!(f, "{}", nucleobase)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl AsRef<[Nucleobase]> for Sequence {
+        fn as_ref(&self) -> &[Nucleobase] {
+            &self.0
+        }
+    }
+
+    impl ops::Index<usize> for Sequence {
+        type Output = Nucleobase;
+
+        fn index(&self, index: usize) -> &Nucleobase {
+            &self.0[index]
+        }
+    }
+
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// let sequence = rosalind::rna::Sequence::try_from("UUACGGGCAU").unwrap();
+    ///
+    /// let codon = &sequence[0..3];
+    /// println!("{:?}", codon);
+    /// ```
+    impl ops::Index<ops::Range<usize>> for Sequence {
+        type Output = [Nucleobase];
+
+        fn index(&self, index: ops::Range<usize>) -> &[Nucleobase] {
+            &self.0[index]
+        }
+    }
+
+    impl<'a> From<&'a ::dna::Sequence> for Sequence {
+        /// Converts a DNA sequence to a RNA sequence (basically, replaces all thymine nucleobases with uracil).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// let dna_sequence = rosalind::dna::Sequence::try_from("TTACGGGCAT").unwrap();
+        /// let rna_sequence = rosalind::rna::Sequence::from(&dna_sequence);
+        /// ```
+        fn from(dna_sequence: &'a ::dna::Sequence) -> Self {
+            Sequence(
+                dna_sequence
+                    .into_iter()
+                    .map(|dna_nucleobase| Nucleobase::from(dna_nucleobase))
+                    .collect(),
+            )
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum ParseError {
+        NucleobaseError {
+            index: usize,
+            error: super::nucleobase::ParseError,
+        },
+    }
+
+    impl<'a> convert::TryFrom<&'a str> for Sequence {
+        type Error = ParseError;
+
+        /// Tries to parse a &str to a sequence of RNA nucleobases.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// match rosalind::rna::Sequence::try_from("UUACGGGCAU") {
+        ///     Ok(sequence) => println!("{}", sequence),
+        ///     Err(error) => println!("{:?}", error),
+        /// }
+        /// ```
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            let mut sequence = Vec::new();
+
+            for (index, nucleobase_char) in value.chars().enumerate() {
+                let nucleobase = match Nucleobase::try_from(nucleobase_char) {
+                    Ok(nucleobase) => nucleobase,
+                    Err(error) => {
+                        return Err(ParseError::NucleobaseError {
+                            index: index,
+                            error: error,
+                        });
+                    }
+                };
+
+                sequence.push(nucleobase);
+            }
+
+            Ok(Sequence(sequence))
+        }
+    }
+
+    /// Cloning iterator over a RNA sequence's nucleobases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// let sequence = rosalind::rna::Sequence::try_from("AAUUAGCCG").unwrap();
+    ///
+    /// for nucleobase in &sequence {
+    ///     println!("{}", nucleobase);
+    /// }
+    /// ```
+    impl<'a> IntoIterator for &'a Sequence {
+        type Item = Nucleobase;
+        type IntoIter = ::std::iter::Cloned<::std::slice::Iter<'a, Nucleobase>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.iter().cloned()
+        }
+    }
+
+    /// Iterates over a RNA sequence, grouping nucleobases by codon (a codon is a group of 3 nucleobases).
+    /// If the length of the RNA sequence is not a multiple of 3, the remaining nucleobases are not yielded (hence the name _Strict_CodonIterator),
+    /// but `remainder` returns them once the iteration is over.
+    pub struct StrictCodonIterator<'a> {
+        rna: &'a [Nucleobase],
+        index: usize,
+    }
+
+    impl<'a> StrictCodonIterator<'a> {
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use rosalind::rna::{Sequence, Nucleobase, sequence::StrictCodonIterator};
+        ///
+        /// let rna_sequence = Sequence::try_from("UUACGGGCAU").unwrap();
+        ///
+        /// let codons = StrictCodonIterator::new(&rna_sequence).collect::<Vec<_>>();
+        /// ```
+        pub fn new(rna_sequence: &'a Sequence) -> Self {
+            StrictCodonIterator {
+                rna: &rna_sequence.0,
+                index: 0,
+            }
+        }
+
+        /// Starts from a given position within the `rna_sequence`, skipping all nucleobases that come before `start_index`.
+        /// It is safe to use even if `start_index` >= the length of the `rna_sequence` (in that case it will yield None).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use rosalind::rna::{Sequence, Nucleobase, sequence::StrictCodonIterator};
+        ///
+        /// let rna_sequence = Sequence::try_from("UUACGGGCAU").unwrap();
+        ///
+        /// let codons = StrictCodonIterator::new_starting_from(&rna_sequence, 4).collect::<Vec<_>>();
+        /// ```
+        pub fn new_starting_from(rna_sequence: &'a Sequence, start_index: usize) -> Self {
+            StrictCodonIterator {
+                rna: &rna_sequence.0,
+                index: start_index,
+            }
+        }
+
+        /// Returns the nucleobases not consumed yet: after the last codon, those which don't make a full codon.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use rosalind::rna::{Sequence, sequence::StrictCodonIterator};
+        ///
+        /// let rna_sequence = Sequence::try_from("UUACGGGCAU").unwrap();
+        ///
+        /// let mut codons = StrictCodonIterator::new(&rna_sequence);
+        ///
+        /// assert_eq!(codons.by_ref().count(), 3);
+        /// assert_eq!(codons.remainder().len(), 1);
+        /// ```
+        pub fn remainder(&self) -> &'a [Nucleobase] {
+            if self.index >= self.rna.len() {
+                &[]
+            } else {
+                &self.rna[self.index..]
+            }
+        }
+    }
+
+    impl<'a> Iterator for StrictCodonIterator<'a> {
+        type Item = Codon;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.index + 3 > self.rna.len() {
+                None
+            } else {
+                self.index += 3;
+
+                let codon = &self.rna[self.index - 3..self.index];
+
+                Some(Codon([codon[0], codon[1], codon[2]]))
+            }
+        }
+    }
+}
+
+pub mod codon {
+    #[cfg(test)]
+    mod tests {
+        use std::convert::TryFrom;
+        use genetic_code::GeneticCode;
+        use super::{Codon, ParseError};
+        use super::super::Nucleobase::*;
+
+        #[test]
+        fn try_from_str() {
+            assert_eq!(Codon::try_from("AUG").unwrap(), Codon([Adenine, Uracil, Guanine]));
+            assert_eq!(Codon::try_from("AU"), Err(ParseError::InvalidLength { length: 2 }));
+            assert_eq!(Codon::try_from("AUGC"), Err(ParseError::InvalidLength { length: 4 }));
+
+            match Codon::try_from("AXG") {
+                Err(ParseError::NucleobaseError { index: 1, .. }) => {}
+                _ => panic!("AXG isn't a codon"),
+            }
+        }
+
+        #[test]
+        fn index() {
+            // the TCAG order of the NCBI tables
+            assert_eq!(Codon::try_from("UUU").unwrap().index(), 0);
+            assert_eq!(Codon::try_from("UUC").unwrap().index(), 1);
+            assert_eq!(Codon::try_from("UCU").unwrap().index(), 4);
+            assert_eq!(Codon::try_from("GGG").unwrap().index(), 63);
+
+            for index in 0..64 {
+                assert_eq!(Codon::from_index(index).index(), index);
+            }
+        }
+
+        #[test]
+        fn all() {
+            let codons: Vec<Codon> = Codon::all().collect();
+
+            assert_eq!(codons.len(), 64);
+            assert_eq!(codons[0].to_string(), "UUU");
+            assert_eq!(codons[63].to_string(), "GGG");
+        }
+
+        #[test]
+        fn is_synonymous_with() {
+            let standard = GeneticCode::standard();
+            let codon = |string| Codon::try_from(string).unwrap();
+
+            assert!(codon("CUU").is_synonymous_with(codon("UUA"), &standard));
+            assert!(codon("UAA").is_synonymous_with(codon("UGA"), &standard));
+            assert!(!codon("AUG").is_synonymous_with(codon("AUA"), &standard));
+            assert!(codon("AUG").is_synonymous_with(codon("AUA"), &GeneticCode::ncbi(2).unwrap()));
+        }
+    }
+
+    use std::{convert, error, fmt};
+    use genetic_code::GeneticCode;
+    use super::Nucleobase;
+
+    // U, C, A, G like in the NCBI tables
+    const TCAG: [Nucleobase; 4] = [
+        Nucleobase::Uracil,
+        Nucleobase::Cytosine,
+        Nucleobase::Adenine,
+        Nucleobase::Guanine,
+    ];
+
+    /// Group of 3 nucleobases, which a genetic code translates to an amino acid.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct Codon(pub [Nucleobase; 3]);
+
+    impl Codon {
+        /// Returns the codon with this index in the TCAG order (UUU, UUC, UUA, UUG, UCU, ..., GGG).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` >= 64.
+        pub fn from_index(index: usize) -> Self {
+            assert!(index < 64, "There are only 64 codons");
+
+            Codon([TCAG[index / 16], TCAG[index / 4 % 4], TCAG[index % 4]])
+        }
+
+        /// Returns the index of the codon in the TCAG order, in 0..64.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        /// use rosalind::rna::Codon;
+        ///
+        /// let codon = Codon::try_from("AUG").unwrap();
+        ///
+        /// assert_eq!(Codon::from_index(codon.index()), codon);
+        /// ```
+        pub fn index(&self) -> usize {
+            self.0.iter().fold(0, |index, nucleobase| 4 * index + tcag_index(*nucleobase))
+        }
+
+        /// Iterates over the 64 codons in the TCAG order.
+        pub fn all() -> impl Iterator<Item = Codon> {
+            (0..64).map(Codon::from_index)
+        }
+
+        /// Returns whether `self` and `other` code for the same amino acid in `genetic_code`.
+        /// Stop codons are synonymous with each other.
+        pub fn is_synonymous_with(&self, other: Codon, genetic_code: &GeneticCode) -> bool {
+            genetic_code.amino_acid(*self) == genetic_code.amino_acid(other)
+        }
+    }
+
+    fn tcag_index(nucleobase: Nucleobase) -> usize {
+        match nucleobase {
+            Nucleobase::Uracil => 0,
+            Nucleobase::Cytosine => 1,
+            Nucleobase::Adenine => 2,
+            Nucleobase::Guanine => 3,
+        }
+    }
+
+    impl fmt::Display for Codon {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}{}{}", self.0[0], self.0[1], self.0[2])
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum ParseError {
+        InvalidLength { length: usize },
+        NucleobaseError {
+            index: usize,
+            error: super::nucleobase::ParseError,
+        },
+    }
+
+    impl error::Error for ParseError {
+        fn description(&self) -> &str {
+            match self {
+                ParseError::InvalidLength { .. } => "a codon is 3-nucleobase long",
+                ParseError::NucleobaseError { .. } => "illegal nucleobase",
+            }
+        }
+    }
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ParseError::InvalidLength { length } => {
+                    write!(f, "a codon is 3-nucleobase long, not {}", length)
+                }
+                ParseError::NucleobaseError { index, error } => write!(f, "at index {}: {}", index, error),
+            }
+        }
+    }
+
+    impl<'a> convert::TryFrom<&'a str> for Codon {
+        type Error = ParseError;
+
+        /// Tries to parse a 3-character &str to a codon.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::convert::TryFrom;
+        ///
+        /// let codon = rosalind::rna::Codon::try_from("UGA").unwrap();
+        /// ```
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            let length = value.chars().count();
+
+            if length != 3 {
+                return Err(ParseError::InvalidLength { length: length });
+            }
+
+            let mut nucleobases = [Nucleobase::Adenine; 3];
+
+            for (index, ch) in value.chars().enumerate() {
+                nucleobases[index] = Nucleobase::try_from(ch).map_err(|error| ParseError::NucleobaseError {
+                    index: index,
+                    error: error,
+                })?;
+            }
+
+            Ok(Codon(nucleobases))
+        }
+    }
+}
@@ -0,0 +1,412 @@
+// RNA secondary structure: the base pairs a single strand makes with itself, without pseudoknots
+//
+// structures are written in the dot-bracket notation, ( and ) for the nucleobases of a pair and . for the unpaired ones:
+//
+//     GGGAAAUCC
+//     (((...)))
+//
+// the Nussinov algorithm predicts the structure with the most pairs: N(i, j), the most pairs between i and j,
+// is either N(i + 1, j) with i unpaired, or N(i + 1, k - 1) + 1 + N(k + 1, j) with i paired with some k
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use rna::Sequence;
+    use super::{Nussinov, Pair, PairKind, Structure, StructureError};
+
+    fn rna(string: &str) -> Sequence {
+        Sequence::try_from(string).unwrap()
+    }
+
+    #[test]
+    fn pair_kind() {
+        use rna::Nucleobase::*;
+
+        assert_eq!(super::pair_kind(Guanine, Cytosine), Some(PairKind::WatsonCrick));
+        assert_eq!(super::pair_kind(Uracil, Adenine), Some(PairKind::WatsonCrick));
+        assert_eq!(super::pair_kind(Guanine, Uracil), Some(PairKind::Wobble));
+        assert_eq!(super::pair_kind(Adenine, Cytosine), None);
+        assert_eq!(super::pair_kind(Guanine, Guanine), None);
+    }
+
+    #[test]
+    fn nussinov() {
+        let sequence = rna("GGGAAAUCC");
+
+        let structure = Nussinov::new().fold(&sequence);
+
+        // as many pairs as ((.....)), but the first G is left unpaired
+        assert_eq!(structure.to_string(), ".((....))");
+        assert_eq!(
+            structure.pairs(),
+            &[
+                Pair {
+                    opening: 1,
+                    closing: 8,
+                    kind: PairKind::WatsonCrick,
+                },
+                Pair {
+                    opening: 2,
+                    closing: 7,
+                    kind: PairKind::WatsonCrick,
+                },
+            ]
+        );
+
+        let structure = Nussinov::new().wobble_pairs(true).fold(&sequence);
+
+        assert_eq!(structure.to_string(), "(((...)))");
+        assert_eq!(structure.pairs()[2].kind, PairKind::Wobble);
+    }
+
+    #[test]
+    fn nussinov_min_hairpin_loop() {
+        assert_eq!(Nussinov::new().fold(&rna("GAC")).to_string(), "...");
+        assert_eq!(Nussinov::new().min_hairpin_loop(1).fold(&rna("GAC")).to_string(), "(.)");
+        assert_eq!(Nussinov::new().fold(&rna("")).to_string(), "");
+    }
+
+    #[test]
+    fn nussinov_two_hairpins() {
+        let sequence = rna("GGGAAACCCAGGGAAACCCU");
+
+        let structure = Nussinov::new().fold(&sequence);
+
+        assert_eq!(structure.to_string(), "(((...)))((((...))))");
+        assert_eq!(Structure::from_dot_bracket(&sequence, &structure.to_string()), Ok(structure));
+    }
+
+    #[test]
+    fn from_dot_bracket() {
+        let sequence = rna("GGAAAUC");
+
+        let structure = Structure::from_dot_bracket(&sequence, "((...))").unwrap();
+
+        assert_eq!(structure.len(), 7);
+        assert_eq!(structure.partner(0), Some(6));
+        assert_eq!(structure.partner(5), Some(1));
+        assert_eq!(structure.partner(3), None);
+        assert_eq!(structure.pairs()[1].kind, PairKind::Wobble);
+
+        assert_eq!(
+            Structure::from_dot_bracket(&sequence, "((..)"),
+            Err(StructureError::LengthMismatch { sequence: 7, structure: 5 })
+        );
+        assert_eq!(
+            Structure::from_dot_bracket(&sequence, "((...)."),
+            Err(StructureError::UnbalancedBracket { index: 0 })
+        );
+        assert_eq!(
+            Structure::from_dot_bracket(&sequence, "(....))"),
+            Err(StructureError::UnbalancedBracket { index: 6 })
+        );
+        assert_eq!(
+            Structure::from_dot_bracket(&sequence, "(....x)"),
+            Err(StructureError::IllegalChar { ch: 'x', index: 5 })
+        );
+        assert_eq!(
+            Structure::from_dot_bracket(&sequence, "..(.).."),
+            Err(StructureError::InvalidPair { opening: 2, closing: 4 })
+        );
+    }
+}
+
+use std::{error, fmt};
+use super::{Nucleobase, Sequence};
+
+/// Kind of a base pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PairKind {
+    /// A-U or G-C.
+    WatsonCrick,
+    /// G-U.
+    Wobble,
+}
+
+/// Returns the kind of pair `a` and `b` make, None if they don't pair.
+pub fn pair_kind(a: Nucleobase, b: Nucleobase) -> Option<PairKind> {
+    use super::Nucleobase::*;
+
+    match (a, b) {
+        (Adenine, Uracil) | (Uracil, Adenine) | (Guanine, Cytosine) | (Cytosine, Guanine) => {
+            Some(PairKind::WatsonCrick)
+        }
+        (Guanine, Uracil) | (Uracil, Guanine) => Some(PairKind::Wobble),
+        _ => None,
+    }
+}
+
+/// Base pair between the nucleobases at `opening` and `closing` (`opening` < `closing`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pair {
+    pub opening: usize,
+    pub closing: usize,
+    pub kind: PairKind,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum StructureError {
+    LengthMismatch { sequence: usize, structure: usize },
+    /// The bracket at `index` has no match.
+    UnbalancedBracket { index: usize },
+    IllegalChar { ch: char, index: usize },
+    /// The nucleobases at `opening` and `closing` can't pair.
+    InvalidPair { opening: usize, closing: usize },
+}
+
+impl error::Error for StructureError {
+    fn description(&self) -> &str {
+        match self {
+            StructureError::LengthMismatch { .. } => "the structure and the sequence don't have the same length",
+            StructureError::UnbalancedBracket { .. } => "a bracket has no match",
+            StructureError::IllegalChar { .. } => "illegal character",
+            StructureError::InvalidPair { .. } => "two nucleobases which can't pair are paired",
+        }
+    }
+}
+
+impl fmt::Display for StructureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StructureError::LengthMismatch { sequence, structure } => write!(
+                f,
+                "the structure is {}-nucleobase long, the sequence {}-nucleobase long",
+                structure, sequence
+            ),
+            StructureError::UnbalancedBracket { index } => write!(f, "the bracket at index {} has no match", index),
+            StructureError::IllegalChar { ch, index } => write!(f, "illegal character {} at index {}", ch, index),
+            StructureError::InvalidPair { opening, closing } => write!(
+                f,
+                "the nucleobases at indexes {} and {} can't pair",
+                opening, closing
+            ),
+        }
+    }
+}
+
+/// Secondary structure of a RNA sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Structure {
+    length: usize,
+    // sorted by opening nucleobase
+    pairs: Vec<Pair>,
+}
+
+impl Structure {
+    /// Reads the dot-bracket notation of a structure of `sequence`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::rna::Sequence;
+    /// use rosalind::rna::structure::Structure;
+    ///
+    /// let sequence = Sequence::try_from("GGGAAAUCC").unwrap();
+    ///
+    /// let structure = Structure::from_dot_bracket(&sequence, "(((...)))").unwrap();
+    ///
+    /// assert_eq!(structure.pairs().len(), 3);
+    /// ```
+    pub fn from_dot_bracket(sequence: &Sequence, dot_bracket: &str) -> Result<Self, StructureError> {
+        let length = dot_bracket.chars().count();
+
+        if length != sequence.len() {
+            return Err(StructureError::LengthMismatch {
+                sequence: sequence.len(),
+                structure: length,
+            });
+        }
+
+        let mut opened = Vec::new();
+        let mut pairs = Vec::new();
+
+        for (index, ch) in dot_bracket.chars().enumerate() {
+            match ch {
+                '.' => {}
+                '(' => opened.push(index),
+                ')' => {
+                    let opening = opened.pop().ok_or(StructureError::UnbalancedBracket { index: index })?;
+                    let kind = pair_kind(sequence[opening], sequence[index]).ok_or(StructureError::InvalidPair {
+                        opening: opening,
+                        closing: index,
+                    })?;
+
+                    pairs.push(Pair {
+                        opening: opening,
+                        closing: index,
+                        kind: kind,
+                    });
+                }
+                _ => return Err(StructureError::IllegalChar { ch: ch, index: index }),
+            }
+        }
+
+        if let Some(&index) = opened.first() {
+            return Err(StructureError::UnbalancedBracket { index: index });
+        }
+
+        Ok(Structure::new(length, pairs))
+    }
+
+    // sorts the pairs
+    pub(crate) fn new(length: usize, mut pairs: Vec<Pair>) -> Self {
+        pairs.sort_by_key(|pair| pair.opening);
+
+        Structure {
+            length: length,
+            pairs: pairs,
+        }
+    }
+
+    /// Returns the length of the sequence.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the pairs, by increasing opening nucleobase.
+    pub fn pairs(&self) -> &[Pair] {
+        &self.pairs
+    }
+
+    /// Returns the index of the nucleobase paired with the one at `index`, if any.
+    pub fn partner(&self, index: usize) -> Option<usize> {
+        self.pairs.iter().find_map(|pair| {
+            if pair.opening == index {
+                Some(pair.closing)
+            } else if pair.closing == index {
+                Some(pair.opening)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl fmt::Display for Structure {
+    /// Writes the structure in the dot-bracket notation.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut dot_bracket = vec!['.'; self.length];
+
+        for pair in &self.pairs {
+            dot_bracket[pair.opening] = '(';
+            dot_bracket[pair.closing] = ')';
+        }
+
+        write!(f, "{}", dot_bracket.into_iter().collect::<String>())
+    }
+}
+
+/// Predicts secondary structures by maximizing the number of base pairs (Nussinov algorithm).
+/// By default, only Watson-Crick pairs are made and hairpin loops have at least 3 nucleobases.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::rna::Sequence;
+/// use rosalind::rna::structure::Nussinov;
+///
+/// let sequence = Sequence::try_from("GGGAAAUCC").unwrap();
+///
+/// let structure = Nussinov::new().wobble_pairs(true).fold(&sequence);
+///
+/// assert_eq!(structure.to_string(), "(((...)))");
+/// ```
+pub struct Nussinov {
+    wobble_pairs: bool,
+    min_hairpin_loop: usize,
+}
+
+impl Default for Nussinov {
+    fn default() -> Self {
+        Nussinov::new()
+    }
+}
+
+impl Nussinov {
+    pub fn new() -> Self {
+        Nussinov {
+            wobble_pairs: false,
+            min_hairpin_loop: 3,
+        }
+    }
+
+    /// Also pairs G with U.
+    pub fn wobble_pairs(self, wobble_pairs: bool) -> Self {
+        Nussinov {
+            wobble_pairs: wobble_pairs,
+            ..self
+        }
+    }
+
+    /// Leaves at least `min_hairpin_loop` unpaired nucleobases between the nucleobases of a pair.
+    pub fn min_hairpin_loop(self, min_hairpin_loop: usize) -> Self {
+        Nussinov {
+            min_hairpin_loop: min_hairpin_loop,
+            ..self
+        }
+    }
+
+    /// Returns a structure of `sequence` with as many pairs as possible.
+    /// Among equally good structures, the 5' nucleobases are rather left unpaired.
+    pub fn fold(&self, sequence: &Sequence) -> Structure {
+        let n = sequence.len();
+        // pairs[i][j]: the most pairs between i and j - 1, so that empty ranges need no special case
+        let mut pairs = vec![vec![0usize; n + 1]; n + 1];
+
+        for i in (0..n).rev() {
+            for j in i + 1..n + 1 {
+                let mut best = pairs[i + 1][j];
+
+                for k in i + 1 + self.min_hairpin_loop..j {
+                    if self.can_pair(sequence, i, k) {
+                        best = best.max(pairs[i + 1][k] + 1 + pairs[k + 1][j]);
+                    }
+                }
+
+                pairs[i][j] = best;
+            }
+        }
+
+        let mut found = Vec::with_capacity(pairs[0][n]);
+        let mut ranges = vec![(0, n)];
+
+        while let Some((i, j)) = ranges.pop() {
+            if i >= j || pairs[i][j] == 0 {
+                continue;
+            }
+
+            if pairs[i][j] == pairs[i + 1][j] {
+                ranges.push((i + 1, j));
+                continue;
+            }
+
+            let k = (i + 1 + self.min_hairpin_loop..j)
+                .find(|&k| self.can_pair(sequence, i, k) && pairs[i][j] == pairs[i + 1][k] + 1 + pairs[k + 1][j])
+                .expect("i is paired in the best structure");
+
+            found.push(Pair {
+                opening: i,
+                closing: k,
+                kind: pair_kind(sequence[i], sequence[k]).expect("i and k can pair"),
+            });
+            ranges.push((i + 1, k));
+            ranges.push((k + 1, j));
+        }
+
+        Structure::new(n, found)
+    }
+
+    fn can_pair(&self, sequence: &Sequence, i: usize, j: usize) -> bool {
+        match pair_kind(sequence[i], sequence[j]) {
+            Some(PairKind::WatsonCrick) => true,
+            Some(PairKind::Wobble) => self.wobble_pairs,
+            None => false,
+        }
+    }
+}
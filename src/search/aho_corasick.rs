@@ -0,0 +1,342 @@
+// Aho-Corasick automaton: finds all the occurrences of many patterns in a single pass over the text
+//
+// the automaton is a trie of the patterns where every node also knows where to go for every nucleobase
+// when the trie has no such edge (the node of the longest suffix of the current text which is in the trie),
+// so scanning the text is just following one transition per nucleobase, whatever the number of patterns
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::{Sequence as DnaSequence, Topology};
+    use super::{AhoCorasick, Match, Strand};
+
+    fn dna_sequences(strings: &[&str]) -> Vec<DnaSequence> {
+        strings
+            .iter()
+            .map(|string| DnaSequence::try_from(*string).unwrap())
+            .collect()
+    }
+
+    fn forward(pattern: usize, position: usize) -> Match {
+        Match {
+            pattern: pattern,
+            position: position,
+            strand: Strand::Forward,
+        }
+    }
+
+    fn reverse(pattern: usize, position: usize) -> Match {
+        Match {
+            pattern: pattern,
+            position: position,
+            strand: Strand::Reverse,
+        }
+    }
+
+    #[test]
+    fn find_all() {
+        let patterns = dna_sequences(&["ATAT", "TAT", "GCA"]);
+        let automaton = AhoCorasick::new(&patterns);
+
+        let text = DnaSequence::try_from("GATATATGCATATACTT").unwrap();
+
+        assert_eq!(
+            automaton.find_all(&text),
+            vec![
+                forward(0, 1),
+                forward(1, 2),
+                forward(0, 3),
+                forward(1, 4),
+                forward(2, 7),
+                forward(0, 9),
+                forward(1, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_all_with_nested_patterns() {
+        let patterns = dna_sequences(&["A", "AA", "AAA"]);
+        let automaton = AhoCorasick::new(&patterns);
+
+        let text = DnaSequence::try_from("CAAAC").unwrap();
+
+        assert_eq!(
+            automaton.find_all(&text),
+            vec![
+                forward(0, 1),
+                forward(1, 1),
+                forward(2, 1),
+                forward(0, 2),
+                forward(1, 2),
+                forward(0, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_all_both_strands() {
+        // GGAC is the reverse complement of GTCC
+        let patterns = dna_sequences(&["GTCC", "GAATTC"]);
+        let automaton = AhoCorasick::new(&patterns);
+
+        let text = DnaSequence::try_from("AGTCCAGGACTGAATTCA").unwrap();
+
+        assert_eq!(
+            automaton.find_all_both_strands(&text),
+            vec![
+                forward(0, 1),
+                reverse(0, 6),
+                forward(1, 11),
+                // EcoRI's site is a palindrome, so it is found on both strands
+                reverse(1, 11),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_all_in_circular_text() {
+        let patterns = dna_sequences(&["GTCC"]);
+        let automaton = AhoCorasick::new(&patterns);
+
+        let text = DnaSequence::try_from("CCAGGACTAAGT").unwrap();
+
+        assert_eq!(automaton.find_all_both_strands(&text), vec![reverse(0, 3)]);
+
+        let text = text.with_topology(Topology::Circular);
+
+        assert_eq!(automaton.find_all(&text), vec![forward(0, 10)]);
+        assert_eq!(automaton.find_all_both_strands(&text), vec![reverse(0, 3), forward(0, 10)]);
+    }
+
+    #[test]
+    fn empty_patterns_never_match() {
+        let patterns = dna_sequences(&["", "C"]);
+        let automaton = AhoCorasick::new(&patterns);
+
+        let text = DnaSequence::try_from("ACA").unwrap();
+
+        assert_eq!(automaton.find_all(&text), vec![forward(1, 1)]);
+    }
+
+    #[test]
+    fn find_all_matches_naive_search() {
+        let patterns = dna_sequences(&["ACG", "CGT", "GTA", "TACG", "ACGTACGT", "CC", "C"]);
+        let automaton = AhoCorasick::new(&patterns);
+
+        let text = DnaSequence::try_from("ACGTACGTTACGCCACGTACGTACCCGTA").unwrap();
+        let text_nucleobases = text.as_ref();
+
+        let mut expected = Vec::new();
+
+        for position in 0..text.len() {
+            for (index, pattern) in patterns.iter().enumerate() {
+                let pattern = pattern.as_ref();
+
+                if text_nucleobases[position..].starts_with(pattern) {
+                    expected.push(forward(index, position));
+                }
+            }
+        }
+
+        assert_eq!(automaton.find_all(&text), expected);
+    }
+}
+
+use std::collections::VecDeque;
+use alignment::Symbol;
+use dna::{Nucleobase, Sequence as DnaSequence};
+
+pub use super::Strand;
+use super::unroll;
+
+const ROOT: usize = 0;
+
+/// Occurrence of a pattern in a text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// Index of the pattern, in the order they were given to `AhoCorasick::new`.
+    pub pattern: usize,
+    /// 0-based position of the leftmost nucleobase of the occurrence, on the forward strand.
+    pub position: usize,
+    pub strand: Strand,
+}
+
+/// Automaton to search many DNA patterns at once.
+pub struct AhoCorasick {
+    // transitions[node][nucleobase index]
+    transitions: Vec<[usize; 4]>,
+    // patterns ending at each node, including the ones ending at its suffixes
+    outputs: Vec<Vec<usize>>,
+    pattern_lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Builds the automaton of `patterns`, in O(total length of the patterns).
+    /// Empty patterns are accepted but never match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::search::aho_corasick::AhoCorasick;
+    ///
+    /// let adapters = vec![
+    ///     Sequence::try_from("AGATCGGAAGAGC").unwrap(),
+    ///     Sequence::try_from("CTGTCTCTTATA").unwrap(),
+    /// ];
+    ///
+    /// let automaton = AhoCorasick::new(&adapters);
+    ///
+    /// let read = Sequence::try_from("TTGACAGATCGGAAGAGCACACG").unwrap();
+    ///
+    /// for found in automaton.find_all(&read) {
+    ///     println!("adapter {} at {}", found.pattern, found.position);
+    /// }
+    /// ```
+    pub fn new<Q: AsRef<[Nucleobase]>>(patterns: &[Q]) -> Self {
+        // the trie, where 0 means "no edge" as the root can never be the target of an edge
+        let mut transitions = vec![[ROOT; 4]];
+        let mut outputs = vec![Vec::new()];
+
+        for (index, pattern) in patterns.iter().enumerate() {
+            let pattern = pattern.as_ref();
+
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut node = ROOT;
+
+            for nucleobase in pattern {
+                let symbol = nucleobase.index();
+
+                if transitions[node][symbol] == ROOT {
+                    transitions.push([ROOT; 4]);
+                    outputs.push(Vec::new());
+                    transitions[node][symbol] = transitions.len() - 1;
+                }
+
+                node = transitions[node][symbol];
+            }
+
+            outputs[node].push(index);
+        }
+
+        // breadth-first, so that the suffix link of a node is always computed before the node itself:
+        // the missing edges of a node are the edges of its suffix link
+        let mut suffix_links = vec![ROOT; transitions.len()];
+        let mut queue: VecDeque<usize> = transitions[ROOT]
+            .iter()
+            .cloned()
+            .filter(|&child| child != ROOT)
+            .collect();
+
+        while let Some(node) = queue.pop_front() {
+            let suffix_link_outputs = outputs[suffix_links[node]].clone();
+            outputs[node].extend(suffix_link_outputs);
+
+            for symbol in 0..4 {
+                let child = transitions[node][symbol];
+                let fallback = transitions[suffix_links[node]][symbol];
+
+                if child == ROOT {
+                    transitions[node][symbol] = fallback;
+                } else {
+                    suffix_links[child] = fallback;
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        AhoCorasick {
+            transitions: transitions,
+            outputs: outputs,
+            pattern_lengths: patterns.iter().map(|pattern| pattern.as_ref().len()).collect(),
+        }
+    }
+
+    /// Returns the number of patterns of the automaton.
+    pub fn number_of_patterns(&self) -> usize {
+        self.pattern_lengths.len()
+    }
+
+    /// Returns every occurrence of every pattern in `text`, overlapping ones included,
+    /// sorted by position and then by pattern index.
+    /// In a circular text, the occurrences spanning the origin are included.
+    pub fn find_all(&self, text: &DnaSequence) -> Vec<Match> {
+        match unroll(text, self.longest_pattern_length()) {
+            Some(unrolled) => {
+                let mut matches = self.find_all_in_strand(&unrolled, Strand::Forward);
+
+                matches.retain(|found| found.position < text.len());
+
+                matches
+            }
+            None => self.find_all_in_strand(text, Strand::Forward),
+        }
+    }
+
+    /// Same as `find_all`, also searching the reverse complement of `text`.
+    /// Positions of the matches on the reverse strand are still given on the forward strand,
+    /// so a match of pattern P at position i on the reverse strand means that the reverse complement of P is at i.
+    pub fn find_all_both_strands(&self, text: &DnaSequence) -> Vec<Match> {
+        match unroll(text, self.longest_pattern_length()) {
+            Some(unrolled) => {
+                let mut matches = self.find_all_in_both_strands(&unrolled);
+
+                matches.retain(|found| found.position < text.len());
+
+                matches
+            }
+            None => self.find_all_in_both_strands(text),
+        }
+    }
+
+    fn longest_pattern_length(&self) -> usize {
+        self.pattern_lengths.iter().cloned().max().unwrap_or(0)
+    }
+
+    // text is read as if it were linear
+    fn find_all_in_both_strands(&self, text: &DnaSequence) -> Vec<Match> {
+        let mut matches = self.find_all_in_strand(text, Strand::Forward);
+
+        let reverse_complement = text.reverse_complement();
+
+        for reverse_match in self.find_all_in_strand(&reverse_complement, Strand::Reverse) {
+            let length = self.pattern_lengths[reverse_match.pattern];
+
+            matches.push(Match {
+                position: text.len() - reverse_match.position - length,
+                ..reverse_match
+            });
+        }
+
+        matches.sort_by_key(|found| (found.position, found.strand, found.pattern));
+
+        matches
+    }
+
+    // text is read as if it were linear
+    fn find_all_in_strand(&self, text: &DnaSequence, strand: Strand) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut node = ROOT;
+
+        for (end, nucleobase) in text.as_ref().iter().enumerate() {
+            node = self.transitions[node][nucleobase.index()];
+
+            for &pattern in &self.outputs[node] {
+                matches.push(Match {
+                    pattern: pattern,
+                    position: end + 1 - self.pattern_lengths[pattern],
+                    strand: strand,
+                });
+            }
+        }
+
+        matches.sort_by_key(|found| (found.position, found.pattern));
+
+        matches
+    }
+}
@@ -0,0 +1,451 @@
+// approximate pattern matching: occurrences of a pattern with at most d mismatches (Hamming distance)
+//
+// if the pattern is split into d + 1 pieces, an occurrence with at most d mismatches contains at least one
+// of the pieces without any mismatch (pigeonhole principle): the pieces are searched all at once with
+// an Aho-Corasick automaton, and only the windows around their occurrences are compared with the pattern
+//
+// the most frequent k-mers with mismatches are counted from the d-neighborhood of every k-mer of the text,
+// rather than by trying all the 4^k possible k-mers against the whole text
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::{Sequence as DnaSequence, Topology};
+    use random::SplitMix64;
+    use search::Strand;
+    use super::ApproximateMatch;
+
+    fn forward(position: usize, mismatches: usize) -> ApproximateMatch {
+        ApproximateMatch {
+            position: position,
+            strand: Strand::Forward,
+            mismatches: mismatches,
+        }
+    }
+
+    fn strings(kmers: &[DnaSequence]) -> Vec<String> {
+        kmers.iter().map(|kmer| kmer.to_string()).collect()
+    }
+
+    #[test]
+    fn find_all() {
+        let text = DnaSequence::try_from(
+            "CGCCCGAATCCAGAACGCATTCCCATATTTCGGGACCACTGGCCTCCACGGTACGGACGTCAATCAAATGCCTAGCGGCTTGTGGTTTCTCCTACGCTCC",
+        ).unwrap();
+        let pattern = DnaSequence::try_from("ATTCTGGA").unwrap();
+
+        let positions: Vec<usize> = super::find_all(&text, &pattern, 3)
+            .iter()
+            .map(|found| found.position)
+            .collect();
+
+        assert_eq!(positions, vec![6, 7, 26, 27, 78]);
+    }
+
+    #[test]
+    fn find_all_both_strands() {
+        // TTCCA is the reverse complement of TGGAA
+        let text = DnaSequence::try_from("TGGAACCCTTCGA").unwrap();
+        let pattern = DnaSequence::try_from("TGGAA").unwrap();
+
+        assert_eq!(
+            super::find_all_both_strands(&text, &pattern, 1),
+            vec![
+                forward(0, 0),
+                ApproximateMatch {
+                    position: 8,
+                    strand: Strand::Reverse,
+                    mismatches: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn find_all_in_circular_text() {
+        let text = DnaSequence::try_from("GAACCCTTCGATG").unwrap();
+        let pattern = DnaSequence::try_from("TGGAA").unwrap();
+
+        assert!(super::find_all(&text, &pattern, 0).is_empty());
+
+        let text = text.with_topology(Topology::Circular);
+
+        assert_eq!(super::find_all(&text, &pattern, 0), vec![forward(11, 0)]);
+    }
+
+    #[test]
+    fn more_mismatches_than_pattern_length() {
+        let text = DnaSequence::try_from("ACGT").unwrap();
+        let pattern = DnaSequence::try_from("GG").unwrap();
+
+        assert_eq!(
+            super::find_all(&text, &pattern, 2),
+            vec![forward(0, 2), forward(1, 1), forward(2, 1)]
+        );
+    }
+
+    #[test]
+    fn find_all_matches_naive_search() {
+        let mut rng = SplitMix64::new(3);
+
+        let mut random_sequence = |length: usize| {
+            let string: String = (0..length).map(|_| "ACGT".as_bytes()[rng.below(4)] as char).collect();
+            DnaSequence::try_from(string.as_str()).unwrap()
+        };
+
+        let text = random_sequence(500);
+
+        for &(pattern_length, max_mismatches) in &[(6, 0), (6, 1), (9, 2), (12, 3), (4, 3)] {
+            let pattern = random_sequence(pattern_length);
+            let reverse_complement = pattern.reverse_complement();
+
+            let mut expected = Vec::new();
+
+            for position in 0..text.len() - pattern_length + 1 {
+                let window = &text[position..position + pattern_length];
+
+                for &(strand, expected_pattern) in &[
+                    (Strand::Forward, &pattern),
+                    (Strand::Reverse, &reverse_complement),
+                ] {
+                    let mismatches = window
+                        .iter()
+                        .zip(expected_pattern.as_ref())
+                        .filter(|&(a, b)| a != b)
+                        .count();
+
+                    if mismatches <= max_mismatches {
+                        expected.push(ApproximateMatch {
+                            position: position,
+                            strand: strand,
+                            mismatches: mismatches,
+                        });
+                    }
+                }
+            }
+
+            assert_eq!(
+                super::find_all_both_strands(&text, &pattern, max_mismatches),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn most_frequent_kmers() {
+        let text = DnaSequence::try_from("ACGTTGCATGTCGCATGATGCATGAGAGCT").unwrap();
+
+        let frequent = super::most_frequent_kmers(&text, 4, 1);
+
+        assert_eq!(strings(&frequent.kmers), vec!["ATGC", "ATGT", "GATG"]);
+        assert_eq!(frequent.count, 5);
+    }
+
+    #[test]
+    fn most_frequent_kmers_both_strands() {
+        let text = DnaSequence::try_from("ACGTTGCATGTCGCATGATGCATGAGAGCT").unwrap();
+
+        let frequent = super::most_frequent_kmers_both_strands(&text, 4, 1);
+
+        assert_eq!(strings(&frequent.kmers), vec!["ACAT", "ATGT"]);
+        assert_eq!(frequent.count, 9);
+    }
+
+    #[test]
+    fn most_frequent_kmers_too_long() {
+        let text = DnaSequence::try_from("ACGT").unwrap();
+
+        let frequent = super::most_frequent_kmers(&text, 5, 1);
+
+        assert!(frequent.kmers.is_empty());
+        assert_eq!(frequent.count, 0);
+    }
+}
+
+use std::collections::HashMap;
+use alignment::Symbol;
+use dna::{Nucleobase, Sequence as DnaSequence};
+use super::aho_corasick::AhoCorasick;
+use super::{unroll, Strand};
+
+// in the order of their index
+const NUCLEOBASES: [Nucleobase; 4] = [
+    Nucleobase::Adenine,
+    Nucleobase::Cytosine,
+    Nucleobase::Guanine,
+    Nucleobase::Thymine,
+];
+
+/// Occurrence of a pattern in a text, with at most the allowed number of mismatches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApproximateMatch {
+    /// 0-based position of the leftmost nucleobase of the occurrence, on the forward strand.
+    pub position: usize,
+    pub strand: Strand,
+    /// Hamming distance between the occurrence and the pattern (or its reverse complement on the reverse strand).
+    pub mismatches: usize,
+}
+
+/// K-mers appearing the most often in a text, with mismatches.
+#[derive(Debug, PartialEq)]
+pub struct FrequentKmers {
+    /// The most frequent k-mers, in lexicographic order. They don't necessarily appear as is in the text.
+    pub kmers: Vec<DnaSequence>,
+    /// Number of windows of the text within the allowed number of mismatches of each of these k-mers.
+    pub count: usize,
+}
+
+/// Returns every occurrence of `pattern` in `text` with at most `max_mismatches` mismatches,
+/// overlapping ones included, sorted by position. An empty pattern never matches.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+/// use rosalind::search::approximate;
+///
+/// let text = Sequence::try_from("CGCCCGAATCCAGAACGCATTCCCATATTTCGGGACCACTGG").unwrap();
+/// let primer = Sequence::try_from("ATTCTGGA").unwrap();
+///
+/// for found in approximate::find_all(&text, &primer, 3) {
+///     println!("{} ({} mismatches)", found.position, found.mismatches);
+/// }
+/// ```
+pub fn find_all(text: &DnaSequence, pattern: &DnaSequence, max_mismatches: usize) -> Vec<ApproximateMatch> {
+    find(text, pattern, max_mismatches, false)
+}
+
+/// Same as `find_all`, also searching the reverse complement of `pattern`.
+/// Occurrences are sorted by position, then by strand.
+pub fn find_all_both_strands(
+    text: &DnaSequence,
+    pattern: &DnaSequence,
+    max_mismatches: usize,
+) -> Vec<ApproximateMatch> {
+    find(text, pattern, max_mismatches, true)
+}
+
+/// Returns the k-mers which appear the most often in `text` with at most `max_mismatches` mismatches.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+/// use rosalind::search::approximate;
+///
+/// let text = Sequence::try_from("ACGTTGCATGTCGCATGATGCATGAGAGCT").unwrap();
+///
+/// let frequent = approximate::most_frequent_kmers(&text, 4, 1);
+///
+/// assert_eq!(frequent.count, 5);
+///
+/// for kmer in &frequent.kmers {
+///     println!("{}", kmer);
+/// }
+/// ```
+pub fn most_frequent_kmers(text: &DnaSequence, k: usize, max_mismatches: usize) -> FrequentKmers {
+    most_frequent(text, k, max_mismatches, false)
+}
+
+/// Same as `most_frequent_kmers`, where the occurrences of the reverse complement of a k-mer count as its own.
+pub fn most_frequent_kmers_both_strands(text: &DnaSequence, k: usize, max_mismatches: usize) -> FrequentKmers {
+    most_frequent(text, k, max_mismatches, true)
+}
+
+fn find(text: &DnaSequence, pattern: &DnaSequence, max_mismatches: usize, both_strands: bool) -> Vec<ApproximateMatch> {
+    if let Some(unrolled) = unroll(text, pattern.len()) {
+        let mut matches = find(&unrolled, pattern, max_mismatches, both_strands);
+
+        matches.retain(|found| found.position < text.len());
+
+        return matches;
+    }
+
+    let length = pattern.len();
+
+    if length == 0 || length > text.len() {
+        return Vec::new();
+    }
+
+    let strands = if both_strands {
+        vec![Strand::Forward, Strand::Reverse]
+    } else {
+        vec![Strand::Forward]
+    };
+
+    let mut candidates: Vec<(usize, Strand)> = if max_mismatches >= length {
+        // every window is close enough, nothing to seed
+        (0..text.len() - length + 1)
+            .flat_map(|position| strands.iter().map(move |&strand| (position, strand)))
+            .collect()
+    } else {
+        let pieces = pieces(pattern.as_ref(), max_mismatches + 1);
+        let automaton = AhoCorasick::new(&pieces.iter().map(|&(_, piece)| piece).collect::<Vec<_>>());
+
+        let seeds = if both_strands {
+            automaton.find_all_both_strands(text)
+        } else {
+            automaton.find_all(text)
+        };
+
+        seeds
+            .into_iter()
+            .filter_map(|seed| {
+                let (offset, piece) = pieces[seed.pattern];
+
+                // the pieces of the reverse complement of the pattern are in the reverse order
+                let offset = match seed.strand {
+                    Strand::Forward => offset,
+                    Strand::Reverse => length - offset - piece.len(),
+                };
+
+                if seed.position < offset || seed.position - offset + length > text.len() {
+                    None
+                } else {
+                    Some((seed.position - offset, seed.strand))
+                }
+            })
+            .collect()
+    };
+
+    // several pieces may point to the same window
+    candidates.sort();
+    candidates.dedup();
+
+    let reverse_complement = pattern.reverse_complement();
+
+    candidates
+        .into_iter()
+        .filter_map(|(position, strand)| {
+            let expected = match strand {
+                Strand::Forward => pattern.as_ref(),
+                Strand::Reverse => reverse_complement.as_ref(),
+            };
+
+            mismatches(&text[position..position + length], expected, max_mismatches).map(|mismatches| {
+                ApproximateMatch {
+                    position: position,
+                    strand: strand,
+                    mismatches: mismatches,
+                }
+            })
+        })
+        .collect()
+}
+
+// splits the pattern into `count` pieces of nearly equal lengths, with their offsets in the pattern
+fn pieces(pattern: &[Nucleobase], count: usize) -> Vec<(usize, &[Nucleobase])> {
+    let mut pieces = Vec::with_capacity(count);
+    let mut offset = 0;
+
+    for index in 0..count {
+        let length = pattern.len() / count + if index < pattern.len() % count { 1 } else { 0 };
+
+        pieces.push((offset, &pattern[offset..offset + length]));
+        offset += length;
+    }
+
+    pieces
+}
+
+// number of mismatches between a and b, or None as soon as there are more than max_mismatches
+fn mismatches(a: &[Nucleobase], b: &[Nucleobase], max_mismatches: usize) -> Option<usize> {
+    let mut mismatches = 0;
+
+    for (x, y) in a.iter().zip(b) {
+        if x != y {
+            mismatches += 1;
+
+            if mismatches > max_mismatches {
+                return None;
+            }
+        }
+    }
+
+    Some(mismatches)
+}
+
+fn most_frequent(text: &DnaSequence, k: usize, max_mismatches: usize, both_strands: bool) -> FrequentKmers {
+    // a circular text has as many windows as nucleobases
+    let unrolled = unroll(text, k);
+    let text = unrolled.as_ref().unwrap_or(text);
+
+    if k == 0 || k > text.len() {
+        return FrequentKmers {
+            kmers: Vec::new(),
+            count: 0,
+        };
+    }
+
+    let indexes: Vec<u8> = text.as_ref().iter().map(|nucleobase| nucleobase.index() as u8).collect();
+
+    // number of windows of the text within max_mismatches of each k-mer, indexed by the symbols of the k-mer
+    let mut counts: HashMap<Vec<u8>, usize> = HashMap::new();
+
+    {
+        let mut count_neighbors = |kmer: &[u8]| {
+            *counts.entry(kmer.to_vec()).or_insert(0) += 1;
+        };
+
+        for window in indexes.windows(k) {
+            for_each_neighbor(&mut window.to_vec(), 0, max_mismatches, &mut count_neighbors);
+
+            // a window close to the reverse complement of a k-mer is a window whose reverse complement is close to it
+            if both_strands {
+                // with A, C, G, T = 0, 1, 2, 3, the index of the complement of x is 3 - x
+                let mut reverse_complement: Vec<u8> = window.iter().rev().map(|&index| 3 - index).collect();
+
+                for_each_neighbor(&mut reverse_complement, 0, max_mismatches, &mut count_neighbors);
+            }
+        }
+    }
+
+    let count = counts.values().cloned().max().unwrap_or(0);
+
+    let mut kmers: Vec<Vec<u8>> = counts
+        .into_iter()
+        .filter(|&(_, kmer_count)| kmer_count == count)
+        .map(|(kmer, _)| kmer)
+        .collect();
+
+    kmers.sort();
+
+    FrequentKmers {
+        kmers: kmers
+            .into_iter()
+            .map(|kmer| {
+                DnaSequence::new(
+                    kmer.into_iter()
+                        .map(|index| NUCLEOBASES[index as usize].clone())
+                        .collect(),
+                )
+            })
+            .collect(),
+        count: count,
+    }
+}
+
+// calls f with every k-mer at most max_mismatches away from kmer, exactly once each,
+// by only changing the nucleobases at or after start
+fn for_each_neighbor<F: FnMut(&[u8])>(kmer: &mut Vec<u8>, start: usize, max_mismatches: usize, f: &mut F) {
+    f(kmer);
+
+    if max_mismatches == 0 {
+        return;
+    }
+
+    for position in start..kmer.len() {
+        let original = kmer[position];
+
+        for symbol in 0..4 {
+            if symbol != original {
+                kmer[position] = symbol;
+                for_each_neighbor(kmer, position + 1, max_mismatches - 1, f);
+            }
+        }
+
+        kmer[position] = original;
+    }
+}
@@ -0,0 +1,167 @@
+// Knuth-Morris-Pratt: finds all the occurrences of a pattern in a text in O(text length + pattern length)
+//
+// the failure array tells, for every prefix of the pattern, the length of its longest proper prefix
+// which is also a suffix: when a mismatch happens after having matched that prefix, we already know
+// that this many symbols match, so the search resumes from there instead of going back in the text
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::Sequence as DnaSequence;
+    use super::Kmp;
+
+    #[test]
+    fn failure_array() {
+        let sequence = DnaSequence::try_from("CAGCATGGTATCACAGCAGAG").unwrap();
+
+        assert_eq!(
+            super::failure_array(&sequence),
+            vec![0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 1, 2, 3, 4, 5, 3, 0, 0]
+        );
+    }
+
+    #[test]
+    fn failure_array_of_repeated_symbol() {
+        assert_eq!(super::failure_array("AAAA"), vec![0, 1, 2, 3]);
+        assert_eq!(super::failure_array(""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn find_all_overlapping_occurrences() {
+        let text = DnaSequence::try_from("GATATATGCATATACTT").unwrap();
+        let pattern = DnaSequence::try_from("ATAT").unwrap();
+
+        assert_eq!(super::find_all(&text, &pattern), vec![1, 3, 9]);
+    }
+
+    #[test]
+    fn find_all_in_strings() {
+        assert_eq!(super::find_all("ABAAAAEFGAAAAA", "AAA"), vec![2, 3, 9, 10, 11]);
+        assert_eq!(super::find_all("ABC", "ABCD"), Vec::<usize>::new());
+        assert_eq!(super::find_all("ABC", ""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn reuse_matcher() {
+        let matcher = Kmp::new(&[1, 2, 1]);
+
+        assert_eq!(matcher.find_all(&[1, 2, 1, 2, 1]), vec![0, 2]);
+        assert_eq!(matcher.find_all(&[2, 1, 2, 2, 1, 2, 1]), vec![4]);
+    }
+}
+
+/// Returns the failure array of `sequence`: for each position `k`, the length of the longest proper prefix
+/// of `sequence[..k + 1]` which is also a suffix of it.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+///
+/// let sequence = Sequence::try_from("CAGCATGGTATCACAGCAGAG").unwrap();
+///
+/// let failure_array = rosalind::search::kmp::failure_array(&sequence);
+///
+/// assert_eq!(&failure_array[..6], &[0, 0, 0, 1, 2, 0]);
+/// ```
+pub fn failure_array<T, Q>(sequence: &Q) -> Vec<usize>
+where
+    T: PartialEq,
+    Q: AsRef<[T]> + ?Sized,
+{
+    let sequence = sequence.as_ref();
+    let mut failure = vec![0; sequence.len()];
+
+    // length of the prefix matched so far
+    let mut matched = 0;
+
+    for index in 1..sequence.len() {
+        while matched > 0 && sequence[index] != sequence[matched] {
+            matched = failure[matched - 1];
+        }
+
+        if sequence[index] == sequence[matched] {
+            matched += 1;
+        }
+
+        failure[index] = matched;
+    }
+
+    failure
+}
+
+/// Returns the 0-based positions of all the occurrences of `pattern` in `text`, overlapping ones included.
+/// An empty pattern never matches.
+///
+/// Works with any sequence of comparable symbols: DNA, RNA, amino acids, or even strings (compared byte by byte).
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+/// use rosalind::search::kmp;
+///
+/// let text = Sequence::try_from("GATATATGCATATACTT").unwrap();
+/// let pattern = Sequence::try_from("ATAT").unwrap();
+///
+/// assert_eq!(kmp::find_all(&text, &pattern), vec![1, 3, 9]);
+/// assert_eq!(kmp::find_all("GATATATGCATATACTT", "ATAT"), vec![1, 3, 9]);
+/// ```
+pub fn find_all<T, Q, P>(text: &Q, pattern: &P) -> Vec<usize>
+where
+    T: PartialEq,
+    Q: AsRef<[T]> + ?Sized,
+    P: AsRef<[T]> + ?Sized,
+{
+    Kmp::new(pattern).find_all(text)
+}
+
+/// A pattern prepared to be searched in many texts.
+pub struct Kmp<'a, T: 'a> {
+    pattern: &'a [T],
+    failure: Vec<usize>,
+}
+
+impl<'a, T: PartialEq> Kmp<'a, T> {
+    pub fn new<P: AsRef<[T]> + ?Sized>(pattern: &'a P) -> Self {
+        let pattern = pattern.as_ref();
+
+        Kmp {
+            pattern: pattern,
+            failure: failure_array(pattern),
+        }
+    }
+
+    /// Returns the 0-based positions of all the occurrences of the pattern in `text`, overlapping ones included.
+    pub fn find_all<Q: AsRef<[T]> + ?Sized>(&self, text: &Q) -> Vec<usize> {
+        let text = text.as_ref();
+        let mut positions = Vec::new();
+
+        if self.pattern.is_empty() {
+            return positions;
+        }
+
+        let mut matched = 0;
+
+        for (index, symbol) in text.iter().enumerate() {
+            while matched > 0 && *symbol != self.pattern[matched] {
+                matched = self.failure[matched - 1];
+            }
+
+            if *symbol == self.pattern[matched] {
+                matched += 1;
+            }
+
+            if matched == self.pattern.len() {
+                positions.push(index + 1 - matched);
+
+                // keep going as if the last symbol had mismatched, to find overlapping occurrences
+                matched = self.failure[matched - 1];
+            }
+        }
+
+        positions
+    }
+}
@@ -0,0 +1,28 @@
+// exact and approximate pattern matching over sequences
+//
+// occurrences spanning the origin of circular DNA sequences are found too,
+// except by the KMP functions which work on any slice and thus know nothing about topology
+
+use dna::Sequence as DnaSequence;
+
+pub mod aho_corasick;
+pub mod approximate;
+pub mod kmp;
+
+/// Strand of the text on which a pattern was found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strand {
+    Forward,
+    Reverse,
+}
+
+// a circular text followed by enough of its beginning for occurrences of patterns up to `longest` nucleobases
+// spanning the origin to be found by a linear scan, or None for a linear text
+// the occurrences starting at or after the length of the original text are duplicates and must be dropped
+pub(crate) fn unroll(text: &DnaSequence, longest: usize) -> Option<DnaSequence> {
+    if text.is_circular() && text.len() > 0 && longest > 1 {
+        Some(text.subsequence(0, text.len() + longest - 1))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,521 @@
+// substitutions between two aligned sequences of the same length
+//
+// each difference is a transition (purine <=> purine or pyrimidine <=> pyrimidine, the most frequent)
+// or a transversion (purine <=> pyrimidine)
+//
+// synonymous and non-synonymous substitutions are counted the Nei-Gojobori (1986) way:
+// - each codon has 3 sites, split between synonymous and non-synonymous in proportion to the changes
+//   of each position which keep or change the amino acid (changes to stop codons don't count)
+// - codons differing at several positions are counted along every order of the changes,
+//   except those going through a stop codon, and the counts are averaged
+// - codon pairs including a stop codon are left out
+//
+// the proportions of differing sites are then corrected for multiple substitutions with the Jukes-Cantor formula
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::Sequence as DnaSequence;
+    use genetic_code::GeneticCode;
+    use super::{Difference, Kind, SubstitutionError};
+
+    fn dna(string: &str) -> DnaSequence {
+        DnaSequence::try_from(string).unwrap()
+    }
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!((value - expected).abs() < 1e-6, "{} instead of {}", value, expected);
+    }
+
+    #[test]
+    fn count_differences() {
+        assert_eq!(super::count_differences(&dna("GAGCCTACTAACGGGAT"), &dna("CATCGTAATGACGGCCT")), 7);
+        assert_eq!(super::count_differences(&dna("GAGCCTACTAACGGGAT"), &dna("GCGTAATGAAAG")), 8);
+    }
+
+    #[test]
+    fn classify() {
+        use dna::Nucleobase::*;
+
+        assert_eq!(super::classify(&Adenine, &Guanine), Some(Kind::Transition));
+        assert_eq!(super::classify(&Thymine, &Cytosine), Some(Kind::Transition));
+        assert_eq!(super::classify(&Adenine, &Thymine), Some(Kind::Transversion));
+        assert_eq!(super::classify(&Guanine, &Cytosine), Some(Kind::Transversion));
+        assert_eq!(super::classify(&Guanine, &Guanine), None);
+    }
+
+    #[test]
+    fn compare() {
+        // rosalind.info/problems/tran
+        let a = dna("GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGAAGTACGGGCATCAACCCAGTT");
+        let b = dna("TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGCGGTACGAGTGTTCCTTTGGGT");
+
+        let substitutions = super::compare(&a, &b).unwrap();
+
+        assert_eq!(substitutions.transitions(), 17);
+        assert_eq!(substitutions.transversions(), 14);
+        assert_close(substitutions.transition_transversion_ratio().unwrap(), 1.21428571429);
+        assert_eq!(substitutions.differences.len(), 31);
+    }
+
+    #[test]
+    fn substitution_matrix() {
+        use dna::Nucleobase::*;
+
+        let substitutions = super::compare(&dna("AACGTA"), &dna("AGCTTA")).unwrap();
+
+        assert_eq!(substitutions.count(&Adenine, &Adenine), 2);
+        assert_eq!(substitutions.count(&Adenine, &Guanine), 1);
+        assert_eq!(substitutions.count(&Guanine, &Thymine), 1);
+        assert_eq!(substitutions.count(&Guanine, &Adenine), 0);
+        assert_eq!(substitutions.matrix.iter().map(|row| row.iter().sum::<usize>()).sum::<usize>(), 6);
+
+        assert_eq!(
+            substitutions.differences,
+            vec![
+                Difference {
+                    position: 1,
+                    from: Adenine,
+                    to: Guanine,
+                    kind: Kind::Transition,
+                },
+                Difference {
+                    position: 3,
+                    from: Guanine,
+                    to: Thymine,
+                    kind: Kind::Transversion,
+                },
+            ]
+        );
+
+        // no transversion
+        assert_eq!(super::compare(&dna("AC"), &dna("GT")).unwrap().transition_transversion_ratio(), None);
+    }
+
+    #[test]
+    fn nei_gojobori_sites() {
+        let standard = GeneticCode::standard();
+
+        // TTA (leucine): CTA at the 1st position and TTG at the 3rd are synonymous, TAA and TGA are stops
+        // CTA (leucine): TTA at the 1st position and any 3rd position are synonymous
+        let analysis = super::nei_gojobori(&dna("TTACTA"), &dna("CTACTA"), &standard).unwrap();
+
+        assert_close(analysis.synonymous_sites, (2.0 / 3.0 + 4.0 / 3.0) / 2.0 + 4.0 / 3.0);
+        assert_close(analysis.nonsynonymous_sites, 6.0 - analysis.synonymous_sites);
+        assert_close(analysis.synonymous_differences, 1.0);
+        assert_close(analysis.nonsynonymous_differences, 0.0);
+
+        assert_close(analysis.ds().unwrap(), -0.75 * (3.0f64 / 7.0).ln());
+        assert_eq!(analysis.dn(), Some(0.0));
+        assert_eq!(analysis.dn_ds(), Some(0.0));
+    }
+
+    #[test]
+    fn nei_gojobori_pathways() {
+        let standard = GeneticCode::standard();
+
+        // AAA -> GAA -> GAG or AAA -> AAG -> GAG: one synonymous and one non-synonymous change either way
+        let analysis = super::nei_gojobori(&dna("AAA"), &dna("GAG"), &standard).unwrap();
+
+        assert_close(analysis.synonymous_differences, 1.0);
+        assert_close(analysis.nonsynonymous_differences, 1.0);
+
+        // TTA -> TGA is a stop, so only TTA -> TTG -> TGG counts
+        let analysis = super::nei_gojobori(&dna("TTA"), &dna("TGG"), &standard).unwrap();
+
+        assert_close(analysis.synonymous_differences, 1.0);
+        assert_close(analysis.nonsynonymous_differences, 1.0);
+
+        // codons with stops are left out
+        let analysis = super::nei_gojobori(&dna("TAAAAA"), &dna("TAGAAG"), &standard).unwrap();
+
+        assert_close(analysis.synonymous_sites + analysis.nonsynonymous_sites, 3.0);
+        assert_close(analysis.synonymous_differences, 1.0);
+    }
+
+    #[test]
+    fn nei_gojobori_saturation() {
+        // every synonymous site differs: the Jukes-Cantor correction can't be applied
+        let analysis = super::nei_gojobori(&dna("TTA"), &dna("CTA"), &GeneticCode::standard()).unwrap();
+
+        assert_eq!(analysis.ds(), None);
+        assert_eq!(analysis.dn_ds(), None);
+    }
+
+    #[test]
+    fn nei_gojobori_errors() {
+        let standard = GeneticCode::standard();
+
+        assert_eq!(
+            super::nei_gojobori(&dna("AAAA"), &dna("AAA"), &standard).unwrap_err(),
+            SubstitutionError::LengthMismatch { a: 4, b: 3 }
+        );
+        assert_eq!(
+            super::nei_gojobori(&dna("AAAA"), &dna("AAAT"), &standard).unwrap_err(),
+            SubstitutionError::PartialCodon { length: 4 }
+        );
+    }
+}
+
+use std::{error, fmt};
+use dna::{Nucleobase, Sequence as DnaSequence};
+use genetic_code::GeneticCode;
+use rna::{Codon, Nucleobase as RnaNucleobase, Sequence as RnaSequence, StrictCodonIterator};
+
+#[derive(Debug, PartialEq)]
+pub enum SubstitutionError {
+    /// The sequences, of lengths `a` and `b`, aren't aligned.
+    LengthMismatch { a: usize, b: usize },
+    /// The sequences don't split into codons.
+    PartialCodon { length: usize },
+}
+
+impl error::Error for SubstitutionError {
+    fn description(&self) -> &str {
+        match self {
+            SubstitutionError::LengthMismatch { .. } => "the sequences don't have the same length",
+            SubstitutionError::PartialCodon { .. } => "the length of the sequences isn't a multiple of 3",
+        }
+    }
+}
+
+impl fmt::Display for SubstitutionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubstitutionError::LengthMismatch { a, b } => {
+                write!(f, "the sequences have different lengths: {} and {}", a, b)
+            }
+            SubstitutionError::PartialCodon { length } => {
+                write!(f, "the length of the sequences, {}, isn't a multiple of 3", length)
+            }
+        }
+    }
+}
+
+/// Kind of a substitution of a nucleobase by another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A purine replaced by a purine, or a pyrimidine by a pyrimidine.
+    Transition,
+    /// A purine replaced by a pyrimidine, or conversely.
+    Transversion,
+}
+
+/// Returns the kind of the substitution of `from` by `to`, None if they are the same.
+pub fn classify(from: &Nucleobase, to: &Nucleobase) -> Option<Kind> {
+    if from == to {
+        None
+    } else if from.is_purine() == to.is_purine() {
+        Some(Kind::Transition)
+    } else {
+        Some(Kind::Transversion)
+    }
+}
+
+/// Position where two aligned sequences differ.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    pub position: usize,
+    pub from: Nucleobase,
+    pub to: Nucleobase,
+    pub kind: Kind,
+}
+
+/// Substitutions from a sequence to another, see `compare`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Substitutions {
+    pub differences: Vec<Difference>,
+    /// How many times each nucleobase (row) is aligned with each nucleobase (column), matches included,
+    /// in the A, C, G, T order.
+    pub matrix: [[usize; 4]; 4],
+}
+
+impl Substitutions {
+    /// Returns how many times `from` is aligned with `to`.
+    pub fn count(&self, from: &Nucleobase, to: &Nucleobase) -> usize {
+        self.matrix[index(from)][index(to)]
+    }
+
+    pub fn transitions(&self) -> usize {
+        self.of_kind(Kind::Transition)
+    }
+
+    pub fn transversions(&self) -> usize {
+        self.of_kind(Kind::Transversion)
+    }
+
+    /// Returns the number of transitions per transversion, None if there is no transversion.
+    pub fn transition_transversion_ratio(&self) -> Option<f64> {
+        match self.transversions() {
+            0 => None,
+            transversions => Some(self.transitions() as f64 / transversions as f64),
+        }
+    }
+
+    fn of_kind(&self, kind: Kind) -> usize {
+        self.differences.iter().filter(|difference| difference.kind == kind).count()
+    }
+}
+
+/// Classifies the differences between the aligned sequences `a` and `b` and counts all the substitutions.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+///
+/// let a = Sequence::try_from("GCAACGCACAAC").unwrap();
+/// let b = Sequence::try_from("TTATCTGACAAA").unwrap();
+///
+/// let substitutions = rosalind::substitution::compare(&a, &b).unwrap();
+///
+/// println!("transitions/transversions: {:?}", substitutions.transition_transversion_ratio());
+/// ```
+pub fn compare(a: &DnaSequence, b: &DnaSequence) -> Result<Substitutions, SubstitutionError> {
+    if a.len() != b.len() {
+        return Err(SubstitutionError::LengthMismatch { a: a.len(), b: b.len() });
+    }
+
+    let mut substitutions = Substitutions {
+        differences: Vec::new(),
+        matrix: [[0; 4]; 4],
+    };
+
+    for (position, (from, to)) in a.as_ref().iter().zip(b.as_ref()).enumerate() {
+        substitutions.matrix[index(from)][index(to)] += 1;
+
+        if let Some(kind) = classify(from, to) {
+            substitutions.differences.push(Difference {
+                position: position,
+                from: from.clone(),
+                to: to.clone(),
+                kind: kind,
+            });
+        }
+    }
+
+    Ok(substitutions)
+}
+
+// A, C, G, T = 0, 1, 2, 3
+fn index(nucleobase: &Nucleobase) -> usize {
+    match nucleobase {
+        Nucleobase::Adenine => 0,
+        Nucleobase::Cytosine => 1,
+        Nucleobase::Guanine => 2,
+        Nucleobase::Thymine => 3,
+    }
+}
+
+/// Returns the number of positions where `a` and `b` differ (Hamming distance), up to the end of the shortest one.
+pub fn count_differences(a: &DnaSequence, b: &DnaSequence) -> usize {
+    a.as_ref()
+        .iter()
+        .zip(b.as_ref())
+        .filter(|&(a, b)| a != b)
+        .count()
+}
+
+/// Synonymous and non-synonymous sites and differences between two coding sequences, see `nei_gojobori`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SynonymousAnalysis {
+    pub synonymous_sites: f64,
+    pub nonsynonymous_sites: f64,
+    pub synonymous_differences: f64,
+    pub nonsynonymous_differences: f64,
+}
+
+impl SynonymousAnalysis {
+    /// Returns the number of synonymous substitutions per synonymous site, with the Jukes-Cantor correction,
+    /// or None if there is no synonymous site or too many differences for the correction.
+    pub fn ds(&self) -> Option<f64> {
+        jukes_cantor(self.synonymous_differences, self.synonymous_sites)
+    }
+
+    /// Returns the number of non-synonymous substitutions per non-synonymous site, with the Jukes-Cantor correction,
+    /// or None if there is no non-synonymous site or too many differences for the correction.
+    pub fn dn(&self) -> Option<f64> {
+        jukes_cantor(self.nonsynonymous_differences, self.nonsynonymous_sites)
+    }
+
+    /// Returns dN/dS: below 1 under purifying selection, above 1 under positive selection.
+    /// None if dN or dS can't be computed, or if dS is 0.
+    pub fn dn_ds(&self) -> Option<f64> {
+        match (self.dn(), self.ds()) {
+            (Some(dn), Some(ds)) if ds > 0.0 => Some(dn / ds),
+            _ => None,
+        }
+    }
+}
+
+/// Counts the synonymous and non-synonymous sites and differences between the aligned coding sequences `a` and `b`
+/// by the Nei-Gojobori method, see the top of this module.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use rosalind::dna::Sequence;
+/// use rosalind::genetic_code::GeneticCode;
+///
+/// let a = Sequence::try_from("ATGAAACTGCCCGGT").unwrap();
+/// let b = Sequence::try_from("ATGAAGCTGCCAGAT").unwrap();
+///
+/// let analysis = rosalind::substitution::nei_gojobori(&a, &b, &GeneticCode::standard()).unwrap();
+///
+/// println!("dN/dS: {:?}", analysis.dn_ds());
+/// ```
+pub fn nei_gojobori(
+    a: &DnaSequence,
+    b: &DnaSequence,
+    genetic_code: &GeneticCode,
+) -> Result<SynonymousAnalysis, SubstitutionError> {
+    if a.len() != b.len() {
+        return Err(SubstitutionError::LengthMismatch { a: a.len(), b: b.len() });
+    }
+
+    if a.len() % 3 != 0 {
+        return Err(SubstitutionError::PartialCodon { length: a.len() });
+    }
+
+    let mut analysis = SynonymousAnalysis {
+        synonymous_sites: 0.0,
+        nonsynonymous_sites: 0.0,
+        synonymous_differences: 0.0,
+        nonsynonymous_differences: 0.0,
+    };
+
+    let (rna_a, rna_b) = (RnaSequence::from(a), RnaSequence::from(b));
+
+    for (codon_a, codon_b) in StrictCodonIterator::new(&rna_a).zip(StrictCodonIterator::new(&rna_b)) {
+        if genetic_code.is_stop(codon_a) || genetic_code.is_stop(codon_b) {
+            continue;
+        }
+
+        let synonymous_sites = (synonymous_sites(codon_a, genetic_code) + synonymous_sites(codon_b, genetic_code)) / 2.0;
+        let (synonymous, nonsynonymous) = differences(codon_a, codon_b, genetic_code);
+
+        analysis.synonymous_sites += synonymous_sites;
+        analysis.nonsynonymous_sites += 3.0 - synonymous_sites;
+        analysis.synonymous_differences += synonymous;
+        analysis.nonsynonymous_differences += nonsynonymous;
+    }
+
+    Ok(analysis)
+}
+
+const NUCLEOBASES: [RnaNucleobase; 4] = [
+    RnaNucleobase::Adenine,
+    RnaNucleobase::Cytosine,
+    RnaNucleobase::Guanine,
+    RnaNucleobase::Uracil,
+];
+
+// sum over the 3 positions of the proportion of changes keeping the amino acid, among those not making a stop codon
+fn synonymous_sites(codon: Codon, genetic_code: &GeneticCode) -> f64 {
+    let amino_acid = genetic_code.amino_acid(codon);
+    let mut sites = 0.0;
+
+    for position in 0..3 {
+        let mut synonymous = 0;
+        let mut changes = 0;
+
+        for &nucleobase in NUCLEOBASES.iter().filter(|&&nucleobase| nucleobase != codon.0[position]) {
+            let mut mutant = codon;
+            mutant.0[position] = nucleobase;
+
+            if genetic_code.is_stop(mutant) {
+                continue;
+            }
+
+            changes += 1;
+
+            if genetic_code.amino_acid(mutant) == amino_acid {
+                synonymous += 1;
+            }
+        }
+
+        if changes > 0 {
+            sites += synonymous as f64 / changes as f64;
+        }
+    }
+
+    sites
+}
+
+// synonymous and non-synonymous differences between two codons, averaged over the orders of the changes
+// which don't go through a stop codon (or over all the orders if they all do)
+fn differences(a: Codon, b: Codon, genetic_code: &GeneticCode) -> (f64, f64) {
+    let positions: Vec<usize> = (0..3).filter(|&position| a.0[position] != b.0[position]).collect();
+    let mut pathways = Vec::new();
+
+    permutations(&mut positions.clone(), 0, &mut |order| {
+        let mut codon = a;
+        let (mut synonymous, mut nonsynonymous) = (0, 0);
+        let mut through_stop = false;
+
+        for &position in order {
+            let mut next = codon;
+            next.0[position] = b.0[position];
+
+            if genetic_code.amino_acid(next) == genetic_code.amino_acid(codon) {
+                synonymous += 1;
+            } else {
+                nonsynonymous += 1;
+            }
+
+            through_stop |= next != b && genetic_code.is_stop(next);
+            codon = next;
+        }
+
+        pathways.push((synonymous, nonsynonymous, through_stop));
+    });
+
+    let counted: Vec<(usize, usize)> = if pathways.iter().all(|&(_, _, through_stop)| through_stop) {
+        pathways.iter().map(|&(synonymous, nonsynonymous, _)| (synonymous, nonsynonymous)).collect()
+    } else {
+        pathways
+            .iter()
+            .filter(|&&(_, _, through_stop)| !through_stop)
+            .map(|&(synonymous, nonsynonymous, _)| (synonymous, nonsynonymous))
+            .collect()
+    };
+
+    let synonymous: usize = counted.iter().map(|&(synonymous, _)| synonymous).sum();
+    let nonsynonymous: usize = counted.iter().map(|&(_, nonsynonymous)| nonsynonymous).sum();
+
+    (synonymous as f64 / counted.len() as f64, nonsynonymous as f64 / counted.len() as f64)
+}
+
+// calls visit with every permutation of items[start..], there are at most 3 items here
+fn permutations<F>(items: &mut Vec<usize>, start: usize, visit: &mut F)
+where
+    F: FnMut(&[usize]),
+{
+    if start == items.len() {
+        visit(items);
+        return;
+    }
+
+    for index in start..items.len() {
+        items.swap(start, index);
+        permutations(items, start + 1, visit);
+        items.swap(start, index);
+    }
+}
+
+// -3/4 ln(1 - 4/3 p), where p is the proportion of differing sites
+fn jukes_cantor(differences: f64, sites: f64) -> Option<f64> {
+    if sites <= 0.0 {
+        return None;
+    }
+
+    let p = differences / sites;
+
+    if p >= 0.75 {
+        None
+    } else {
+        Some(-0.75 * (1.0 - 4.0 * p / 3.0).ln())
+    }
+}
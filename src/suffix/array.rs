@@ -0,0 +1,605 @@
+// suffix array, built in linear time with SA-IS (Nong, Zhang & Chan, 2009), and its LCP array (Kasai et al., 2001)
+//
+// SA-IS sorts the suffixes starting at LMS positions (an S-type suffix, i.e. smaller than the next one,
+// following an L-type one) by sorting the substrings between consecutive LMS positions and recursing on
+// their names if they aren't all distinct; the order of all the other suffixes is then induced from them
+//
+// several sequences are indexed at once by concatenating them with a distinct separator after each one,
+// so that no common prefix can span two sequences: the separators are smaller than every symbol,
+// the one of the last sequence being the smallest so that it also acts as the sentinel SA-IS needs
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::Sequence as AminoAcidSequence;
+    use dna::{Sequence as DnaSequence, Topology};
+    use suffix::SuffixError;
+    use super::{SuffixArray, SuffixPosition, Substring};
+
+    fn dna_sequences(strings: &[&str]) -> Vec<DnaSequence> {
+        strings
+            .iter()
+            .map(|string| DnaSequence::try_from(*string).unwrap())
+            .collect()
+    }
+
+    // suffixes sorted by comparing them directly
+    fn naive_suffixes(strings: &[&str]) -> Vec<SuffixPosition> {
+        let mut suffixes: Vec<SuffixPosition> = strings
+            .iter()
+            .enumerate()
+            .flat_map(|(sequence, string)| {
+                (0..string.len()).map(move |offset| SuffixPosition {
+                    sequence: sequence,
+                    offset: offset,
+                })
+            })
+            .collect();
+
+        // a suffix which is a prefix of another one comes first, then ties are broken by sequence
+        suffixes.sort_by(|a, b| {
+            let suffix_a = &strings[a.sequence][a.offset..];
+            let suffix_b = &strings[b.sequence][b.offset..];
+
+            if suffix_a == suffix_b {
+                b.sequence.cmp(&a.sequence)
+            } else {
+                suffix_a.cmp(suffix_b)
+            }
+        });
+
+        suffixes
+    }
+
+    #[test]
+    fn suffixes_and_lcp() {
+        let sequence = DnaSequence::try_from("GATTACA").unwrap();
+
+        let suffix_array = SuffixArray::new(&sequence).unwrap();
+
+        let offsets: Vec<usize> = suffix_array.suffixes().iter().map(|suffix| suffix.offset).collect();
+
+        // A, ACA, ATTACA, CA, GATTACA, TACA, TTACA
+        assert_eq!(offsets, vec![6, 4, 1, 5, 0, 3, 2]);
+        assert_eq!(suffix_array.lcp(), &[0, 1, 1, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn suffixes_match_naive_sort() {
+        let strings = [
+            "ACGTACGTTACGCCACGTACGTACCCGTA",
+            "AAAAAAAAAA",
+            "ACACACACAC",
+            "GTGTAAAGTGTAAAGTGTA",
+            "T",
+        ];
+
+        for string in &strings {
+            let sequence = DnaSequence::try_from(*string).unwrap();
+
+            assert_eq!(SuffixArray::new(&sequence).unwrap().suffixes(), &naive_suffixes(&[string])[..]);
+        }
+
+        let suffix_array = SuffixArray::generalized(&dna_sequences(&strings)).unwrap();
+
+        assert_eq!(suffix_array.suffixes(), &naive_suffixes(&strings)[..]);
+    }
+
+    #[test]
+    fn amino_acids() {
+        let sequence = AminoAcidSequence::try_from("MAMAPRTEINSTRING").unwrap();
+
+        let suffix_array = SuffixArray::new(&sequence).unwrap();
+
+        assert_eq!(suffix_array.len(), 16);
+
+        // both IN and MA are repeated, but amino acids are ordered as in substitution matrices: I comes before M
+        assert_eq!(
+            suffix_array.longest_repeated_substring(),
+            Some(Substring {
+                sequence: 0,
+                offset: 8,
+                length: 2,
+            })
+        );
+
+        // translated through stop codons
+        let sequence = AminoAcidSequence::try_from("MW*MW*").unwrap();
+
+        assert_eq!(
+            SuffixArray::new(&sequence).unwrap().longest_repeated_substring(),
+            Some(Substring {
+                sequence: 0,
+                offset: 0,
+                length: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn circular_sequences() {
+        let mut sequences = dna_sequences(&["GATTACA"]);
+        sequences.push(DnaSequence::try_from("ACGT").unwrap().with_topology(Topology::Circular));
+
+        assert_eq!(
+            SuffixArray::generalized(&sequences).err(),
+            Some(SuffixError::CircularSequence { index: 1 })
+        );
+        assert_eq!(
+            SuffixArray::new(&sequences[1]).err(),
+            Some(SuffixError::CircularSequence { index: 0 })
+        );
+    }
+
+    #[test]
+    fn longest_repeated_substring() {
+        // TATCGTT is at 1 and 9
+        let sequence = DnaSequence::try_from("ATATCGTTTTATCGTT").unwrap();
+
+        assert_eq!(
+            SuffixArray::new(&sequence).unwrap().longest_repeated_substring(),
+            Some(Substring {
+                sequence: 0,
+                offset: 1,
+                length: 7,
+            })
+        );
+
+        let sequence = DnaSequence::try_from("ACGT").unwrap();
+
+        assert_eq!(SuffixArray::new(&sequence).unwrap().longest_repeated_substring(), None);
+    }
+
+    #[test]
+    fn longest_common_substring() {
+        let sequences = dna_sequences(&["GATTACA", "TAGACCA", "ATACA"]);
+
+        let suffix_array = SuffixArray::generalized(&sequences).unwrap();
+
+        // AC, CA and TA are all common to the 3 sequences, AC is the smallest
+        let found = suffix_array.longest_common_substring().unwrap();
+
+        assert_eq!(found.length, 2);
+        assert_eq!(
+            &sequences[found.sequence][found.offset..found.offset + found.length],
+            DnaSequence::try_from("AC").unwrap().as_ref()
+        );
+    }
+
+    #[test]
+    fn no_common_substring() {
+        let suffix_array = SuffixArray::generalized(&dna_sequences(&["AAAA", "CCC", "AC"])).unwrap();
+
+        assert_eq!(suffix_array.longest_common_substring(), None);
+
+        let suffix_array = SuffixArray::generalized(&dna_sequences(&["ACGT"])).unwrap();
+
+        assert_eq!(
+            suffix_array.longest_common_substring(),
+            Some(Substring {
+                sequence: 0,
+                offset: 0,
+                length: 4,
+            })
+        );
+    }
+}
+
+use std::collections::VecDeque;
+use alignment::Symbol;
+use super::{SuffixError, Text};
+
+const EMPTY: usize = ::std::usize::MAX;
+
+/// Starting position of a suffix: the sequence it belongs to and its offset within it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuffixPosition {
+    /// Index of the sequence, in the order they were given (always 0 for a single sequence).
+    pub sequence: usize,
+    pub offset: usize,
+}
+
+/// Substring of one of the indexed sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Substring {
+    pub sequence: usize,
+    pub offset: usize,
+    pub length: usize,
+}
+
+/// The suffixes of one or several sequences, sorted in lexicographic order.
+pub struct SuffixArray {
+    suffixes: Vec<SuffixPosition>,
+    lcp: Vec<usize>,
+    number_of_sequences: usize,
+}
+
+impl SuffixArray {
+    /// Builds the suffix array of `sequence`, in O(length of the sequence),
+    /// or fails if it is a circular DNA sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::suffix::SuffixArray;
+    ///
+    /// let sequence = Sequence::try_from("GATTACA").unwrap();
+    ///
+    /// let suffix_array = SuffixArray::new(&sequence).unwrap();
+    ///
+    /// for suffix in suffix_array.suffixes() {
+    ///     println!("{}", suffix.offset);
+    /// }
+    /// ```
+    pub fn new<S: Symbol, Q: Text<S> + ?Sized>(sequence: &Q) -> Result<Self, SuffixError> {
+        SuffixArray::generalized(&[sequence])
+    }
+
+    /// Builds the generalized suffix array of `sequences`, in O(total length of the sequences).
+    /// When two suffixes of different sequences are equal, the one of the last sequence comes first.
+    /// Fails if one of them is a circular DNA sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::suffix::SuffixArray;
+    ///
+    /// let sequences = vec![
+    ///     Sequence::try_from("GATTACA").unwrap(),
+    ///     Sequence::try_from("TAGACCA").unwrap(),
+    ///     Sequence::try_from("ATACA").unwrap(),
+    /// ];
+    ///
+    /// let suffix_array = SuffixArray::generalized(&sequences).unwrap();
+    ///
+    /// let found = suffix_array.longest_common_substring().unwrap();
+    ///
+    /// println!("{:?}", &sequences[found.sequence][found.offset..found.offset + found.length]);
+    /// ```
+    pub fn generalized<S: Symbol, Q: Text<S>>(sequences: &[Q]) -> Result<Self, SuffixError> {
+        if let Some(index) = sequences.iter().position(|sequence| sequence.is_circular()) {
+            return Err(SuffixError::CircularSequence { index: index });
+        }
+
+        let number_of_sequences = sequences.len();
+
+        // the sequence and offset of every position of the concatenated text, separators included
+        let mut positions = Vec::new();
+        let mut text = Vec::new();
+
+        for (index, sequence) in sequences.iter().enumerate() {
+            for (offset, symbol) in sequence.as_ref().iter().enumerate() {
+                text.push(number_of_sequences + symbol.index());
+                positions.push(SuffixPosition {
+                    sequence: index,
+                    offset: offset,
+                });
+            }
+
+            text.push(number_of_sequences - 1 - index);
+            positions.push(SuffixPosition {
+                sequence: index,
+                offset: sequence.as_ref().len(),
+            });
+        }
+
+        if text.is_empty() {
+            return Ok(SuffixArray {
+                suffixes: Vec::new(),
+                lcp: Vec::new(),
+                number_of_sequences: 0,
+            });
+        }
+
+        let suffix_array = sais(&text, number_of_sequences + S::ALPHABET_SIZE);
+        let lcp = lcp(&text, &suffix_array);
+
+        // the suffixes starting with a separator aren't suffixes of the sequences, but they are kept up to here
+        // as the LCP of the suffixes around them is the minimum of the LCPs in between
+        let mut suffixes = Vec::with_capacity(text.len() - number_of_sequences);
+        let mut kept_lcp = Vec::with_capacity(text.len() - number_of_sequences);
+        let mut running_lcp = EMPTY;
+
+        for (&position, &suffix_lcp) in suffix_array.iter().zip(&lcp) {
+            running_lcp = running_lcp.min(suffix_lcp);
+
+            if text[position] >= number_of_sequences {
+                suffixes.push(positions[position]);
+                kept_lcp.push(if kept_lcp.is_empty() { 0 } else { running_lcp });
+                running_lcp = EMPTY;
+            }
+        }
+
+        Ok(SuffixArray {
+            suffixes: suffixes,
+            lcp: kept_lcp,
+            number_of_sequences: number_of_sequences,
+        })
+    }
+
+    /// Returns the number of suffixes, i.e. the total length of the sequences.
+    pub fn len(&self) -> usize {
+        self.suffixes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.suffixes.is_empty()
+    }
+
+    /// Returns the starting positions of the suffixes, in lexicographic order.
+    pub fn suffixes(&self) -> &[SuffixPosition] {
+        &self.suffixes
+    }
+
+    /// Returns the LCP array: the length of the longest common prefix of each suffix and the previous one
+    /// (0 for the first suffix).
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Returns the longest substring appearing at least twice in the sequences (overlaps allowed),
+    /// the smallest one in lexicographic order if there are several, or None if no symbol is repeated.
+    pub fn longest_repeated_substring(&self) -> Option<Substring> {
+        let mut longest: Option<(usize, usize)> = None;
+
+        for (rank, &length) in self.lcp.iter().enumerate() {
+            if length > longest.map_or(0, |(_, longest_length)| longest_length) {
+                longest = Some((rank, length));
+            }
+        }
+
+        longest.map(|(rank, length)| self.substring(rank, length))
+    }
+
+    /// Returns the longest substring common to all the sequences,
+    /// the smallest one in lexicographic order if there are several, or None if they have no symbol in common.
+    pub fn longest_common_substring(&self) -> Option<Substring> {
+        if self.number_of_sequences == 1 {
+            return if self.suffixes.is_empty() {
+                None
+            } else {
+                Some(Substring {
+                    sequence: 0,
+                    offset: 0,
+                    length: self.suffixes.len(),
+                })
+            };
+        }
+
+        // sliding window over the suffixes, as small as possible while containing a suffix of every sequence:
+        // the LCP of the window is the minimum of the LCPs in it, kept in a monotonic queue
+        let mut counts = vec![0; self.number_of_sequences];
+        let mut covered = 0;
+        let mut minimums: VecDeque<usize> = VecDeque::new();
+
+        let mut longest: Option<(usize, usize)> = None;
+        let mut left = 0;
+
+        for right in 0..self.suffixes.len() {
+            let sequence = self.suffixes[right].sequence;
+
+            if counts[sequence] == 0 {
+                covered += 1;
+            }
+            counts[sequence] += 1;
+
+            if right > left {
+                while minimums.back().map_or(false, |&rank| self.lcp[rank] >= self.lcp[right]) {
+                    minimums.pop_back();
+                }
+                minimums.push_back(right);
+            }
+
+            while covered == self.number_of_sequences {
+                let length = minimums.front().map_or(0, |&rank| self.lcp[rank]);
+
+                if length > longest.map_or(0, |(_, longest_length)| longest_length) {
+                    longest = Some((left, length));
+                }
+
+                let sequence = self.suffixes[left].sequence;
+
+                counts[sequence] -= 1;
+                if counts[sequence] == 0 {
+                    covered -= 1;
+                }
+
+                left += 1;
+
+                // the window now starts at left, so the LCP of the suffix at left with the previous one is out of it
+                while minimums.front().map_or(false, |&rank| rank <= left) {
+                    minimums.pop_front();
+                }
+            }
+        }
+
+        longest.map(|(rank, length)| self.substring(rank, length))
+    }
+
+    fn substring(&self, rank: usize, length: usize) -> Substring {
+        Substring {
+            sequence: self.suffixes[rank].sequence,
+            offset: self.suffixes[rank].offset,
+            length: length,
+        }
+    }
+}
+
+// suffix array of text, whose last symbol must be 0 and appear nowhere else, every symbol being < alphabet_size
+pub(crate) fn sais(text: &[usize], alphabet_size: usize) -> Vec<usize> {
+    let length = text.len();
+
+    if length == 1 {
+        return vec![0];
+    }
+
+    // S-type suffixes are smaller than the next one, L-type ones are larger
+    let mut is_s = vec![false; length];
+    is_s[length - 1] = true;
+
+    for index in (0..length - 1).rev() {
+        is_s[index] = text[index] < text[index + 1] || (text[index] == text[index + 1] && is_s[index + 1]);
+    }
+
+    let is_lms = |index: usize| index > 0 && is_s[index] && !is_s[index - 1];
+
+    let mut bucket_sizes = vec![0; alphabet_size];
+    for &symbol in text {
+        bucket_sizes[symbol] += 1;
+    }
+
+    let lms: Vec<usize> = (1..length).filter(|&index| is_lms(index)).collect();
+
+    // sorting the LMS suffixes by their first LMS substring only is enough to induce the order of these substrings
+    let suffix_array = induce(text, &is_s, &bucket_sizes, &lms);
+
+    let sorted_lms: Vec<usize> = suffix_array.into_iter().filter(|&index| is_lms(index)).collect();
+
+    let mut names = vec![EMPTY; length];
+    let mut name = 0;
+    names[sorted_lms[0]] = 0;
+
+    for pair in sorted_lms.windows(2) {
+        if !equal_lms_substrings(text, &is_s, pair[0], pair[1]) {
+            name += 1;
+        }
+
+        names[pair[1]] = name;
+    }
+
+    let reduced_text: Vec<usize> = lms.iter().map(|&index| names[index]).collect();
+
+    // the LMS substrings are all different, their order is the order of the LMS suffixes
+    let reduced_suffix_array = if name + 1 == reduced_text.len() {
+        let mut reduced_suffix_array = vec![0; reduced_text.len()];
+
+        for (index, &name) in reduced_text.iter().enumerate() {
+            reduced_suffix_array[name] = index;
+        }
+
+        reduced_suffix_array
+    } else {
+        sais(&reduced_text, name + 1)
+    };
+
+    let sorted_lms: Vec<usize> = reduced_suffix_array.into_iter().map(|index| lms[index]).collect();
+
+    induce(text, &is_s, &bucket_sizes, &sorted_lms)
+}
+
+// places the LMS suffixes at the end of their buckets in the given order,
+// then induces the L-type suffixes from left to right and the S-type ones from right to left
+fn induce(text: &[usize], is_s: &[bool], bucket_sizes: &[usize], lms: &[usize]) -> Vec<usize> {
+    let length = text.len();
+    let mut suffix_array = vec![EMPTY; length];
+
+    let bucket_ends = || {
+        let mut sum = 0;
+
+        bucket_sizes
+            .iter()
+            .map(|size| {
+                sum += size;
+                sum
+            })
+            .collect::<Vec<usize>>()
+    };
+
+    let mut tails = bucket_ends();
+
+    for &index in lms.iter().rev() {
+        tails[text[index]] -= 1;
+        suffix_array[tails[text[index]]] = index;
+    }
+
+    let mut heads: Vec<usize> = bucket_ends()
+        .iter()
+        .zip(bucket_sizes)
+        .map(|(end, size)| end - size)
+        .collect();
+
+    for rank in 0..length {
+        let index = suffix_array[rank];
+
+        if index != EMPTY && index > 0 && !is_s[index - 1] {
+            let symbol = text[index - 1];
+
+            suffix_array[heads[symbol]] = index - 1;
+            heads[symbol] += 1;
+        }
+    }
+
+    let mut tails = bucket_ends();
+
+    for rank in (0..length).rev() {
+        let index = suffix_array[rank];
+
+        if index != EMPTY && index > 0 && is_s[index - 1] {
+            let symbol = text[index - 1];
+
+            tails[symbol] -= 1;
+            suffix_array[tails[symbol]] = index - 1;
+        }
+    }
+
+    suffix_array
+}
+
+// whether the LMS substrings starting at a and b (up to the next LMS position, included) are equal
+fn equal_lms_substrings(text: &[usize], is_s: &[bool], a: usize, b: usize) -> bool {
+    let is_lms = |index: usize| index > 0 && is_s[index] && !is_s[index - 1];
+
+    let mut offset = 0;
+
+    loop {
+        if text[a + offset] != text[b + offset] || is_s[a + offset] != is_s[b + offset] {
+            return false;
+        }
+
+        if offset > 0 && (is_lms(a + offset) || is_lms(b + offset)) {
+            return is_lms(a + offset) && is_lms(b + offset);
+        }
+
+        // the sentinel is a LMS position and appears only once, so this never goes past the end of the text
+        offset += 1;
+    }
+}
+
+// LCP array of the suffix array, where lcp[rank] is the LCP of the suffixes at rank - 1 and rank:
+// the LCP of the suffix following position i is at least the one of position i minus 1
+pub(crate) fn lcp(text: &[usize], suffix_array: &[usize]) -> Vec<usize> {
+    let length = text.len();
+
+    let mut ranks = vec![0; length];
+    for (rank, &index) in suffix_array.iter().enumerate() {
+        ranks[index] = rank;
+    }
+
+    let mut lcp = vec![0; length];
+    let mut common = 0;
+
+    for index in 0..length {
+        if ranks[index] == 0 {
+            common = 0;
+            continue;
+        }
+
+        let previous = suffix_array[ranks[index] - 1];
+
+        while index + common < length && previous + common < length && text[index + common] == text[previous + common] {
+            common += 1;
+        }
+
+        lcp[ranks[index]] = common;
+
+        if common > 0 {
+            common -= 1;
+        }
+    }
+
+    lcp
+}
@@ -0,0 +1,208 @@
+// Burrows-Wheeler transform: the last column of the sorted rotations of a sequence followed by a sentinel ($),
+// read off the suffix array (the symbol before each sorted suffix)
+//
+// the i-th occurrence of a symbol in the last column and its i-th occurrence in the first column are the same
+// symbol of the sequence (LF mapping), which is all the inverse transform and the FM-index need
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use dna::Sequence as DnaSequence;
+    use super::Bwt;
+
+    #[test]
+    fn transform() {
+        let sequence = DnaSequence::try_from("GCGTGCCTGGTCA").unwrap();
+
+        let bwt = Bwt::new(&sequence);
+
+        assert_eq!(bwt.to_string(), "ACTGGCT$TGCGGC");
+        assert_eq!(bwt.sentinel, 7);
+        assert_eq!(bwt.len(), 14);
+    }
+
+    #[test]
+    fn inverse() {
+        for string in &["GCGTGCCTGGTCA", "AAAAAA", "ACGTACGTTACGCCACGTACGTACCCGTA", "T", ""] {
+            let sequence = DnaSequence::try_from(*string).unwrap();
+
+            assert_eq!(Bwt::new(&sequence).inverse(), sequence);
+        }
+    }
+}
+
+use std::fmt;
+use alignment::Symbol;
+use dna::{Nucleobase, Sequence as DnaSequence};
+use super::array::sais;
+
+pub(crate) const SENTINEL: u8 = 0;
+
+// the sentinel and the 4 nucleobases
+pub(crate) const ALPHABET_SIZE: usize = 5;
+
+const NUCLEOBASES: [Nucleobase; 4] = [
+    Nucleobase::Adenine,
+    Nucleobase::Cytosine,
+    Nucleobase::Guanine,
+    Nucleobase::Thymine,
+];
+
+/// Burrows-Wheeler transform of a DNA sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bwt {
+    /// The transform, without the sentinel.
+    pub nucleobases: Vec<Nucleobase>,
+    /// Position of the sentinel in the transform.
+    pub sentinel: usize,
+}
+
+impl Bwt {
+    /// Returns the Burrows-Wheeler transform of `sequence`, in O(length of the sequence).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::suffix::Bwt;
+    ///
+    /// let sequence = Sequence::try_from("GCGTGCCTGGTCA").unwrap();
+    ///
+    /// let bwt = Bwt::new(&sequence);
+    ///
+    /// assert_eq!(bwt.to_string(), "ACTGGCT$TGCGGC");
+    /// assert_eq!(bwt.inverse(), sequence);
+    /// ```
+    pub fn new(sequence: &DnaSequence) -> Self {
+        let (codes, _) = transform(sequence);
+
+        Bwt::from_codes(&codes)
+    }
+
+    /// Returns the length of the transform, the sentinel included.
+    pub fn len(&self) -> usize {
+        self.nucleobases.len() + 1
+    }
+
+    /// Returns whether the transform holds the sentinel alone, i.e. was computed from an empty sequence.
+    pub fn is_empty(&self) -> bool {
+        self.nucleobases.is_empty()
+    }
+
+    /// Returns the sequence the transform was computed from.
+    pub fn inverse(&self) -> DnaSequence {
+        let codes = self.codes();
+
+        let mut counts = [0; ALPHABET_SIZE];
+        // rank of each symbol among the equal symbols before it
+        let mut ranks = Vec::with_capacity(codes.len());
+
+        for &code in &codes {
+            ranks.push(counts[code as usize]);
+            counts[code as usize] += 1;
+        }
+
+        let first_rows = first_rows(&counts);
+
+        // row 0 is the rotation starting with the sentinel, its last symbol is the last one of the sequence
+        let mut nucleobases = Vec::with_capacity(self.nucleobases.len());
+        let mut row = 0;
+
+        for _ in 0..self.nucleobases.len() {
+            let code = codes[row];
+
+            nucleobases.push(NUCLEOBASES[code as usize - 1].clone());
+            row = first_rows[code as usize] + ranks[row];
+        }
+
+        nucleobases.reverse();
+
+        DnaSequence::new(nucleobases)
+    }
+
+    pub(crate) fn from_codes(codes: &[u8]) -> Self {
+        let mut nucleobases = Vec::with_capacity(codes.len() - 1);
+        let mut sentinel = 0;
+
+        for (position, &code) in codes.iter().enumerate() {
+            if code == SENTINEL {
+                sentinel = position;
+            } else {
+                nucleobases.push(NUCLEOBASES[code as usize - 1].clone());
+            }
+        }
+
+        Bwt {
+            nucleobases: nucleobases,
+            sentinel: sentinel,
+        }
+    }
+
+    // the sentinel is 0 and the nucleobases are their index + 1
+    fn codes(&self) -> Vec<u8> {
+        let mut codes: Vec<u8> = self
+            .nucleobases
+            .iter()
+            .map(|nucleobase| nucleobase.index() as u8 + 1)
+            .collect();
+
+        codes.insert(self.sentinel, SENTINEL);
+
+        codes
+    }
+}
+
+impl fmt::Display for Bwt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (position, nucleobase) in self.nucleobases.iter().enumerate() {
+            if position == self.sentinel {
+                write!(f, "$")?;
+            }
+
+            write!(f, "{}", nucleobase)?;
+        }
+
+        if self.sentinel == self.nucleobases.len() {
+            write!(f, "$")?;
+        }
+
+        Ok(())
+    }
+}
+
+// returns the transform as codes, with the suffix array it was read from
+pub(crate) fn transform(sequence: &DnaSequence) -> (Vec<u8>, Vec<usize>) {
+    let mut text: Vec<usize> = sequence
+        .as_ref()
+        .iter()
+        .map(|nucleobase| nucleobase.index() + 1)
+        .collect();
+    text.push(SENTINEL as usize);
+
+    let suffix_array = sais(&text, ALPHABET_SIZE);
+
+    let codes = suffix_array
+        .iter()
+        .map(|&position| {
+            if position == 0 {
+                SENTINEL
+            } else {
+                text[position - 1] as u8
+            }
+        })
+        .collect();
+
+    (codes, suffix_array)
+}
+
+// row of the first rotation starting with each symbol, given the number of occurrences of every symbol
+pub(crate) fn first_rows(counts: &[usize; ALPHABET_SIZE]) -> [usize; ALPHABET_SIZE] {
+    let mut first_rows = [0; ALPHABET_SIZE];
+
+    for code in 1..ALPHABET_SIZE {
+        first_rows[code] = first_rows[code - 1] + counts[code - 1];
+    }
+
+    first_rows
+}
@@ -0,0 +1,603 @@
+// FM-index: counts and locates the occurrences of a pattern in a reference in O(length of the pattern),
+// without going through the reference again
+//
+// the rows of the sorted rotations starting with a pattern are consecutive, and the backward search narrows them
+// down one symbol of the pattern at a time, from the last one, with the LF mapping of the Burrows-Wheeler transform:
+// it only needs the number of occurrences of a symbol before a row, which is stored every `checkpoint_interval` rows
+// and counted from the last checkpoint in between
+//
+// to locate the rows, only the suffix array entries of the positions which are a multiple of `sample_rate` are kept:
+// going back in the reference with the LF mapping reaches one of them in less than `sample_rate` steps
+//
+// a circular reference is indexed followed by its beginning, so that occurrences spanning its origin are found too,
+// the ones found again after its end being dropped
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::io::Cursor;
+    use dna::{Sequence as DnaSequence, Topology};
+    use random::SplitMix64;
+    use super::{FmIndex, FmIndexError};
+
+    fn random_sequence(rng: &mut SplitMix64, length: usize) -> DnaSequence {
+        let string: String = (0..length).map(|_| "ACGT".as_bytes()[rng.below(4)] as char).collect();
+
+        DnaSequence::try_from(string.as_str()).unwrap()
+    }
+
+    #[test]
+    fn count_and_locate() {
+        let reference = DnaSequence::try_from("GATATATGCATATACTT").unwrap();
+
+        let index = FmIndex::new(&reference);
+
+        let pattern = DnaSequence::try_from("ATAT").unwrap();
+
+        assert_eq!(index.count(&pattern), 3);
+        assert_eq!(index.locate(&pattern), vec![1, 3, 9]);
+
+        let pattern = DnaSequence::try_from("GG").unwrap();
+
+        assert_eq!(index.count(&pattern), 0);
+        assert_eq!(index.locate(&pattern), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn matches_naive_search() {
+        let mut rng = SplitMix64::new(11);
+
+        let reference = random_sequence(&mut rng, 2000);
+        let nucleobases = reference.as_ref();
+
+        // small intervals, so that the checkpoints and the samples are really used
+        let index = FmIndex::with_parameters(&reference, 7, 5);
+
+        for length in 1..10 {
+            let pattern = random_sequence(&mut rng, length);
+
+            let expected: Vec<usize> = (0..reference.len() - length + 1)
+                .filter(|&position| &nucleobases[position..position + length] == pattern.as_ref())
+                .collect();
+
+            assert_eq!(index.count(&pattern), expected.len());
+            assert_eq!(index.locate(&pattern), expected);
+        }
+    }
+
+    #[test]
+    fn circular_reference() {
+        let plasmid = DnaSequence::try_from("TACGGATCCAAAAG").unwrap().with_topology(Topology::Circular);
+
+        let index = FmIndex::with_parameters(&plasmid, 4, 3);
+
+        assert_eq!(index.len(), 14);
+        assert!(index.is_circular());
+
+        // spanning the origin
+        let pattern = DnaSequence::try_from("AAGTAC").unwrap();
+
+        assert_eq!(index.count(&pattern), 1);
+        assert_eq!(index.locate(&pattern), vec![11]);
+
+        // found once, not again in the unrolled beginning
+        let pattern = DnaSequence::try_from("GGATCC").unwrap();
+
+        assert_eq!(index.locate(&pattern), vec![3]);
+
+        let mut bytes = Vec::new();
+        index.write_to(&mut bytes).unwrap();
+
+        let loaded = FmIndex::read_from(&mut Cursor::new(&bytes)).unwrap();
+
+        assert!(loaded.is_circular());
+        assert_eq!(loaded.locate(&pattern), vec![3]);
+    }
+
+    #[test]
+    fn serialization() {
+        let mut rng = SplitMix64::new(12);
+
+        let reference = random_sequence(&mut rng, 500);
+        let index = FmIndex::with_parameters(&reference, 16, 8);
+
+        let mut bytes = Vec::new();
+        index.write_to(&mut bytes).unwrap();
+
+        let loaded = FmIndex::read_from(&mut Cursor::new(&bytes)).unwrap();
+
+        assert_eq!(loaded.bwt(), index.bwt());
+
+        for length in 1..6 {
+            let pattern = random_sequence(&mut rng, length);
+
+            assert_eq!(loaded.locate(&pattern), index.locate(&pattern));
+        }
+    }
+
+    #[test]
+    fn invalid_file() {
+        let mut bytes = Vec::new();
+        FmIndex::new(&DnaSequence::try_from("ACGT").unwrap())
+            .write_to(&mut bytes)
+            .unwrap();
+
+        match FmIndex::read_from(&mut Cursor::new(&bytes[..bytes.len() - 1])) {
+            Err(FmIndexError::Io(_)) => {}
+            _ => panic!("A truncated index shouldn't be read"),
+        }
+
+        bytes[0] = b'X';
+
+        match FmIndex::read_from(&mut Cursor::new(&bytes)) {
+            Err(FmIndexError::InvalidFormat(_)) => {}
+            _ => panic!("An index with the wrong magic number shouldn't be read"),
+        }
+    }
+
+    #[test]
+    fn corrupt_file() {
+        let mut bytes = Vec::new();
+        FmIndex::new(&DnaSequence::try_from("ACGT").unwrap())
+            .write_to(&mut bytes)
+            .unwrap();
+
+        // magic number, checkpoint interval, sample rate, length of the reference, then of the 5-code transform
+        let corrupt = |offset: usize, value: u64| {
+            let mut corrupt = bytes.clone();
+            corrupt[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+
+            FmIndex::read_from(&mut Cursor::new(&corrupt))
+        };
+
+        // a huge transform isn't allocated before being read
+        match corrupt(32, u64::MAX) {
+            Err(FmIndexError::InvalidFormat(_)) => {}
+            _ => panic!("An index longer than its file shouldn't be read"),
+        }
+
+        // then the number of words of the sampled rows and the only word, position 0 being the only sampled one
+        let mut word = [0; 8];
+        word.copy_from_slice(&bytes[53..61]);
+        let word = u64::from_le_bytes(word);
+
+        match corrupt(53, word.rotate_left(1)) {
+            Err(FmIndexError::InvalidFormat(_)) => {}
+            _ => panic!("An index whose position 0 isn't sampled shouldn't be read"),
+        }
+
+        // then the number of samples and the only sample
+        match corrupt(69, 2) {
+            Err(FmIndexError::InvalidFormat(_)) => {}
+            _ => panic!("An index with wrong samples shouldn't be read"),
+        }
+
+        assert!(corrupt(69, 0).is_ok());
+
+        match corrupt(24, 3) {
+            Err(FmIndexError::InvalidFormat(_)) => {}
+            _ => panic!("An index whose reference doesn't match its transform shouldn't be read"),
+        }
+    }
+}
+
+use std::{error, fmt, fs, io};
+use std::io::{Read, Write};
+use alignment::Symbol;
+use dna::Sequence as DnaSequence;
+use search::unroll;
+use super::bwt::{self, Bwt, ALPHABET_SIZE, SENTINEL};
+
+const MAGIC_NUMBER: &[u8; 8] = b"RSLNDFM1";
+
+const DEFAULT_CHECKPOINT_INTERVAL: usize = 64;
+const DEFAULT_SAMPLE_RATE: usize = 32;
+
+#[derive(Debug)]
+pub enum FmIndexError {
+    Io(io::Error),
+    InvalidFormat(&'static str),
+}
+
+impl error::Error for FmIndexError {
+    fn description(&self) -> &str {
+        match self {
+            FmIndexError::Io(_) => "couldn't read or write the index",
+            FmIndexError::InvalidFormat(_) => "invalid index file",
+        }
+    }
+}
+
+impl fmt::Display for FmIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FmIndexError::Io(error) => write!(f, "couldn't read or write the index: {}", error),
+            FmIndexError::InvalidFormat(reason) => write!(f, "invalid index file: {}", reason),
+        }
+    }
+}
+
+impl From<io::Error> for FmIndexError {
+    fn from(error: io::Error) -> Self {
+        FmIndexError::Io(error)
+    }
+}
+
+/// Index of a DNA reference, to count and locate the occurrences of patterns in it.
+pub struct FmIndex {
+    // length of the reference, shorter than the transform if it was unrolled
+    length: usize,
+    // Burrows-Wheeler transform, the sentinel being 0 and the nucleobases their index + 1
+    bwt: Vec<u8>,
+    first_rows: [usize; ALPHABET_SIZE],
+    checkpoint_interval: usize,
+    // occurrences of each nucleobase before every checkpoint_interval-th row
+    checkpoints: Vec<[usize; 4]>,
+    sample_rate: usize,
+    // rows whose position in the reference is sampled, as a bit vector
+    sampled_rows: Vec<u64>,
+    // number of sampled rows before each word of the bit vector
+    sampled_ranks: Vec<usize>,
+    // positions of the sampled rows, in the order of the rows
+    samples: Vec<usize>,
+}
+
+impl FmIndex {
+    /// Builds the index of `reference`, with a checkpoint every 64 rows and one position out of 32 sampled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::suffix::FmIndex;
+    ///
+    /// let reference = Sequence::try_from("GATATATGCATATACTT").unwrap();
+    /// let read = Sequence::try_from("ATAT").unwrap();
+    ///
+    /// let index = FmIndex::new(&reference);
+    ///
+    /// assert_eq!(index.count(&read), 3);
+    /// assert_eq!(index.locate(&read), vec![1, 3, 9]);
+    /// ```
+    pub fn new(reference: &DnaSequence) -> Self {
+        FmIndex::with_parameters(reference, DEFAULT_CHECKPOINT_INTERVAL, DEFAULT_SAMPLE_RATE)
+    }
+
+    /// Builds the index of `reference` with a checkpoint every `checkpoint_interval` rows
+    /// and one position out of `sample_rate` sampled: the larger they are, the smaller and slower the index is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `checkpoint_interval` or `sample_rate` is 0.
+    pub fn with_parameters(reference: &DnaSequence, checkpoint_interval: usize, sample_rate: usize) -> Self {
+        assert!(checkpoint_interval > 0, "The checkpoint interval must be > 0");
+        assert!(sample_rate > 0, "The sample rate must be > 0");
+
+        let unrolled = unroll(reference, reference.len());
+        let (codes, suffix_array) = bwt::transform(unrolled.as_ref().unwrap_or(reference));
+
+        let mut sampled_rows = vec![0; codes.len() / 64 + 1];
+        let mut samples = Vec::new();
+
+        for (row, &position) in suffix_array.iter().enumerate() {
+            if position % sample_rate == 0 {
+                sampled_rows[row / 64] |= 1 << (row % 64);
+                samples.push(position);
+            }
+        }
+
+        FmIndex::from_parts(reference.len(), codes, checkpoint_interval, sample_rate, sampled_rows, samples)
+    }
+
+    /// Returns the length of the reference.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns whether the reference is circular.
+    pub fn is_circular(&self) -> bool {
+        self.bwt.len() - 1 != self.length
+    }
+
+    /// Returns the Burrows-Wheeler transform of the reference, followed by its beginning if it is circular.
+    pub fn bwt(&self) -> Bwt {
+        Bwt::from_codes(&self.bwt)
+    }
+
+    /// Returns the number of occurrences of `pattern` in the reference, overlapping ones included.
+    /// An empty pattern never matches.
+    ///
+    /// In a circular reference, the occurrences spanning its origin are counted too, for patterns up to its length,
+    /// but they must be located to drop the ones found twice.
+    pub fn count(&self, pattern: &DnaSequence) -> usize {
+        if self.is_circular() {
+            return self.locate(pattern).len();
+        }
+
+        let (top, bottom) = self.rows(pattern);
+
+        bottom - top
+    }
+
+    /// Returns the 0-based positions of all the occurrences of `pattern` in the reference, in increasing order.
+    /// An empty pattern never matches.
+    ///
+    /// In a circular reference, the occurrences spanning its origin are located too, for patterns up to its length.
+    pub fn locate(&self, pattern: &DnaSequence) -> Vec<usize> {
+        let (top, bottom) = self.rows(pattern);
+
+        let mut positions: Vec<usize> = (top..bottom)
+            .map(|row| self.position(row))
+            .filter(|&position| position < self.length)
+            .collect();
+        positions.sort();
+
+        positions
+    }
+
+    /// Writes the index to `path`, so that it can be loaded instead of being built again.
+    pub fn save(&self, path: &str) -> Result<(), FmIndexError> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+
+        self.write_to(&mut file)
+    }
+
+    /// Loads an index written by `save`.
+    pub fn load(path: &str) -> Result<Self, FmIndexError> {
+        let mut file = io::BufReader::new(fs::File::open(path)?);
+
+        FmIndex::read_from(&mut file)
+    }
+
+    /// Writes the index in a binary format: the transform and the sampled positions,
+    /// everything else being rebuilt when reading it.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), FmIndexError> {
+        writer.write_all(MAGIC_NUMBER)?;
+
+        write_u64(writer, self.checkpoint_interval as u64)?;
+        write_u64(writer, self.sample_rate as u64)?;
+        write_u64(writer, self.length as u64)?;
+
+        write_u64(writer, self.bwt.len() as u64)?;
+        writer.write_all(&self.bwt)?;
+
+        write_u64(writer, self.sampled_rows.len() as u64)?;
+        for &word in &self.sampled_rows {
+            write_u64(writer, word)?;
+        }
+
+        write_u64(writer, self.samples.len() as u64)?;
+        for &position in &self.samples {
+            write_u64(writer, position as u64)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads an index written by `write_to`.
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<Self, FmIndexError> {
+        let mut magic_number = [0; 8];
+        reader.read_exact(&mut magic_number)?;
+
+        if &magic_number != MAGIC_NUMBER {
+            return Err(FmIndexError::InvalidFormat("not an FM-index"));
+        }
+
+        let checkpoint_interval = read_u64(reader)? as usize;
+        let sample_rate = read_u64(reader)? as usize;
+
+        if checkpoint_interval == 0 || sample_rate == 0 {
+            return Err(FmIndexError::InvalidFormat("the checkpoint interval and the sample rate must be > 0"));
+        }
+
+        let reference_length = read_u64(reader)?;
+
+        // the lengths are checked against what can actually be read before allocating anything
+        let length = read_u64(reader)?;
+        let mut codes = Vec::new();
+        reader.by_ref().take(length).read_to_end(&mut codes)?;
+
+        if codes.len() as u64 != length {
+            return Err(FmIndexError::InvalidFormat("truncated Burrows-Wheeler transform"));
+        }
+
+        // a circular reference of n nucleobases is unrolled to 2n - 1
+        if length != reference_length + 1 && length != 2 * reference_length {
+            return Err(FmIndexError::InvalidFormat("the transform doesn't match the length of the reference"));
+        }
+
+        if codes.iter().filter(|&&code| code == SENTINEL).count() != 1
+            || codes.iter().any(|&code| code as usize >= ALPHABET_SIZE)
+        {
+            return Err(FmIndexError::InvalidFormat("invalid Burrows-Wheeler transform"));
+        }
+
+        if read_u64(reader)? != codes.len() as u64 / 64 + 1 {
+            return Err(FmIndexError::InvalidFormat("the sampled rows don't match the transform"));
+        }
+
+        let sampled_rows = (0..codes.len() / 64 + 1)
+            .map(|_| read_u64(reader))
+            .collect::<io::Result<Vec<u64>>>()?;
+
+        let number_of_samples = read_u64(reader)?;
+
+        if number_of_samples > codes.len() as u64 {
+            return Err(FmIndexError::InvalidFormat("more sampled positions than rows"));
+        }
+
+        let samples = (0..number_of_samples)
+            .map(|_| read_u64(reader).map(|position| position as usize))
+            .collect::<io::Result<Vec<usize>>>()?;
+
+        let number_of_sampled_rows: u32 = sampled_rows.iter().map(|word| word.count_ones()).sum();
+        // bits past the last row
+        let padding = sampled_rows[codes.len() / 64] >> (codes.len() % 64);
+
+        if number_of_sampled_rows as usize != samples.len() || padding != 0 {
+            return Err(FmIndexError::InvalidFormat("the sampled positions don't match the transform"));
+        }
+
+        let index = FmIndex::from_parts(
+            reference_length as usize,
+            codes,
+            checkpoint_interval,
+            sample_rate,
+            sampled_rows,
+            samples,
+        );
+
+        if !index.has_valid_samples() {
+            return Err(FmIndexError::InvalidFormat("the sampled positions don't match the transform"));
+        }
+
+        Ok(index)
+    }
+
+    fn from_parts(
+        length: usize,
+        bwt: Vec<u8>,
+        checkpoint_interval: usize,
+        sample_rate: usize,
+        sampled_rows: Vec<u64>,
+        samples: Vec<usize>,
+    ) -> Self {
+        let mut counts = [0; ALPHABET_SIZE];
+        let mut checkpoints = Vec::with_capacity(bwt.len() / checkpoint_interval + 1);
+
+        for (row, &code) in bwt.iter().enumerate() {
+            if row % checkpoint_interval == 0 {
+                checkpoints.push([counts[1], counts[2], counts[3], counts[4]]);
+            }
+
+            counts[code as usize] += 1;
+        }
+
+        // so that the occurrences before the end of the transform can be looked up like any other row
+        if bwt.len() % checkpoint_interval == 0 {
+            checkpoints.push([counts[1], counts[2], counts[3], counts[4]]);
+        }
+
+        let mut sampled_ranks = Vec::with_capacity(sampled_rows.len());
+        let mut rank = 0;
+
+        for word in &sampled_rows {
+            sampled_ranks.push(rank);
+            rank += word.count_ones() as usize;
+        }
+
+        FmIndex {
+            length: length,
+            first_rows: bwt::first_rows(&counts),
+            bwt: bwt,
+            checkpoint_interval: checkpoint_interval,
+            checkpoints: checkpoints,
+            sample_rate: sample_rate,
+            sampled_rows: sampled_rows,
+            sampled_ranks: sampled_ranks,
+            samples: samples,
+        }
+    }
+
+    // backward search: range of the rows starting with the pattern
+    fn rows(&self, pattern: &DnaSequence) -> (usize, usize) {
+        if pattern.len() == 0 {
+            return (0, 0);
+        }
+
+        let mut top = 0;
+        let mut bottom = self.bwt.len();
+
+        for nucleobase in pattern.as_ref().iter().rev() {
+            let code = nucleobase.index() as u8 + 1;
+
+            top = self.first_rows[code as usize] + self.occurrences(code, top);
+            bottom = self.first_rows[code as usize] + self.occurrences(code, bottom);
+
+            if top >= bottom {
+                return (0, 0);
+            }
+        }
+
+        (top, bottom)
+    }
+
+    // number of occurrences of the nucleobase before the row
+    fn occurrences(&self, code: u8, row: usize) -> usize {
+        let checkpoint = row / self.checkpoint_interval;
+
+        let from = checkpoint * self.checkpoint_interval;
+
+        self.checkpoints[checkpoint][code as usize - 1]
+            + self.bwt[from..row].iter().filter(|&&other| other == code).count()
+    }
+
+    // position in the reference of the suffix at the row
+    fn position(&self, row: usize) -> usize {
+        let mut row = row;
+        let mut steps = 0;
+
+        // position 0 is always sampled, so the sentinel is never reached
+        while !self.is_sampled(row) {
+            row = self.previous_row(row);
+            steps += 1;
+        }
+
+        self.samples[self.sample_rank(row)] + steps
+    }
+
+    // LF mapping: row of the suffix starting one position before the suffix at the row, unless it starts at 0
+    fn previous_row(&self, row: usize) -> usize {
+        let code = self.bwt[row];
+
+        self.first_rows[code as usize] + self.occurrences(code, row)
+    }
+
+    fn is_sampled(&self, row: usize) -> bool {
+        self.sampled_rows[row / 64] & (1 << (row % 64)) != 0
+    }
+
+    // number of sampled rows before the row
+    fn sample_rank(&self, row: usize) -> usize {
+        let word = self.sampled_rows[row / 64] & ((1 << (row % 64)) - 1);
+
+        self.sampled_ranks[row / 64] + word.count_ones() as usize
+    }
+
+    // whether the transform is the one of a single text, going back from its end to position 0 through every row,
+    // and the samples are the positions of their rows, position 0 included, so that `position` always terminates
+    fn has_valid_samples(&self) -> bool {
+        // the sentinel sorts first: row 0 is the suffix made of it alone
+        let mut row = 0;
+
+        for position in (0..self.bwt.len()).rev() {
+            if self.is_sampled(row) && self.samples[self.sample_rank(row)] != position {
+                return false;
+            }
+
+            if self.bwt[row] == SENTINEL {
+                return position == 0 && self.is_sampled(row);
+            }
+
+            row = self.previous_row(row);
+        }
+
+        false
+    }
+}
+
+fn write_u64<W: Write>(writer: &mut W, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
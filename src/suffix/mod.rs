@@ -0,0 +1,67 @@
+// indexes of all the suffixes of sequences, to answer substring queries without scanning the sequences again
+//
+// the suffix array and the suffix tree index sequences as linear strings of symbols, so they reject circular DNA
+// rather than miss what spans its origin; the FM-index unrolls circular references instead
+
+pub use self::array::{SuffixArray, SuffixPosition, Substring};
+pub use self::bwt::Bwt;
+pub use self::fm_index::{FmIndex, FmIndexError};
+pub use self::tree::{Repeat, SuffixTree};
+
+pub mod array;
+pub mod bwt;
+pub mod fm_index;
+pub mod tree;
+
+use std::{error, fmt};
+use amino_acids::{AminoAcid, Sequence as AminoAcidSequence};
+use dna::{Nucleobase, Sequence as DnaSequence};
+
+/// Sequence of symbols the suffix array and the suffix tree can index, provided it is linear.
+pub trait Text<S>: AsRef<[S]> {
+    fn is_circular(&self) -> bool {
+        false
+    }
+}
+
+impl Text<Nucleobase> for DnaSequence {
+    fn is_circular(&self) -> bool {
+        DnaSequence::is_circular(self)
+    }
+}
+
+impl Text<AminoAcid> for AminoAcidSequence {}
+
+impl<S> Text<S> for [S] {}
+
+impl<S> Text<S> for Vec<S> {}
+
+impl<'a, S, T: Text<S> + ?Sized> Text<S> for &'a T {
+    fn is_circular(&self) -> bool {
+        (**self).is_circular()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SuffixError {
+    /// The sequence of index `index` (0 if there is only one) is circular.
+    CircularSequence { index: usize },
+}
+
+impl error::Error for SuffixError {
+    fn description(&self) -> &str {
+        match self {
+            SuffixError::CircularSequence { .. } => "circular sequences can't be indexed",
+        }
+    }
+}
+
+impl fmt::Display for SuffixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SuffixError::CircularSequence { index } => {
+                write!(f, "the sequence at index {} is circular and can't be indexed", index)
+            }
+        }
+    }
+}
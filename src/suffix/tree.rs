@@ -0,0 +1,501 @@
+// suffix tree, built in linear time with Ukkonen's algorithm
+//
+// the sequence is followed by a terminal symbol ($) which appears nowhere else, so that every suffix ends at a leaf;
+// edges are labelled with ranges of the sequence rather than copies of it, and the edges to leaves all end
+// at the current end of the sequence while it is being read, so that extending all of them costs nothing
+//
+// the children of a node are sorted by the index of their first symbol, so a depth-first traversal
+// visits the suffixes in lexicographic order: the leaves below any node are then consecutive in that order
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use amino_acids::Sequence as AminoAcidSequence;
+    use dna::{Sequence as DnaSequence, Topology};
+    use suffix::SuffixError;
+    use super::{Repeat, SuffixTree};
+
+    #[test]
+    fn edge_labels() {
+        let sequence = DnaSequence::try_from("ATAAATG").unwrap();
+
+        let tree = SuffixTree::new(&sequence).unwrap();
+
+        let mut labels = tree.edge_labels();
+        labels.sort();
+
+        let mut expected = vec![
+            "AAATG$", "G$", "T", "ATG$", "TG$", "A", "A", "AAATG$", "G$", "T", "G$", "$",
+        ];
+        expected.sort();
+
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn find_all() {
+        let sequence = DnaSequence::try_from("GATATATGCATATACTT").unwrap();
+
+        let tree = SuffixTree::new(&sequence).unwrap();
+
+        assert_eq!(tree.find_all(DnaSequence::try_from("ATAT").unwrap().as_ref()), vec![1, 3, 9]);
+        assert_eq!(tree.find_all(DnaSequence::try_from("TT").unwrap().as_ref()), vec![15]);
+        assert_eq!(tree.find_all(DnaSequence::try_from("GG").unwrap().as_ref()), Vec::<usize>::new());
+        assert_eq!(tree.find_all(&[]).len(), sequence.len());
+    }
+
+    #[test]
+    fn circular_sequence() {
+        let plasmid = DnaSequence::try_from("GATTACA").unwrap().with_topology(Topology::Circular);
+
+        assert_eq!(
+            SuffixTree::new(&plasmid).err(),
+            Some(SuffixError::CircularSequence { index: 0 })
+        );
+    }
+
+    #[test]
+    fn find_all_matches_naive_search() {
+        let sequence = DnaSequence::try_from("ACGTACGTTACGCCACGTACGTACCCGTAAAAAAC").unwrap();
+        let nucleobases = sequence.as_ref();
+
+        let tree = SuffixTree::new(&sequence).unwrap();
+
+        for start in 0..sequence.len() {
+            for end in start + 1..(start + 6).min(sequence.len()) {
+                let pattern = &nucleobases[start..end];
+
+                let expected: Vec<usize> = (0..sequence.len() - pattern.len() + 1)
+                    .filter(|&position| &nucleobases[position..position + pattern.len()] == pattern)
+                    .collect();
+
+                assert_eq!(tree.find_all(pattern), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn maximal_repeats() {
+        // TATCG is left-maximal thanks to its occurrence at 1 (after A) and 9 (after T),
+        // and right-maximal thanks to TATCGT at 1 and TATCGA at 9
+        let sequence = DnaSequence::try_from("ATATCGTTTTATCGAT").unwrap();
+
+        let tree = SuffixTree::new(&sequence).unwrap();
+
+        let repeats = tree.maximal_repeats(2);
+
+        assert!(repeats.contains(&Repeat {
+            length: 5,
+            positions: vec![1, 9],
+        }));
+        assert_eq!(repeats.iter().map(|repeat| repeat.length).max(), Some(5));
+
+        // ATC is always followed by G, so it is not right-maximal
+        assert!(!repeats.contains(&Repeat {
+            length: 3,
+            positions: vec![2, 10],
+        }));
+
+        // T is the only one to appear at least 6 times
+        assert_eq!(
+            tree.maximal_repeats(6),
+            vec![Repeat {
+                length: 1,
+                positions: vec![1, 3, 6, 7, 8, 9, 11, 15],
+            }]
+        );
+    }
+
+    #[test]
+    fn amino_acids() {
+        let sequence = AminoAcidSequence::try_from("MAMAPRTEINSTRING").unwrap();
+
+        let tree = SuffixTree::new(&sequence).unwrap();
+
+        // R, IN, MA and T, in the order of substitution matrices (ARNDCQEGHILKMFPSTWYV);
+        // A is always after M, so it is not left-maximal
+        assert_eq!(
+            tree.maximal_repeats(2),
+            vec![
+                Repeat {
+                    length: 1,
+                    positions: vec![5, 12],
+                },
+                Repeat {
+                    length: 2,
+                    positions: vec![8, 13],
+                },
+                Repeat {
+                    length: 2,
+                    positions: vec![0, 2],
+                },
+                Repeat {
+                    length: 1,
+                    positions: vec![6, 11],
+                },
+            ]
+        );
+    }
+}
+
+use std::collections::BTreeMap;
+use std::fmt;
+use alignment::Symbol;
+use super::{SuffixError, Text};
+
+const ROOT: usize = 0;
+
+// end of the edges to leaves while the tree is being built
+const OPEN: usize = ::std::usize::MAX;
+
+struct Node {
+    // range of the text labelling the edge from the parent
+    start: usize,
+    end: usize,
+    suffix_link: usize,
+    children: BTreeMap<usize, usize>,
+    // starting position of the suffix ending at the node, for leaves only
+    suffix: Option<usize>,
+}
+
+impl Node {
+    fn new(start: usize, end: usize, suffix: Option<usize>) -> Self {
+        Node {
+            start: start,
+            end: end,
+            suffix_link: ROOT,
+            children: BTreeMap::new(),
+            suffix: suffix,
+        }
+    }
+}
+
+/// Substring appearing several times in a sequence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Repeat {
+    pub length: usize,
+    /// 0-based positions of all the occurrences, overlapping ones included, in increasing order.
+    pub positions: Vec<usize>,
+}
+
+/// The suffix tree of a sequence.
+pub struct SuffixTree<S> {
+    symbols: Vec<S>,
+    // indexes of the symbols, followed by the terminal symbol
+    text: Vec<usize>,
+    nodes: Vec<Node>,
+    // nodes in depth-first order, i.e. in lexicographic order of their path from the root
+    preorder: Vec<usize>,
+    // leaves of the tree in depth-first order, i.e. the suffix array of the sequence
+    leaves: Vec<usize>,
+    // for every node, the range of the leaves below it in `leaves`
+    leaf_ranges: Vec<(usize, usize)>,
+}
+
+impl<S: Symbol + Clone> SuffixTree<S> {
+    /// Builds the suffix tree of `sequence`, in O(length of the sequence × log(alphabet size)),
+    /// or fails if it is a circular DNA sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::suffix::SuffixTree;
+    ///
+    /// let sequence = Sequence::try_from("ATAAATG").unwrap();
+    ///
+    /// let tree = SuffixTree::new(&sequence).unwrap();
+    ///
+    /// for label in tree.edge_labels() {
+    ///     println!("{}", label);
+    /// }
+    /// ```
+    pub fn new<Q: Text<S> + ?Sized>(sequence: &Q) -> Result<Self, SuffixError> {
+        if sequence.is_circular() {
+            return Err(SuffixError::CircularSequence { index: 0 });
+        }
+
+        let symbols = sequence.as_ref().to_vec();
+
+        let mut text: Vec<usize> = symbols.iter().map(|symbol| symbol.index()).collect();
+        text.push(S::ALPHABET_SIZE);
+
+        let mut nodes = build(&text);
+
+        for node in &mut nodes {
+            if node.end == OPEN {
+                node.end = text.len();
+            }
+        }
+
+        let mut preorder = Vec::with_capacity(nodes.len());
+        let mut stack = vec![ROOT];
+
+        while let Some(node) = stack.pop() {
+            preorder.push(node);
+
+            // pushed in reverse order, so that the smallest child is visited first
+            stack.extend(nodes[node].children.values().rev());
+        }
+
+        let mut leaves = Vec::new();
+        let mut leaf_ranges = vec![(0, 0); nodes.len()];
+
+        for &node in &preorder {
+            if let Some(suffix) = nodes[node].suffix {
+                leaf_ranges[node] = (leaves.len(), leaves.len() + 1);
+                leaves.push(suffix);
+            }
+        }
+
+        // children come after their parent in depth-first order
+        for &node in preorder.iter().rev() {
+            let children = &nodes[node].children;
+
+            if let (Some(first), Some(last)) = (children.values().next(), children.values().last()) {
+                leaf_ranges[node] = (leaf_ranges[*first].0, leaf_ranges[*last].1);
+            }
+        }
+
+        Ok(SuffixTree {
+            symbols: symbols,
+            text: text,
+            nodes: nodes,
+            preorder: preorder,
+            leaves: leaves,
+            leaf_ranges: leaf_ranges,
+        })
+    }
+
+    /// Returns the length of the sequence, without the terminal symbol.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Returns the number of nodes of the tree, the root and the leaves included.
+    pub fn number_of_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the 0-based positions of all the occurrences of `pattern`, in increasing order,
+    /// in O(length of the pattern + number of occurrences).
+    pub fn find_all(&self, pattern: &[S]) -> Vec<usize> {
+        let mut node = ROOT;
+        let mut matched = 0;
+
+        while matched < pattern.len() {
+            node = match self.nodes[node].children.get(&pattern[matched].index()) {
+                Some(&child) => child,
+                None => return Vec::new(),
+            };
+
+            let edge = &self.text[self.nodes[node].start..self.nodes[node].end];
+
+            for &symbol in edge.iter().take(pattern.len() - matched) {
+                if symbol != pattern[matched].index() {
+                    return Vec::new();
+                }
+
+                matched += 1;
+            }
+        }
+
+        let (first, last) = self.leaf_ranges[node];
+
+        let mut positions: Vec<usize> = self.leaves[first..last]
+            .iter()
+            .cloned()
+            .filter(|&position| position < self.len())
+            .collect();
+        positions.sort();
+
+        positions
+    }
+
+    /// Returns the maximal repeats appearing at least `min_occurrences` times (and at least twice),
+    /// in lexicographic order: the substrings which can't be extended to the left nor to the right
+    /// without losing one of their occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use rosalind::dna::Sequence;
+    /// use rosalind::suffix::SuffixTree;
+    ///
+    /// let sequence = Sequence::try_from("GATATATGCATATACTT").unwrap();
+    ///
+    /// let tree = SuffixTree::new(&sequence).unwrap();
+    ///
+    /// // the longest substring appearing at least 3 times: ATAT
+    /// let longest = tree.maximal_repeats(3).into_iter().max_by_key(|repeat| repeat.length).unwrap();
+    ///
+    /// assert_eq!(longest.positions, vec![1, 3, 9]);
+    /// assert_eq!(longest.length, 4);
+    /// ```
+    pub fn maximal_repeats(&self, min_occurrences: usize) -> Vec<Repeat> {
+        // length of the path from the root to every node
+        let mut depths = vec![0; self.nodes.len()];
+
+        for &node in &self.preorder {
+            for &child in self.nodes[node].children.values() {
+                depths[child] = depths[node] + self.nodes[child].end - self.nodes[child].start;
+            }
+        }
+
+        let mut repeats = Vec::new();
+
+        for &node in &self.preorder {
+            // leaves have only one occurrence, and every suffix is a prefix of the whole sequence
+            if node == ROOT || self.nodes[node].suffix.is_some() {
+                continue;
+            }
+
+            let (first, last) = self.leaf_ranges[node];
+
+            if last - first < min_occurrences {
+                continue;
+            }
+
+            let mut positions = self.leaves[first..last].to_vec();
+            positions.sort();
+
+            // an occurrence at the start of the sequence can't be extended to the left
+            let left_maximal = positions[0] == 0
+                || positions
+                    .iter()
+                    .any(|&position| self.text[position - 1] != self.text[positions[0] - 1]);
+
+            // internal nodes have at least 2 children, so they can't be extended to the right
+            if left_maximal {
+                repeats.push(Repeat {
+                    length: depths[node],
+                    positions: positions,
+                });
+            }
+        }
+
+        repeats
+    }
+}
+
+impl<S: Symbol + Clone + fmt::Display> SuffixTree<S> {
+    /// Returns the labels of all the edges of the tree, in depth-first order,
+    /// the terminal symbol being written `$`.
+    pub fn edge_labels(&self) -> Vec<String> {
+        self.preorder
+            .iter()
+            .filter(|&&node| node != ROOT)
+            .map(|&node| {
+                let Node { start, end, .. } = self.nodes[node];
+
+                let mut label: String = self.symbols[start..end.min(self.len())]
+                    .iter()
+                    .map(|symbol| symbol.to_string())
+                    .collect();
+
+                if end > self.len() {
+                    label.push('$');
+                }
+
+                label
+            })
+            .collect()
+    }
+}
+
+// Ukkonen's algorithm: the suffixes of text[..position + 1] which aren't in the tree yet are added one by one,
+// from the longest one, starting at the active point (the end of the longest suffix already in the tree)
+fn build(text: &[usize]) -> Vec<Node> {
+    let mut nodes = vec![Node::new(0, 0, None)];
+
+    let mut active_node = ROOT;
+    let mut active_edge = 0;
+    let mut active_length = 0;
+
+    // number of suffixes still to add
+    let mut remainder = 0;
+
+    for position in 0..text.len() {
+        remainder += 1;
+
+        // the last node created in this step, waiting for its suffix link
+        let mut waiting_for_link: Option<usize> = None;
+
+        while remainder > 0 {
+            if active_length == 0 {
+                active_edge = position;
+            }
+
+            let edge_symbol = text[active_edge];
+
+            match nodes[active_node].children.get(&edge_symbol).cloned() {
+                None => {
+                    let leaf = nodes.len();
+                    nodes.push(Node::new(position, OPEN, Some(position + 1 - remainder)));
+                    nodes[active_node].children.insert(edge_symbol, leaf);
+
+                    if let Some(node) = waiting_for_link {
+                        nodes[node].suffix_link = active_node;
+                    }
+                    waiting_for_link = Some(active_node);
+                }
+                Some(next) => {
+                    let edge_length = nodes[next].end.min(position + 1) - nodes[next].start;
+
+                    // the active point is beyond this edge, walk down
+                    if active_length >= edge_length {
+                        active_edge += edge_length;
+                        active_length -= edge_length;
+                        active_node = next;
+                        continue;
+                    }
+
+                    // the suffix is already in the tree, and so are all the shorter ones
+                    if text[nodes[next].start + active_length] == text[position] {
+                        if let Some(node) = waiting_for_link {
+                            nodes[node].suffix_link = active_node;
+                        }
+
+                        active_length += 1;
+                        break;
+                    }
+
+                    let split_position = nodes[next].start + active_length;
+
+                    let split = nodes.len();
+                    nodes.push(Node::new(nodes[next].start, split_position, None));
+                    nodes[active_node].children.insert(edge_symbol, split);
+
+                    let leaf = nodes.len();
+                    nodes.push(Node::new(position, OPEN, Some(position + 1 - remainder)));
+                    nodes[split].children.insert(text[position], leaf);
+
+                    nodes[next].start = split_position;
+                    nodes[split].children.insert(text[split_position], next);
+
+                    if let Some(node) = waiting_for_link {
+                        nodes[node].suffix_link = split;
+                    }
+                    waiting_for_link = Some(split);
+                }
+            }
+
+            remainder -= 1;
+
+            if active_node == ROOT && active_length > 0 {
+                active_length -= 1;
+                active_edge = position + 1 - remainder;
+            } else {
+                active_node = nodes[active_node].suffix_link;
+            }
+        }
+    }
+
+    nodes
+}